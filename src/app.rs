@@ -1,67 +1,173 @@
 use ratatui::widgets::ListState;
+use std::collections::HashSet;
 
+use crate::diff::{ChangeKind, DiffRow, DumpDiff};
+use crate::dump::DumpRoot;
 use crate::error::Result;
+use crate::events::EventInfo;
+use crate::global_search::GlobalSearch;
 use crate::kubernetes::{
-    load_deployments, load_namespaces, load_pods, ClusterAnalysis, DeploymentInfo, NamespaceInfo,
-    PodInfo,
+    load_namespace_analysis, load_namespaces, load_nodes, load_ownership, load_scheduled_pods,
+    ClusterAnalysis, DeploymentInfo, NamespaceInfo, PodInfo,
 };
-use crate::logs::ComponentLogs;
+use crate::logs::{
+    entry_pod, has_pod_log_file, list_log_components, list_log_containers, load_container_logs,
+    load_pod_logs, load_workload_logs, log_patterns, search_logs, ComponentLogs, LogEntry,
+    LogPattern, LogSearch,
+};
+use crate::networking::NamespaceNetworking;
+use crate::nodes::NodeInfo;
+use crate::ownership::OwnershipGraph;
+use crate::rules::RuleRegistry;
+use crate::workloads::{NamespaceWorkloads, WorkloadKind};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Screen {
     MainMenu,
     NamespaceList,
     NamespaceDetails,
     ClusterAnalysis,
+    Issues,
     ComponentDetails,
     LogsList,
     LogsViewer,
     Capybara,
     PodsList,        // New screen for pod selection
     DeploymentsList, // New screen for deployment selection
+    WorkloadsList,   // StatefulSets, DaemonSets, ... (see App::workload_kind)
+    ServicesList,
+    EventsList,
+    GlobalSearch, // Search in every log of the dump
+    NodesList,
+    Capacity, // Requests and limits per node and namespace
+    Diff,     // Changes since the baseline dump
+}
+
+/// Tabs of the logs viewer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogView {
+    Entries,
+    Patterns, // Entries grouped by message template
+}
+
+/// Step of the Left/Right keys in the logs viewer, in characters.
+const HORIZONTAL_SCROLL_STEP: usize = 20;
+
+/// Entries of the namespace details menu, in display order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NamespaceAction {
+    Pods,
+    Deployments,
+    Workloads(WorkloadKind),
+    Services,
+    Events,
+    Logs,
 }
 
 pub struct App {
+    pub dump: DumpRoot,
+    pub baseline: Option<DumpRoot>, // Earlier dump of the same cluster, for the Diff screen
+    pub rules: RuleRegistry,
     pub namespaces: Vec<NamespaceInfo>,
     pub current_screen: Screen,
     pub list_state: ListState,
     pub logs_scroll_state: ListState,
-    pub details_scroll_state: ListState,
+    pub details_scroll: usize, // Lines of ComponentDetails scrolled out at the top
+    pub details_max_scroll: usize, // Largest details_scroll, set when ComponentDetails is drawn
     pub selected_namespace: Option<String>,
     pub selected_component: Option<(String, String)>, // (component_name, component_type)
+    pub details_origin: Screen, // Screen to return to when leaving ComponentDetails
     pub pods: Vec<PodInfo>,
     pub deployments: Vec<DeploymentInfo>,
+    pub workloads: NamespaceWorkloads,
+    pub workload_kind: WorkloadKind, // Kind shown by the WorkloadsList screen
+    pub networking: NamespaceNetworking,
+    pub events: Vec<EventInfo>, // Events of the selected namespace, oldest first
+    pub ownership: OwnershipGraph,
+    pub nodes: Vec<NodeInfo>,
+    pub node_pods: Vec<(String, PodInfo)>, // (namespace, pod) of every scheduled pod
+    pub nodes_origin: Screen,              // Screen to return to when leaving NodesList
+    nodes_selected: usize,                 // Node to select when NodesList opens
     pub current_logs: Option<ComponentLogs>,
+    pub logs_error: Option<String>, // Why the log source selected in LogsList could not be opened
     pub cluster_analysis: Option<ClusterAnalysis>,
+    pub dump_diff: Option<DumpDiff>,
     pub log_filter: Option<String>,
+    pub log_search: LogSearch,
+    pub expanded_log_entries: HashSet<usize>, // Line numbers of entries shown with their stack traces
+    pub log_detail_open: bool,                // Detail pane of the selected entry
+    pub log_detail_scroll: u16,
+    pub log_horizontal_scroll: usize, // Characters of the messages scrolled out on the left
+    pub log_view: LogView,
+    pub log_patterns: Vec<LogPattern>, // Patterns of the visible entries, see refresh_log_patterns
+    pub patterns_state: ListState,
+    pub log_pattern_filter: Option<LogPattern>, // Pattern drilled down into from the Patterns tab
+    pub logs_origin: Screen,                    // Screen to return to when leaving LogsViewer
+    pub global_search: Option<GlobalSearch>,
+    pub global_query: LogSearch,
+    pub global_level_filter: Option<String>,
+    pub global_search_editing: bool, // The query prompt of the GlobalSearch screen is open
+    global_search_selected: usize,   // Hit to select again when coming back from LogsViewer
+    pub log_search_editing: bool,    // The `/` prompt is open
+    log_search_origin: (LogSearch, usize), // Search and selection to restore on cancel
     pub show_capybara: bool,
 }
 
 impl App {
-    pub fn new() -> Result<App> {
-        let namespaces = load_namespaces()?;
+    pub fn new(dump: DumpRoot, baseline: Option<DumpRoot>, rules: RuleRegistry) -> Result<App> {
+        let namespaces = load_namespaces(&dump)?;
         let mut list_state = ListState::default();
         list_state.select(Some(0));
 
         let mut logs_scroll_state = ListState::default();
         logs_scroll_state.select(Some(0));
 
-        let mut details_scroll_state = ListState::default();
-        details_scroll_state.select(Some(0));
-
         Ok(App {
+            dump,
+            baseline,
+            rules,
             namespaces,
             current_screen: Screen::MainMenu,
             list_state,
             logs_scroll_state,
-            details_scroll_state,
+            details_scroll: 0,
+            details_max_scroll: 0,
             selected_namespace: None,
             selected_component: None,
+            details_origin: Screen::ClusterAnalysis,
             pods: Vec::new(),
             deployments: Vec::new(),
+            workloads: NamespaceWorkloads::default(),
+            workload_kind: WorkloadKind::StatefulSet,
+            networking: NamespaceNetworking::default(),
+            events: Vec::new(),
+            ownership: OwnershipGraph::default(),
+            nodes: Vec::new(),
+            node_pods: Vec::new(),
+            nodes_origin: Screen::MainMenu,
+            nodes_selected: 0,
             current_logs: None,
+            logs_error: None,
             cluster_analysis: None,
+            dump_diff: None,
             log_filter: None,
+            log_search: LogSearch::default(),
+            expanded_log_entries: HashSet::new(),
+            log_detail_open: false,
+            log_detail_scroll: 0,
+            log_horizontal_scroll: 0,
+            log_view: LogView::Entries,
+            log_patterns: Vec::new(),
+            patterns_state: ListState::default(),
+            log_pattern_filter: None,
+            logs_origin: Screen::LogsList,
+            global_search: None,
+            global_query: LogSearch::default(),
+            global_level_filter: None,
+            global_search_editing: false,
+            global_search_selected: 0,
+            log_search_editing: false,
+            log_search_origin: (LogSearch::default(), 0),
             show_capybara: false,
         })
     }
@@ -93,74 +199,342 @@ impl App {
     }
 
     pub fn scroll_logs_down(&mut self) {
-        if let Some(ref logs) = self.current_logs {
-            let len = logs.entries.len();
-            if len == 0 {
-                return;
+        if self.log_view == LogView::Patterns {
+            let len = self.log_patterns.len();
+            if len > 0 {
+                let i = self.patterns_state.selected().map_or(0, |i| (i + 1) % len);
+                self.patterns_state.select(Some(i));
             }
+            return;
+        }
 
-            let i = match self.logs_scroll_state.selected() {
-                Some(i) => (i + 1) % len,
-                None => 0,
-            };
-            self.logs_scroll_state.select(Some(i));
+        let len = self.visible_log_indices().len();
+        let selected = self.logs_scroll_state.selected();
+        // Large files: past the last loaded entry comes the next window
+        if self.log_window_open() && selected.map_or(len == 0, |i| i + 1 >= len) {
+            self.shift_log_window(true);
+            return;
         }
+        if len == 0 {
+            return;
+        }
+
+        let i = match selected {
+            Some(i) => (i + 1) % len,
+            None => 0,
+        };
+        self.logs_scroll_state.select(Some(i));
+        self.log_detail_scroll = 0;
     }
 
     pub fn scroll_logs_up(&mut self) {
-        if let Some(ref logs) = self.current_logs {
-            let len = logs.entries.len();
-            if len == 0 {
-                return;
+        if self.log_view == LogView::Patterns {
+            let len = self.log_patterns.len();
+            if len > 0 {
+                let i = self
+                    .patterns_state
+                    .selected()
+                    .map_or(0, |i| (i + len - 1) % len);
+                self.patterns_state.select(Some(i));
             }
+            return;
+        }
 
-            let i = match self.logs_scroll_state.selected() {
-                Some(i) => (i + len - 1) % len,
-                None => 0,
-            };
-            self.logs_scroll_state.select(Some(i));
+        let len = self.visible_log_indices().len();
+        let selected = self.logs_scroll_state.selected();
+        if self.log_window_open() && selected.map_or(len == 0, |i| i == 0) {
+            self.shift_log_window(false);
+            return;
+        }
+        if len == 0 {
+            return;
         }
+
+        let i = match selected {
+            Some(i) => (i + len - 1) % len,
+            None => 0,
+        };
+        self.logs_scroll_state.select(Some(i));
+        self.log_detail_scroll = 0;
     }
 
-    pub fn scroll_details_down(&mut self) {
-        // For component details scrolling
-        if let Some(analysis) = &self.cluster_analysis {
-            let mut total_items = 0;
-            for ns_analysis in &analysis.namespaces {
-                total_items += ns_analysis.pods.len() + ns_analysis.deployments.len();
-            }
+    /// True when the logs viewer shows a window of a large log file.
+    fn log_window_open(&self) -> bool {
+        self.current_logs
+            .as_ref()
+            .is_some_and(|logs| logs.store.is_some())
+    }
 
-            if total_items == 0 {
-                return;
-            }
+    /// Load the next or previous window of a large log file, keeping the cursor on the
+    /// entry after (or before) the one it was on.
+    fn shift_log_window(&mut self, forward: bool) {
+        let selected_line = self.selected_log_entry().map(|entry| entry.line_number);
+        let Some(ref mut logs) = self.current_logs else {
+            return;
+        };
+        if !matches!(
+            logs.shift_window(forward, self.log_filter.as_deref()),
+            Ok(true)
+        ) {
+            return;
+        }
+
+        let visible = self.visible_log_indices();
+        let Some(last) = visible.len().checked_sub(1) else {
+            self.logs_scroll_state.select(Some(0));
+            return;
+        };
+        let position = match (self.current_logs.as_ref(), selected_line) {
+            (Some(logs), Some(line)) => visible
+                .iter()
+                .position(|&index| logs.entries[index].line_number == line),
+            _ => None,
+        };
+        let i = match (position, forward) {
+            (Some(position), true) => (position + 1).min(last),
+            (Some(position), false) => position.saturating_sub(1),
+            (None, true) => 0,
+            (None, false) => last,
+        };
+        self.logs_scroll_state.select(Some(i));
+        self.log_detail_scroll = 0;
+    }
 
-            let i = match self.details_scroll_state.selected() {
-                Some(i) => (i + 1) % total_items,
-                None => 0,
-            };
-            self.details_scroll_state.select(Some(i));
+    /// Follow the background indexing of a large log file. Returns true while it runs.
+    pub fn poll_log_store(&mut self) -> bool {
+        if self.current_screen != Screen::LogsViewer {
+            return false;
+        }
+        match self.current_logs {
+            Some(ref mut logs) => logs.poll_store(),
+            None => false,
         }
     }
 
-    pub fn scroll_details_up(&mut self) {
-        if let Some(analysis) = &self.cluster_analysis {
-            let mut total_items = 0;
-            for ns_analysis in &analysis.namespaces {
-                total_items += ns_analysis.pods.len() + ns_analysis.deployments.len();
-            }
+    pub fn scroll_logs_horizontally(&mut self, right: bool) {
+        self.log_horizontal_scroll = if right {
+            self.log_horizontal_scroll + HORIZONTAL_SCROLL_STEP
+        } else {
+            self.log_horizontal_scroll
+                .saturating_sub(HORIZONTAL_SCROLL_STEP)
+        };
+    }
+
+    pub fn toggle_log_detail(&mut self) {
+        self.log_detail_open = !self.log_detail_open;
+        self.log_detail_scroll = 0;
+    }
+
+    pub fn scroll_log_detail(&mut self, down: bool) {
+        self.log_detail_scroll = if down {
+            self.log_detail_scroll.saturating_add(5)
+        } else {
+            self.log_detail_scroll.saturating_sub(5)
+        };
+    }
+
+    /// The entry under the cursor of the logs viewer.
+    pub fn selected_log_entry(&self) -> Option<&LogEntry> {
+        let selected = self.logs_scroll_state.selected()?;
+        let index = *self.visible_log_indices().get(selected)?;
+        self.current_logs.as_ref()?.entries.get(index)
+    }
+
+    /// Indices into `current_logs.entries` of the entries passing the level filter.
+    pub fn visible_log_indices(&self) -> Vec<usize> {
+        let Some(ref logs) = self.current_logs else {
+            return Vec::new();
+        };
+        let visible = logs.visible_indices(self.log_filter.as_deref());
+        match self.log_pattern_filter {
+            Some(ref pattern) => visible
+                .into_iter()
+                .filter(|&index| {
+                    pattern
+                        .line_numbers
+                        .contains(&logs.entries[index].line_number)
+                })
+                .collect(),
+            None => visible,
+        }
+    }
+
+    /// Switch between the Entries and Patterns tabs.
+    pub fn toggle_log_view(&mut self) {
+        self.log_view = match self.log_view {
+            LogView::Entries => LogView::Patterns,
+            LogView::Patterns => LogView::Entries,
+        };
+        if self.log_view == LogView::Patterns {
+            self.log_pattern_filter = None;
+            self.refresh_log_patterns();
+        }
+    }
+
+    /// Group the entries passing the level filter into patterns.
+    pub fn refresh_log_patterns(&mut self) {
+        self.log_patterns = match self.current_logs {
+            Some(ref logs) => log_patterns(logs, &logs.visible_indices(self.log_filter.as_deref())),
+            None => Vec::new(),
+        };
+        self.patterns_state.select(Some(0));
+    }
 
-            if total_items == 0 {
-                return;
+    /// Show the entries of the selected pattern in the Entries tab.
+    pub fn open_selected_pattern(&mut self) {
+        let selected = self.patterns_state.selected().unwrap_or(0);
+        if let Some(pattern) = self.log_patterns.get(selected) {
+            self.log_pattern_filter = Some(pattern.clone());
+            self.log_view = LogView::Entries;
+            self.logs_scroll_state.select(Some(0));
+        }
+    }
+
+    /// Leave the drill-down into a pattern and return to the Patterns tab.
+    pub fn close_pattern_filter(&mut self) {
+        self.log_pattern_filter = None;
+        self.log_view = LogView::Patterns;
+        self.logs_scroll_state.select(Some(0));
+    }
+
+    /// Positions in the visible entries of the search hits.
+    pub fn log_search_hits(&self) -> Vec<usize> {
+        match self.current_logs {
+            Some(ref logs) => search_logs(logs, &self.visible_log_indices(), &self.log_search),
+            None => Vec::new(),
+        }
+    }
+
+    /// Show or hide the folded lines of the selected entry.
+    pub fn toggle_selected_log_entry(&mut self) {
+        let selected = self.logs_scroll_state.selected().unwrap_or(0);
+        let line_number = match (
+            self.current_logs.as_ref(),
+            self.visible_log_indices().get(selected),
+        ) {
+            (Some(logs), Some(&index)) if !logs.entries[index].continuation.is_empty() => {
+                logs.entries[index].line_number
             }
+            _ => return,
+        };
+        if !self.expanded_log_entries.remove(&line_number) {
+            self.expanded_log_entries.insert(line_number);
+        }
+    }
+
+    /// Expand every multi-line entry, or collapse them all if any is expanded.
+    pub fn toggle_all_log_entries(&mut self) {
+        if !self.expanded_log_entries.is_empty() {
+            self.expanded_log_entries.clear();
+        } else if let Some(ref logs) = self.current_logs {
+            self.expanded_log_entries = logs
+                .entries
+                .iter()
+                .filter(|entry| !entry.continuation.is_empty())
+                .map(|entry| entry.line_number)
+                .collect();
+        }
+    }
+
+    /// Open the `/` prompt with an empty query.
+    pub fn start_log_search(&mut self) {
+        let selected = self.logs_scroll_state.selected().unwrap_or(0);
+        self.log_search_origin = (self.log_search.clone(), selected);
+        self.log_search_editing = true;
+
+        let mut search = self.log_search.clone();
+        search.set_query(String::new());
+        self.log_search = search;
+    }
+
+    pub fn push_log_search_char(&mut self, c: char) {
+        let mut query = self.log_search.query.clone();
+        query.push(c);
+        self.update_log_search_query(query);
+    }
+
+    pub fn pop_log_search_char(&mut self) {
+        let mut query = self.log_search.query.clone();
+        query.pop();
+        self.update_log_search_query(query);
+    }
+
+    pub fn confirm_log_search(&mut self) {
+        self.log_search_editing = false;
+    }
+
+    /// Close the prompt and restore the previous search and selection.
+    pub fn cancel_log_search(&mut self) {
+        let (search, selected) = self.log_search_origin.clone();
+        self.log_search = search;
+        self.log_search_editing = false;
+        self.logs_scroll_state.select(Some(selected));
+    }
 
-            let i = match self.details_scroll_state.selected() {
-                Some(i) => (i + total_items - 1) % total_items,
-                None => 0,
-            };
-            self.details_scroll_state.select(Some(i));
+    pub fn toggle_log_search_case(&mut self) {
+        self.log_search.toggle_case_sensitive();
+        self.jump_to_search_hit_from(self.search_start());
+    }
+
+    pub fn toggle_log_search_regex(&mut self) {
+        self.log_search.toggle_regex();
+        self.jump_to_search_hit_from(self.search_start());
+    }
+
+    /// Select the next (or previous) search hit, wrapping around.
+    pub fn next_log_search_hit(&mut self, forward: bool) {
+        let hits = self.log_search_hits();
+        if hits.is_empty() {
+            return;
+        }
+
+        let selected = self.logs_scroll_state.selected().unwrap_or(0);
+        let hit = if forward {
+            hits.iter().find(|&&hit| hit > selected).or(hits.first())
+        } else {
+            hits.iter()
+                .rev()
+                .find(|&&hit| hit < selected)
+                .or(hits.last())
+        };
+        self.logs_scroll_state.select(hit.copied());
+    }
+
+    fn update_log_search_query(&mut self, query: String) {
+        self.log_search.set_query(query);
+        self.jump_to_search_hit_from(self.log_search_origin.1);
+    }
+
+    /// While typing, hits are searched from where the search started; afterwards from
+    /// the current selection.
+    fn search_start(&self) -> usize {
+        if self.log_search_editing {
+            self.log_search_origin.1
+        } else {
+            self.logs_scroll_state.selected().unwrap_or(0)
         }
     }
 
+    /// Select the first hit at or after `start`, wrapping around.
+    fn jump_to_search_hit_from(&mut self, start: usize) {
+        let hits = self.log_search_hits();
+        let hit = hits
+            .iter()
+            .find(|&&hit| hit >= start)
+            .or(hits.first())
+            .copied();
+        self.logs_scroll_state.select(Some(hit.unwrap_or(start)));
+    }
+
+    /// Scroll the ComponentDetails screen by one line. The screen stops at the last line.
+    pub fn scroll_details_down(&mut self) {
+        self.details_scroll = (self.details_scroll + 1).min(self.details_max_scroll);
+    }
+
+    pub fn scroll_details_up(&mut self) {
+        self.details_scroll = self.details_scroll.saturating_sub(1);
+    }
+
     pub fn toggle_log_filter(&mut self, filter: &str) {
         match self.log_filter.as_deref() {
             Some(current) if current == filter => {
@@ -171,104 +545,255 @@ impl App {
             }
         }
         self.logs_scroll_state.select(Some(0)); // Reset scroll position
+        if self.log_view == LogView::Patterns {
+            self.refresh_log_patterns();
+        }
+    }
+
+    pub fn clear_log_filter(&mut self) {
+        self.log_filter = None;
+        if self.log_view == LogView::Patterns {
+            self.refresh_log_patterns();
+        }
+    }
+
+    /// Hide the entries of the `number`th pod of merged workload logs, or show them again.
+    pub fn toggle_log_pod(&mut self, number: usize) {
+        if let Some(ref mut logs) = self.current_logs {
+            logs.toggle_pod(number);
+            self.pods_filter_changed();
+        }
+    }
+
+    /// Hide the entries of the pod of the selected entry, for pods past the 9th.
+    pub fn hide_selected_log_pod(&mut self) {
+        let Some(pod) = self
+            .selected_log_entry()
+            .and_then(entry_pod)
+            .map(str::to_string)
+        else {
+            return;
+        };
+        if let Some(ref mut logs) = self.current_logs {
+            logs.hidden_pods.insert(pod);
+            self.pods_filter_changed();
+        }
+    }
+
+    pub fn show_all_log_pods(&mut self) {
+        if let Some(ref mut logs) = self.current_logs {
+            logs.hidden_pods.clear();
+            self.pods_filter_changed();
+        }
+    }
+
+    fn pods_filter_changed(&mut self) {
+        self.logs_scroll_state.select(Some(0));
+        if self.log_view == LogView::Patterns {
+            self.refresh_log_patterns();
+        }
+    }
+
+    /// Switch the logs viewer to the next container of the current pod. The pod-level log
+    /// file, when there is one, is part of the cycle. Merged workload logs already show
+    /// every container.
+    pub fn cycle_log_container(&mut self) -> Result<()> {
+        let (namespace, pod_name, current, newest_first) = match self.current_logs {
+            Some(ref logs) if !logs.pods.is_empty() => return Ok(()),
+            Some(ref logs) => (
+                logs.namespace.clone(),
+                logs.component_name.clone(),
+                logs.container.clone(),
+                logs.newest_first,
+            ),
+            None => return Ok(()),
+        };
+
+        let mut sources: Vec<Option<String>> = Vec::new();
+        if has_pod_log_file(&self.dump, &namespace, &pod_name) {
+            sources.push(None);
+        }
+        sources.extend(
+            list_log_containers(&self.dump, &namespace, &pod_name)
+                .into_iter()
+                .map(Some),
+        );
+        if sources.len() < 2 {
+            return Ok(());
+        }
+
+        let position = sources.iter().position(|source| *source == current);
+        let next = match position {
+            Some(i) => &sources[(i + 1) % sources.len()],
+            None => &sources[0],
+        };
+
+        let mut logs = match next {
+            Some(container) => load_container_logs(&self.dump, &namespace, &pod_name, container)?,
+            None => load_pod_logs(&self.dump, &namespace, &pod_name)?,
+        };
+        // Keep the display order the user picked
+        if logs.newest_first != newest_first {
+            logs.toggle_order();
+        }
+        self.current_logs = Some(logs);
+        self.expanded_log_entries.clear();
+        self.log_pattern_filter = None;
+        if self.log_view == LogView::Patterns {
+            self.refresh_log_patterns();
+        }
+        self.logs_scroll_state.select(Some(0));
+        Ok(())
+    }
+
+    pub fn toggle_log_order(&mut self) {
+        if let Some(ref mut logs) = self.current_logs {
+            logs.toggle_order();
+            self.logs_scroll_state.select(Some(0));
+        }
     }
 
     pub fn select(&mut self) -> Result<()> {
+        let previous_screen = self.current_screen;
+        let result = self.select_current();
+
+        // Start every newly opened list and component at the top
+        if self.current_screen != previous_screen {
+            self.list_state.select(Some(0));
+            self.details_scroll = 0;
+            if self.current_screen == Screen::NodesList {
+                self.list_state.select(Some(self.nodes_selected));
+            }
+        }
+        result
+    }
+
+    fn select_current(&mut self) -> Result<()> {
         match self.current_screen {
             Screen::MainMenu => self.handle_main_menu_selection(),
             Screen::NamespaceList => self.handle_namespace_selection(),
             Screen::NamespaceDetails => self.handle_namespace_details_selection(),
             Screen::ClusterAnalysis => self.handle_cluster_analysis_selection(),
+            Screen::Issues => self.handle_issues_selection(),
             Screen::LogsList => self.handle_logs_list_selection(),
             Screen::PodsList => self.handle_pods_list_selection(),
             Screen::DeploymentsList => self.handle_deployments_list_selection(),
+            Screen::WorkloadsList => self.handle_workloads_list_selection(),
+            Screen::ServicesList => self.handle_services_list_selection(),
+            Screen::EventsList => self.handle_events_list_selection(),
+            Screen::GlobalSearch => self.handle_global_search_selection(),
+            Screen::Capacity => self.handle_capacity_selection(),
+            Screen::Diff => self.handle_diff_selection(),
             _ => Ok(()),
         }
     }
 
     pub fn back(&mut self) {
         match self.current_screen {
-            Screen::NamespaceList | Screen::ClusterAnalysis | Screen::Capybara => {
+            Screen::NamespaceList
+            | Screen::ClusterAnalysis
+            | Screen::Issues
+            | Screen::Capacity
+            | Screen::Diff
+            | Screen::Capybara => {
                 self.current_screen = Screen::MainMenu;
                 self.show_capybara = false;
             }
+            Screen::GlobalSearch => {
+                // Dropping the search stops its thread
+                self.global_search = None;
+                self.current_screen = Screen::MainMenu;
+            }
+            Screen::NodesList => {
+                self.current_screen = self.nodes_origin;
+                self.nodes_origin = Screen::MainMenu;
+            }
             Screen::NamespaceDetails => {
                 self.current_screen = Screen::NamespaceList;
             }
-            Screen::PodsList | Screen::DeploymentsList => {
+            Screen::PodsList
+            | Screen::DeploymentsList
+            | Screen::WorkloadsList
+            | Screen::ServicesList
+            | Screen::EventsList => {
                 self.current_screen = Screen::NamespaceDetails;
             }
             Screen::ComponentDetails => {
-                // Go back to the appropriate screen based on how we got here
-                match self.selected_component {
-                    Some((_, ref comp_type)) => match comp_type.as_str() {
-                        "Pod" => self.current_screen = Screen::PodsList,
-                        "Deployment" => self.current_screen = Screen::DeploymentsList,
-                        _ => self.current_screen = Screen::ClusterAnalysis,
-                    },
-                    None => self.current_screen = Screen::ClusterAnalysis,
-                }
+                // Go back to the screen we came from
+                self.current_screen = self.details_origin;
                 self.selected_component = None;
             }
             Screen::LogsList => {
                 self.current_screen = Screen::NamespaceDetails;
+                self.logs_error = None;
             }
             Screen::LogsViewer => {
-                self.current_screen = Screen::LogsList;
+                self.current_screen = self.logs_origin;
+                self.logs_origin = Screen::LogsList;
                 self.current_logs = None;
                 self.log_filter = None;
+                self.log_search = LogSearch::default();
+                self.expanded_log_entries.clear();
+                self.log_detail_open = false;
+                self.log_horizontal_scroll = 0;
+                self.log_view = LogView::Entries;
+                self.log_patterns.clear();
+                self.log_pattern_filter = None;
             }
             _ => {}
         }
         self.list_state.select(Some(0));
         self.logs_scroll_state.select(Some(0));
-        self.details_scroll_state.select(Some(0));
+        self.details_scroll = 0;
+        if self.current_screen == Screen::GlobalSearch {
+            self.list_state.select(Some(self.global_search_selected));
+        }
     }
 
     fn get_list_length(&self) -> usize {
         match self.current_screen {
-            Screen::MainMenu => 5, // Number of main menu items
+            Screen::MainMenu => self.get_main_menu_items().len(),
             Screen::NamespaceList => self.namespaces.len(),
-            Screen::NamespaceDetails => 3, // Pods, Deployments, Logs
+            Screen::NamespaceDetails => self.get_namespace_actions().len(),
             Screen::PodsList => self.pods.len(),
             Screen::DeploymentsList => self.deployments.len(),
+            Screen::WorkloadsList => self.workloads.count(self.workload_kind),
+            Screen::ServicesList => self.networking.services.len(),
+            Screen::EventsList => self.events.len(),
+            Screen::NodesList => self.nodes.len(),
+            Screen::GlobalSearch => self
+                .global_search
+                .as_ref()
+                .map(|search| search.hits.len())
+                .unwrap_or(0),
             Screen::ClusterAnalysis => {
-                // Count ONLY selectable components (pods and deployments)
-                if let Some(ref analysis) = self.cluster_analysis {
-                    let mut count = 0;
-                    for ns_analysis in &analysis.namespaces {
-                        count += ns_analysis.pods.len() + ns_analysis.deployments.len();
-                    }
-                    count
-                } else {
-                    0
-                }
+                // Count ONLY selectable components (no namespace headers)
+                self.cluster_analysis
+                    .as_ref()
+                    .map(|analysis| analysis.component_count())
+                    .unwrap_or(0)
             }
+            Screen::Issues => self
+                .cluster_analysis
+                .as_ref()
+                .map(|analysis| analysis.total_issues)
+                .unwrap_or(0),
+            Screen::Capacity => self
+                .cluster_analysis
+                .as_ref()
+                .map(|analysis| analysis.capacity.nodes.len() + analysis.capacity.namespaces.len())
+                .unwrap_or(0),
+            Screen::Diff => self
+                .dump_diff
+                .as_ref()
+                .map(|diff| diff.rows().len())
+                .unwrap_or(0),
             Screen::LogsList => {
                 // Count actual log directories in the namespace
-                if let Some(ref namespace) = self.selected_namespace {
-                    let namespace_dir = format!("output/{}", namespace);
-                    if let Ok(entries) = std::fs::read_dir(&namespace_dir) {
-                        return entries
-                            .flatten()
-                            .filter(|entry| {
-                                if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
-                                    let component_path = entry.path();
-                                    [
-                                        component_path.join("logs.txt"),
-                                        component_path.join("log.txt"),
-                                        component_path.join("logs.json"),
-                                    ]
-                                    .iter()
-                                    .any(|path| path.exists())
-                                } else {
-                                    false
-                                }
-                            })
-                            .count();
-                    }
+                match self.selected_namespace {
+                    Some(ref namespace) => list_log_components(&self.dump, namespace).len(),
+                    None => 0,
                 }
-                0
             }
             _ => 0,
         }
@@ -279,16 +804,44 @@ impl App {
             match selected {
                 0 => {
                     // Load cluster analysis when entering
-                    self.cluster_analysis = Some(crate::kubernetes::analyze_cluster()?);
+                    self.cluster_analysis =
+                        Some(crate::kubernetes::analyze_cluster(&self.dump, &self.rules)?);
                     self.current_screen = Screen::ClusterAnalysis;
                 }
-                1 => self.current_screen = Screen::NamespaceList,
-                2 => {
+                1 => {
+                    self.cluster_analysis =
+                        Some(crate::kubernetes::analyze_cluster(&self.dump, &self.rules)?);
+                    self.current_screen = Screen::Issues;
+                }
+                2 => self.current_screen = Screen::NamespaceList,
+                3 => self.open_nodes(Screen::MainMenu, None),
+                4 => {
+                    self.cluster_analysis =
+                        Some(crate::kubernetes::analyze_cluster(&self.dump, &self.rules)?);
+                    self.current_screen = Screen::Capacity;
+                }
+                5 => {
+                    self.dump_diff = match self.baseline {
+                        Some(ref baseline) => {
+                            let before = crate::kubernetes::analyze_cluster(baseline, &self.rules)?;
+                            let after =
+                                crate::kubernetes::analyze_cluster(&self.dump, &self.rules)?;
+                            Some(DumpDiff::new(&before, &after))
+                        }
+                        None => None,
+                    };
+                    self.current_screen = Screen::Diff;
+                }
+                6 => {
+                    self.current_screen = Screen::GlobalSearch;
+                    self.global_search_editing = true;
+                }
+                7 => {
                     self.current_screen = Screen::Capybara;
                     self.show_capybara = true;
                 }
-                3 => {}                         // Help - do nothing for now
-                4 => return Err("exit".into()), // Exit
+                8 => {}                         // Help - do nothing for now
+                9 => return Err("exit".into()), // Exit
                 _ => {}
             }
         }
@@ -298,12 +851,11 @@ impl App {
     fn handle_namespace_selection(&mut self) -> Result<()> {
         if let Some(selected) = self.list_state.selected() {
             if selected < self.namespaces.len() {
-                let namespace = &self.namespaces[selected];
-                self.selected_namespace = Some(namespace.name.clone());
+                let namespace = self.namespaces[selected].name.clone();
 
                 // Load namespace details
-                self.pods = load_pods(&namespace.name)?;
-                self.deployments = load_deployments(&namespace.name)?;
+                self.load_namespace(&namespace);
+                self.selected_namespace = Some(namespace);
 
                 self.current_screen = Screen::NamespaceDetails;
             }
@@ -312,22 +864,22 @@ impl App {
     }
 
     fn handle_namespace_details_selection(&mut self) -> Result<()> {
-        if let Some(selected) = self.list_state.selected() {
-            match selected {
-                0 => {
-                    // View Pods
-                    self.current_screen = Screen::PodsList;
-                }
-                1 => {
-                    // View Deployments
-                    self.current_screen = Screen::DeploymentsList;
-                }
-                2 => {
-                    // View Logs
-                    self.current_screen = Screen::LogsList;
-                }
-                _ => {}
+        let action = self
+            .list_state
+            .selected()
+            .and_then(|selected| self.get_namespace_actions().get(selected).copied());
+
+        match action {
+            Some(NamespaceAction::Pods) => self.current_screen = Screen::PodsList,
+            Some(NamespaceAction::Deployments) => self.current_screen = Screen::DeploymentsList,
+            Some(NamespaceAction::Workloads(kind)) => {
+                self.workload_kind = kind;
+                self.current_screen = Screen::WorkloadsList;
             }
+            Some(NamespaceAction::Services) => self.current_screen = Screen::ServicesList,
+            Some(NamespaceAction::Events) => self.current_screen = Screen::EventsList,
+            Some(NamespaceAction::Logs) => self.current_screen = Screen::LogsList,
+            None => {}
         }
         Ok(())
     }
@@ -337,6 +889,7 @@ impl App {
             if selected < self.pods.len() {
                 let pod = &self.pods[selected];
                 self.selected_component = Some((pod.name.clone(), "Pod".to_string()));
+                self.details_origin = Screen::PodsList;
                 self.current_screen = Screen::ComponentDetails;
             }
         }
@@ -348,6 +901,44 @@ impl App {
             if selected < self.deployments.len() {
                 let deployment = &self.deployments[selected];
                 self.selected_component = Some((deployment.name.clone(), "Deployment".to_string()));
+                self.details_origin = Screen::DeploymentsList;
+                self.current_screen = Screen::ComponentDetails;
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_workloads_list_selection(&mut self) -> Result<()> {
+        if let Some(selected) = self.list_state.selected() {
+            let summaries = self.workloads.summaries(self.workload_kind);
+            if let Some(summary) = summaries.get(selected) {
+                self.selected_component =
+                    Some((summary.name.clone(), self.workload_kind.label().to_string()));
+                self.details_origin = Screen::WorkloadsList;
+                self.current_screen = Screen::ComponentDetails;
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_services_list_selection(&mut self) -> Result<()> {
+        if let Some(selected) = self.list_state.selected() {
+            if let Some(service) = self.networking.services.get(selected) {
+                self.selected_component = Some((service.name.clone(), "Service".to_string()));
+                self.details_origin = Screen::ServicesList;
+                self.current_screen = Screen::ComponentDetails;
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_events_list_selection(&mut self) -> Result<()> {
+        // Jump to the object the event is about
+        if let Some(selected) = self.list_state.selected() {
+            if let Some(event) = self.events.get(selected) {
+                self.selected_component =
+                    Some((event.involved_name.clone(), event.involved_kind.clone()));
+                self.details_origin = Screen::EventsList;
                 self.current_screen = Screen::ComponentDetails;
             }
         }
@@ -357,24 +948,18 @@ impl App {
     fn handle_cluster_analysis_selection(&mut self) -> Result<()> {
         if let Some(selected) = self.list_state.selected() {
             if let Some(ref analysis) = self.cluster_analysis {
-                let mut all_components = Vec::new();
-
                 // Collect all components from all namespaces
-                for ns_analysis in &analysis.namespaces {
-                    // Add pods
-                    for pod in &ns_analysis.pods {
-                        all_components.push((pod.name.clone(), "Pod".to_string()));
-                    }
-                    // Add deployments
-                    for deployment in &ns_analysis.deployments {
-                        all_components.push((deployment.name.clone(), "Deployment".to_string()));
-                    }
-                }
+                let all_components: Vec<(String, String)> = analysis
+                    .namespaces
+                    .iter()
+                    .flat_map(|ns_analysis| ns_analysis.components())
+                    .collect();
 
                 if selected < all_components.len() {
                     let (component_name, component_type) = &all_components[selected];
                     self.selected_component =
                         Some((component_name.clone(), component_type.clone()));
+                    self.details_origin = Screen::ClusterAnalysis;
                     self.current_screen = Screen::ComponentDetails;
                 }
             }
@@ -382,34 +967,306 @@ impl App {
         Ok(())
     }
 
+    fn handle_issues_selection(&mut self) -> Result<()> {
+        let selected = match self.list_state.selected() {
+            Some(selected) => selected,
+            None => return Ok(()),
+        };
+
+        // Flatten the issues in the same order as the dashboard displays them
+        let issue = self.cluster_analysis.as_ref().and_then(|analysis| {
+            analysis
+                .issues_by_namespace()
+                .into_iter()
+                .flat_map(|(_, issues)| issues)
+                .nth(selected)
+                .cloned()
+        });
+
+        if let Some(issue) = issue {
+            if issue.component_type == "Node" {
+                self.open_nodes(Screen::Issues, Some(&issue.component));
+                return Ok(());
+            }
+            self.open_component(
+                &issue.namespace,
+                issue.component,
+                issue.component_type,
+                Screen::Issues,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// New and changed issues and added or changed objects of the Diff screen are in the
+    /// current dump: open them. Resolved issues and removed objects are only in the
+    /// baseline.
+    fn handle_diff_selection(&mut self) -> Result<()> {
+        let selected = self.list_state.selected().unwrap_or(0);
+        let target = self
+            .dump_diff
+            .as_ref()
+            .and_then(|diff| match diff.rows().get(selected)? {
+                DiffRow::NewIssue(issue) => Some((
+                    issue.namespace.clone(),
+                    issue.component.clone(),
+                    issue.component_type.clone(),
+                )),
+                DiffRow::ChangedIssue(change) => Some((
+                    change.after.namespace.clone(),
+                    change.after.component.clone(),
+                    change.after.component_type.clone(),
+                )),
+                DiffRow::Change(object) if object.change != ChangeKind::Removed => Some((
+                    object.namespace.clone(),
+                    object.name.clone(),
+                    object.kind.clone(),
+                )),
+                _ => None,
+            });
+
+        match target {
+            Some((_, name, kind)) if kind == "Node" => {
+                self.open_nodes(Screen::Diff, Some(&name));
+            }
+            Some((namespace, name, kind)) if !namespace.is_empty() => {
+                self.open_component(&namespace, name, kind, Screen::Diff)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Load the pods, deployments, workloads, networking, events and ownership that the
+    /// namespace screens show, reading the namespace's objects once.
+    fn load_namespace(&mut self, namespace: &str) {
+        let ns_analysis = load_namespace_analysis(&self.dump, namespace);
+        self.pods = ns_analysis.pods;
+        self.deployments = ns_analysis.deployments;
+        self.workloads = ns_analysis.workloads;
+        self.networking = ns_analysis.networking;
+        self.events = ns_analysis.events;
+        self.ownership = ns_analysis.ownership;
+    }
+
+    /// Load `namespace` so the details view finds the component, and show it.
+    fn open_component(
+        &mut self,
+        namespace: &str,
+        name: String,
+        component_type: String,
+        origin: Screen,
+    ) -> Result<()> {
+        self.load_namespace(namespace);
+        self.selected_namespace = Some(namespace.to_string());
+        self.selected_component = Some((name, component_type));
+        self.details_origin = origin;
+        self.current_screen = Screen::ComponentDetails;
+        Ok(())
+    }
+
+    /// Open the logs of the component shown by ComponentDetails: the logs of a pod, or the
+    /// merged logs of the pods a workload owns.
+    pub fn open_component_logs(&mut self) -> Result<()> {
+        let Some((name, component_type)) = self.selected_component.clone() else {
+            return Ok(());
+        };
+        let Some(namespace) = self.component_namespace(&name, &component_type) else {
+            return Ok(());
+        };
+
+        let logs = if component_type == "Pod" {
+            load_pod_logs(&self.dump, &namespace, &name)?
+        } else {
+            let ownership = load_ownership(&self.dump, &namespace);
+            let pods = ownership.pods_of(&component_type, &name);
+            load_workload_logs(&self.dump, &namespace, &component_type, &name, &pods)?
+        };
+        self.current_logs = Some(logs);
+        self.selected_namespace = Some(namespace);
+        self.logs_origin = Screen::ComponentDetails;
+        self.current_screen = Screen::LogsViewer;
+        Ok(())
+    }
+
+    /// Namespace of a component: the browsed namespace when it has it, otherwise the
+    /// first namespace of the cluster analysis that does.
+    fn component_namespace(&self, name: &str, component_type: &str) -> Option<String> {
+        let loaded = match component_type {
+            "Pod" => self.pods.iter().any(|pod| pod.name == name),
+            "Deployment" => self
+                .deployments
+                .iter()
+                .any(|deployment| deployment.name == name),
+            kind => WorkloadKind::from_label(kind).is_some_and(|kind| {
+                self.workloads
+                    .summaries(kind)
+                    .iter()
+                    .any(|summary| summary.name == name)
+            }),
+        };
+        if loaded && self.selected_namespace.is_some() {
+            return self.selected_namespace.clone();
+        }
+
+        let component = (name.to_string(), component_type.to_string());
+        self.cluster_analysis
+            .as_ref()?
+            .namespaces
+            .iter()
+            .find(|ns_analysis| ns_analysis.components().contains(&component))
+            .map(|ns_analysis| ns_analysis.name.clone())
+    }
+
+    /// Rows of the Capacity screen are the nodes, then the namespaces: open the Nodes
+    /// screen on the selected node.
+    fn handle_capacity_selection(&mut self) -> Result<()> {
+        let selected = self.list_state.selected().unwrap_or(0);
+        let node = self
+            .cluster_analysis
+            .as_ref()
+            .and_then(|analysis| analysis.capacity.nodes.get(selected))
+            .map(|allocation| allocation.node.clone());
+        if let Some(node) = node {
+            self.open_nodes(Screen::Capacity, Some(&node));
+        }
+        Ok(())
+    }
+
+    /// Load the nodes and the pods scheduled on them and show the Nodes screen, on
+    /// `node` if given.
+    fn open_nodes(&mut self, origin: Screen, node: Option<&str>) {
+        self.nodes = load_nodes(&self.dump);
+        self.node_pods = load_scheduled_pods(&self.dump);
+        self.nodes_selected = node
+            .and_then(|name| self.nodes.iter().position(|node| node.name == name))
+            .unwrap_or(0);
+        self.nodes_origin = origin;
+        self.current_screen = Screen::NodesList;
+    }
+
+    /// The selected node on the Nodes screen and the pods scheduled on it.
+    pub fn selected_node(&self) -> Option<(&NodeInfo, Vec<&(String, PodInfo)>)> {
+        let node = self.nodes.get(self.list_state.selected()?)?;
+        let pods = self
+            .node_pods
+            .iter()
+            .filter(|(_, pod)| pod.node_name.as_deref() == Some(node.name.as_str()))
+            .collect();
+        Some((node, pods))
+    }
+
+    /// Open the selected hit in the logs viewer, on the matching entry.
+    fn handle_global_search_selection(&mut self) -> Result<()> {
+        let selected = self.list_state.selected().unwrap_or(0);
+        let hit = match self.global_search {
+            Some(ref search) => match search.hits.get(selected) {
+                Some(hit) => hit.clone(),
+                None => return Ok(()),
+            },
+            None => return Ok(()),
+        };
+
+        let mut logs = match hit.container {
+            Some(ref container) => {
+                load_container_logs(&self.dump, &hit.namespace, &hit.pod, container)?
+            }
+            None => load_pod_logs(&self.dump, &hit.namespace, &hit.pod)?,
+        };
+        logs.show_line(hit.line_number)?;
+        self.selected_namespace = Some(hit.namespace.clone());
+        self.current_logs = Some(logs);
+        self.log_search = self.global_query.clone();
+        self.log_filter = self.global_level_filter.clone();
+        self.logs_origin = Screen::GlobalSearch;
+        self.global_search_selected = selected;
+        self.current_screen = Screen::LogsViewer;
+
+        let position = match self.current_logs {
+            Some(ref logs) => self
+                .visible_log_indices()
+                .iter()
+                .position(|&index| logs.entries[index].line_number == hit.line_number),
+            None => None,
+        };
+        self.logs_scroll_state.select(Some(position.unwrap_or(0)));
+        Ok(())
+    }
+
+    /// Start a new global search with the current query, options and level filter.
+    pub fn start_global_search(&mut self) {
+        self.global_search_editing = false;
+        self.list_state.select(Some(0));
+        self.global_search = if self.global_query.is_active() {
+            Some(GlobalSearch::start(
+                self.dump.clone(),
+                self.global_query.clone(),
+                self.global_level_filter.clone(),
+            ))
+        } else {
+            None
+        };
+    }
+
+    /// Collect the hits found by the running global search, if any.
+    pub fn poll_global_search(&mut self) {
+        if let Some(ref mut search) = self.global_search {
+            search.poll();
+        }
+    }
+
+    pub fn global_search_running(&self) -> bool {
+        self.global_search
+            .as_ref()
+            .map(|search| !search.done)
+            .unwrap_or(false)
+    }
+
+    pub fn edit_global_search(&mut self) {
+        self.global_search_editing = true;
+    }
+
+    pub fn push_global_query_char(&mut self, c: char) {
+        let mut query = self.global_query.query.clone();
+        query.push(c);
+        self.global_query.set_query(query);
+    }
+
+    pub fn pop_global_query_char(&mut self) {
+        let mut query = self.global_query.query.clone();
+        query.pop();
+        self.global_query.set_query(query);
+    }
+
+    /// Toggle case sensitivity and search again, unless the query is being typed.
+    pub fn toggle_global_search_case(&mut self) {
+        self.global_query.toggle_case_sensitive();
+        if !self.global_search_editing {
+            self.start_global_search();
+        }
+    }
+
+    pub fn toggle_global_search_regex(&mut self) {
+        self.global_query.toggle_regex();
+        if !self.global_search_editing {
+            self.start_global_search();
+        }
+    }
+
+    /// Same toggling as the logs viewer level filter; `None` shows every level.
+    pub fn set_global_level_filter(&mut self, filter: Option<&str>) {
+        self.global_level_filter = match (self.global_level_filter.as_deref(), filter) {
+            (Some(current), Some(filter)) if current == filter => None,
+            (_, filter) => filter.map(|filter| filter.to_string()),
+        };
+        self.start_global_search();
+    }
+
     fn handle_logs_list_selection(&mut self) -> Result<()> {
         if let Some(selected) = self.list_state.selected() {
             if let Some(ref namespace) = self.selected_namespace {
                 // Get list of actual log directories
-                let namespace_dir = format!("output/{}", namespace);
-                let mut log_components = Vec::new();
-
-                if let Ok(entries) = std::fs::read_dir(&namespace_dir) {
-                    for entry in entries.flatten() {
-                        if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
-                            let component_name = entry.file_name().to_string_lossy().to_string();
-                            let component_path = entry.path();
-
-                            // Check if this directory has logs
-                            let has_logs = [
-                                component_path.join("logs.txt"),
-                                component_path.join("log.txt"),
-                                component_path.join("logs.json"),
-                            ]
-                            .iter()
-                            .any(|path| path.exists());
-
-                            if has_logs {
-                                log_components.push(component_name);
-                            }
-                        }
-                    }
-                }
+                let log_components = list_log_components(&self.dump, namespace);
 
                 if selected < log_components.len() {
                     let component_name = &log_components[selected];
@@ -417,16 +1274,15 @@ impl App {
                         Some((component_name.clone(), "Component".to_string()));
 
                     // Load component logs using the updated function
-                    match crate::logs::load_pod_logs(namespace, component_name) {
+                    match load_pod_logs(&self.dump, namespace, component_name) {
                         Ok(logs) => {
                             self.current_logs = Some(logs);
+                            self.logs_error = None;
                             self.current_screen = Screen::LogsViewer;
                         }
                         Err(e) => {
-                            eprintln!(
-                                "Warning: Could not load logs for component {}: {}",
-                                component_name, e
-                            );
+                            self.logs_error =
+                                Some(format!("Could not load logs for {}: {}", component_name, e));
                         }
                     }
                 }
@@ -438,21 +1294,60 @@ impl App {
     pub fn get_main_menu_items(&self) -> Vec<&'static str> {
         vec![
             "🔍 Cluster Analysis",
+            "🚨 Issues Dashboard",
             "📁 Browse Namespaces",
+            "🖥️ Nodes",
+            "📊 Capacity",
+            "🔀 Diff",
+            "🔎 Search All Logs",
             "🐹 Capybara Easter Egg",
             "❓ Help",
             "🚪 Exit",
         ]
     }
 
-    pub fn get_namespace_details_items(&self) -> Vec<String> {
-        let pod_count = self.pods.len();
-        let deployment_count = self.deployments.len();
+    /// Namespace menu: pods, deployments, services and events always, other workload kinds
+    /// only when present.
+    pub fn get_namespace_actions(&self) -> Vec<NamespaceAction> {
+        let mut actions = vec![NamespaceAction::Pods, NamespaceAction::Deployments];
+        for kind in WorkloadKind::ALL {
+            if self.workloads.count(kind) > 0 {
+                actions.push(NamespaceAction::Workloads(kind));
+            }
+        }
+        actions.push(NamespaceAction::Services);
+        actions.push(NamespaceAction::Events);
+        actions.push(NamespaceAction::Logs);
+        actions
+    }
 
-        vec![
-            format!("📦 View Pods ({})", pod_count),
-            format!("🚀 View Deployments ({})", deployment_count),
-            format!("📋 View Logs"),
-        ]
+    pub fn get_namespace_details_items(&self) -> Vec<String> {
+        self.get_namespace_actions()
+            .into_iter()
+            .map(|action| match action {
+                NamespaceAction::Pods => format!("📦 View Pods ({})", self.pods.len()),
+                NamespaceAction::Deployments => {
+                    format!("🚀 View Deployments ({})", self.deployments.len())
+                }
+                NamespaceAction::Workloads(kind) => format!(
+                    "{} View {} ({})",
+                    kind.icon(),
+                    kind.plural(),
+                    self.workloads.count(kind)
+                ),
+                NamespaceAction::Services => {
+                    format!("🌐 View Services ({})", self.networking.services.len())
+                }
+                NamespaceAction::Events => {
+                    let warnings = self.events.iter().filter(|e| e.is_warning()).count();
+                    format!(
+                        "📅 View Events ({}, {} warnings)",
+                        self.events.len(),
+                        warnings
+                    )
+                }
+                NamespaceAction::Logs => "📋 View Logs".to_string(),
+            })
+            .collect()
     }
 }
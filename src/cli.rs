@@ -0,0 +1,207 @@
+use std::path::PathBuf;
+
+use crate::config::{CONFIG_ENV, DUMP_ENV};
+use crate::error::Result;
+use crate::report::ReportFormat;
+
+/// Exit status of `analyze` when the dump has Critical issues, and of `diff` when the
+/// second dump has Critical issues the first one did not have.
+pub const EXIT_CRITICAL_ISSUES: i32 = 2;
+
+#[derive(Debug)]
+pub enum Command {
+    Tui,
+    Analyze {
+        format: ReportFormat,
+    },
+    Diff {
+        before: PathBuf,
+        after: PathBuf,
+        format: ReportFormat,
+    },
+    Rules,
+    Help,
+    Version,
+}
+
+#[derive(Debug)]
+pub struct CliArgs {
+    pub dump: Option<PathBuf>,
+    pub config: Option<PathBuf>,
+    pub baseline: Option<PathBuf>, // Earlier dump the TUI compares the dump with
+    pub format: Option<ReportFormat>, // Applied to the report command once all arguments are read
+    pub command: Command,
+}
+
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<CliArgs> {
+    let mut cli = CliArgs {
+        dump: None,
+        config: None,
+        baseline: None,
+        format: None,
+        command: Command::Tui,
+    };
+
+    // Positional arguments, the two dumps of 'diff'. Options may come between them.
+    let mut operands = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-d" | "--dump" => cli.dump = Some(PathBuf::from(expect_value(&mut args, &arg)?)),
+            "-c" | "--config" => cli.config = Some(PathBuf::from(expect_value(&mut args, &arg)?)),
+            "-b" | "--baseline" => {
+                cli.baseline = Some(PathBuf::from(expect_value(&mut args, &arg)?))
+            }
+            "-h" | "--help" => cli.command = Command::Help,
+            "-V" | "--version" => cli.command = Command::Version,
+            "analyze" => {
+                cli.command = Command::Analyze {
+                    format: ReportFormat::Text,
+                }
+            }
+            "diff" if !matches!(cli.command, Command::Diff { .. }) => {
+                cli.command = Command::Diff {
+                    before: PathBuf::new(),
+                    after: PathBuf::new(),
+                    format: ReportFormat::Text,
+                }
+            }
+            "rules" => cli.command = Command::Rules,
+            "-f" | "--format" => {
+                cli.format = Some(ReportFormat::parse(&expect_value(&mut args, &arg)?)?)
+            }
+            _ => {
+                if let Some(value) = arg.strip_prefix("--dump=") {
+                    cli.dump = Some(PathBuf::from(value));
+                } else if let Some(value) = arg.strip_prefix("--config=") {
+                    cli.config = Some(PathBuf::from(value));
+                } else if let Some(value) = arg.strip_prefix("--baseline=") {
+                    cli.baseline = Some(PathBuf::from(value));
+                } else if let Some(value) = arg.strip_prefix("--format=") {
+                    cli.format = Some(ReportFormat::parse(value)?);
+                } else if !arg.starts_with('-') {
+                    operands.push(PathBuf::from(arg));
+                } else {
+                    return Err(format!("Unknown argument: {}", arg).into());
+                }
+            }
+        }
+    }
+
+    if let Command::Diff {
+        ref mut before,
+        ref mut after,
+        ..
+    } = cli.command
+    {
+        let [first, second] = <[PathBuf; 2]>::try_from(operands)
+            .map_err(|_| "'diff' needs two dumps: diff <BEFORE> <AFTER>")?;
+        *before = first;
+        *after = second;
+    } else if let Some(operand) = operands.first() {
+        return Err(format!("Unknown argument: {}", operand.display()).into());
+    }
+
+    // Like the other options, --format may come before or after the command
+    if let Some(value) = cli.format {
+        match cli.command {
+            Command::Analyze { ref mut format } | Command::Diff { ref mut format, .. } => {
+                *format = value;
+            }
+            _ => return Err("--format is only valid with the 'analyze' or 'diff' command".into()),
+        }
+    }
+
+    Ok(cli)
+}
+
+fn expect_value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String> {
+    args.next()
+        .ok_or_else(|| format!("Missing value for {}", flag).into())
+}
+
+pub fn usage() -> String {
+    format!(
+        "🐹 Capybara Hacker - Kubernetes Cluster Analyzer
+
+Usage: k8s-analyzer [OPTIONS] [COMMAND]
+
+Commands:
+  analyze               Print the cluster analysis report and exit
+                        (exit status {} when Critical issues are found)
+  diff <BEFORE> <AFTER> Compare two dumps of the same cluster: added, removed
+                        and changed objects, new and resolved issues
+                        (exit status {} when new Critical issues appear)
+  rules                 List the health check rules and their settings
+
+Options:
+  -d, --dump <DIR>      Cluster dump directory (default: ./output), or a
+                        .tar, .tar.gz, .tar.zst or .zip archive of one
+  -c, --config <FILE>   Config file (default: ./k8s-analyzer.json or
+                        ~/.config/k8s-analyzer/config.json); its 'rules'
+                        section enables, disables or re-severities rules
+  -b, --baseline <DIR>  Earlier dump to compare the dump with on the Diff
+                        screen of the TUI
+  -f, --format <FMT>    Report format for 'analyze' and 'diff': text, json
+                        or markdown
+  -h, --help            Show this help
+  -V, --version         Show version
+
+Environment:
+  {}     Cluster dump directory
+  {}   Config file",
+        EXIT_CRITICAL_ISSUES, EXIT_CRITICAL_ISSUES, DUMP_ENV, CONFIG_ENV
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<CliArgs> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn diff_of(cli: CliArgs) -> (PathBuf, PathBuf, ReportFormat) {
+        match cli.command {
+            Command::Diff {
+                before,
+                after,
+                format,
+            } => (before, after, format),
+            other => panic!("expected diff, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn diff_options_before_or_between_the_dumps() {
+        for args in [
+            &["diff", "-f", "json", "/tmp/a", "/tmp/b"][..],
+            &["diff", "/tmp/a", "/tmp/b", "--format", "json"],
+            &["-f", "json", "diff", "/tmp/a", "/tmp/b"],
+            &["diff", "/tmp/a", "--format=json", "/tmp/b"],
+        ] {
+            let (before, after, format) = diff_of(parse(args).unwrap());
+            assert_eq!(before, PathBuf::from("/tmp/a"), "{:?}", args);
+            assert_eq!(after, PathBuf::from("/tmp/b"), "{:?}", args);
+            assert!(matches!(format, ReportFormat::Json), "{:?}", args);
+        }
+    }
+
+    #[test]
+    fn diff_needs_exactly_two_dumps() {
+        assert!(parse(&["diff", "/tmp/a"]).is_err());
+        assert!(parse(&["diff", "-f", "json", "/tmp/a"]).is_err());
+        assert!(parse(&["diff", "/tmp/a", "/tmp/b", "/tmp/c"]).is_err());
+    }
+
+    #[test]
+    fn stray_operands_are_rejected() {
+        assert!(parse(&["analyze", "/tmp/a"]).is_err());
+        assert!(parse(&["/tmp/a"]).is_err());
+        assert!(matches!(
+            parse(&["analyze"]).unwrap().command,
+            Command::Analyze { .. }
+        ));
+    }
+}
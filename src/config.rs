@@ -0,0 +1,95 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::dump::{DumpRoot, DEFAULT_DUMP_DIR};
+use crate::error::Result;
+use crate::rules::RuleConfig;
+
+/// Environment variable pointing at the dump directory.
+pub const DUMP_ENV: &str = "K8S_ANALYZER_DUMP";
+/// Environment variable pointing at the config file.
+pub const CONFIG_ENV: &str = "K8S_ANALYZER_CONFIG";
+
+const LOCAL_CONFIG_FILE: &str = "k8s-analyzer.json";
+
+/// Settings read from the JSON config file.
+///
+/// Example `k8s-analyzer.json`:
+///
+/// ```json
+/// {
+///   "dump": "/var/dumps/incident-42",
+///   "rules": { "latest-tag": { "enabled": false }, "high-restarts": { "severity": "critical" } }
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    pub dump: Option<PathBuf>,
+    pub rules: HashMap<String, RuleConfig>,
+}
+
+impl Config {
+    /// Load the config file, looking (in order) at the explicit path, `$K8S_ANALYZER_CONFIG`,
+    /// `./k8s-analyzer.json` and `~/.config/k8s-analyzer/config.json`.
+    ///
+    /// An explicitly requested file must exist; the default locations are optional.
+    pub fn load(explicit: Option<&Path>) -> Result<Config> {
+        if let Some(path) = explicit {
+            return Config::from_file(path);
+        }
+        if let Some(path) = env::var_os(CONFIG_ENV) {
+            return Config::from_file(Path::new(&path));
+        }
+
+        let mut candidates = vec![PathBuf::from(LOCAL_CONFIG_FILE)];
+        if let Some(home) = env::var_os("HOME") {
+            candidates.push(
+                PathBuf::from(home)
+                    .join(".config")
+                    .join("k8s-analyzer")
+                    .join("config.json"),
+            );
+        }
+
+        for candidate in candidates {
+            if candidate.is_file() {
+                return Config::from_file(&candidate);
+            }
+        }
+
+        Ok(Config::default())
+    }
+
+    fn from_file(path: &Path) -> Result<Config> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read config file {}: {}", path.display(), e))?;
+        let json: Value = serde_json::from_str(&content)
+            .map_err(|e| format!("Invalid config file {}: {}", path.display(), e))?;
+
+        // Relative paths in the config file are relative to the file itself
+        let base_dir = path.parent().unwrap_or(Path::new(""));
+        let dump = json["dump"].as_str().map(|dump| base_dir.join(dump));
+
+        let mut rules = HashMap::new();
+        if let Some(rule_overrides) = json["rules"].as_object() {
+            for (rule_id, rule_json) in rule_overrides {
+                rules.insert(rule_id.clone(), RuleConfig::from_json(rule_id, rule_json)?);
+            }
+        }
+
+        Ok(Config { dump, rules })
+    }
+}
+
+/// Pick the dump directory: command line, then `$K8S_ANALYZER_DUMP`, then the config file,
+/// then `./output`. The dump can be a directory or an archive of one.
+pub fn resolve_dump_root(cli_dump: Option<PathBuf>, config: &Config) -> Result<DumpRoot> {
+    let path = cli_dump
+        .or_else(|| env::var_os(DUMP_ENV).map(PathBuf::from))
+        .or_else(|| config.dump.clone())
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_DUMP_DIR));
+    DumpRoot::open(path)
+}
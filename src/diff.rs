@@ -0,0 +1,698 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use crate::kubernetes::{ClusterAnalysis, ClusterIssue, DeploymentInfo, IssueSeverity, PodInfo};
+use crate::nodes::NodeInfo;
+use crate::workloads::{WorkloadKind, WorkloadSummary};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl ChangeKind {
+    pub const ALL: [ChangeKind; 3] = [ChangeKind::Added, ChangeKind::Removed, ChangeKind::Changed];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChangeKind::Added => "added",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Changed => "changed",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ChangeKind::Added => "➕",
+            ChangeKind::Removed => "➖",
+            ChangeKind::Changed => "✏️",
+        }
+    }
+}
+
+/// An object found in only one of the dumps, or in both with different fields.
+#[derive(Debug, Clone)]
+pub struct ObjectChange {
+    pub change: ChangeKind,
+    pub kind: String,      // "Namespace", "Node", "Image", "Pod", "Deployment", ...
+    pub namespace: String, // Empty for cluster-scoped objects
+    pub name: String,
+    pub details: Vec<String>, // "replicas: 2 → 3" for changed objects
+}
+
+impl ObjectChange {
+    /// `default/web`, or just the name for cluster-scoped objects.
+    pub fn path(&self) -> String {
+        if self.namespace.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}/{}", self.namespace, self.name)
+        }
+    }
+}
+
+/// An issue raised in both dumps whose severity or description changed, e.g. a
+/// restart count that went up.
+#[derive(Debug, Clone)]
+pub struct IssueChange {
+    pub before: ClusterIssue,
+    pub after: ClusterIssue,
+}
+
+impl IssueChange {
+    /// "was: 🟡 Container app restarted 7 times", what the baseline said.
+    pub fn previous_label(&self) -> String {
+        let mut label = "was:".to_string();
+        if self.before.severity != self.after.severity {
+            label.push(' ');
+            label.push_str(self.before.severity.icon());
+        }
+        if self.before.description != self.after.description {
+            label.push(' ');
+            label.push_str(&self.before.description);
+        }
+        label
+    }
+}
+
+/// Counts of one dump, shown as `before → after`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DumpTotals {
+    pub nodes: usize,
+    pub namespaces: usize,
+    pub pods: usize,
+    pub deployments: usize,
+    pub workloads: usize,
+    pub issues: usize,
+    pub critical: usize,
+}
+
+impl DumpTotals {
+    pub fn of(analysis: &ClusterAnalysis) -> Self {
+        DumpTotals {
+            nodes: analysis.nodes.len(),
+            namespaces: analysis.namespaces.len(),
+            pods: analysis.total_pods,
+            deployments: analysis.total_deployments,
+            workloads: analysis.total_workloads,
+            issues: analysis.total_issues,
+            critical: analysis.issue_count(IssueSeverity::Critical),
+        }
+    }
+}
+
+/// What changed between two analyses of the same cluster, e.g. before and after a
+/// deploy.
+#[derive(Debug, Clone, Default)]
+pub struct DumpDiff {
+    pub before: DumpTotals,
+    pub after: DumpTotals,
+    pub changes: Vec<ObjectChange>,
+    pub new_issues: Vec<ClusterIssue>, // Most severe first
+    pub resolved_issues: Vec<ClusterIssue>,
+    pub changed_issues: Vec<IssueChange>,
+}
+
+/// Rows of the Diff screen, in display order.
+#[derive(Debug, Clone, Copy)]
+pub enum DiffRow<'a> {
+    NewIssue(&'a ClusterIssue),
+    ResolvedIssue(&'a ClusterIssue),
+    ChangedIssue(&'a IssueChange),
+    Change(&'a ObjectChange),
+}
+
+impl DumpDiff {
+    pub fn new(before: &ClusterAnalysis, after: &ClusterAnalysis) -> Self {
+        let mut changes = Vec::new();
+
+        // Namespaces: objects of a namespace that only one dump has are counted, not listed
+        let before_namespaces: BTreeMap<&str, _> = before
+            .namespaces
+            .iter()
+            .map(|ns_analysis| (ns_analysis.name.as_str(), ns_analysis))
+            .collect();
+        let after_namespaces: BTreeMap<&str, _> = after
+            .namespaces
+            .iter()
+            .map(|ns_analysis| (ns_analysis.name.as_str(), ns_analysis))
+            .collect();
+        compare(
+            &mut changes,
+            "Namespace",
+            "",
+            &before_namespaces,
+            &after_namespaces,
+            |ns_analysis| {
+                vec![(
+                    "contents",
+                    format!(
+                        "{} pods, {} deployments, {} other workloads",
+                        ns_analysis.pods.len(),
+                        ns_analysis.deployments.len(),
+                        ns_analysis.workloads.total()
+                    ),
+                )]
+            },
+        );
+        // Only added and removed namespaces are interesting, their contents are compared below
+        changes.retain(|change| change.change != ChangeKind::Changed);
+
+        compare(
+            &mut changes,
+            "Node",
+            "",
+            &by_name(&before.nodes, |node| &node.name),
+            &by_name(&after.nodes, |node| &node.name),
+            node_fields,
+        );
+
+        let before_images = images(before);
+        let after_images = images(after);
+        for (change, images) in [
+            (ChangeKind::Added, after_images.difference(&before_images)),
+            (ChangeKind::Removed, before_images.difference(&after_images)),
+        ] {
+            changes.extend(images.map(|image| ObjectChange {
+                change,
+                kind: "Image".to_string(),
+                namespace: String::new(),
+                name: image.to_string(),
+                details: Vec::new(),
+            }));
+        }
+
+        for (namespace, after_ns) in &after_namespaces {
+            let Some(before_ns) = before_namespaces.get(namespace) else {
+                continue;
+            };
+            compare(
+                &mut changes,
+                "Pod",
+                namespace,
+                &by_name(&before_ns.pods, |pod| &pod.name),
+                &by_name(&after_ns.pods, |pod| &pod.name),
+                pod_fields,
+            );
+            compare(
+                &mut changes,
+                "Deployment",
+                namespace,
+                &by_name(&before_ns.deployments, |deployment| &deployment.name),
+                &by_name(&after_ns.deployments, |deployment| &deployment.name),
+                deployment_fields,
+            );
+            for kind in WorkloadKind::ALL {
+                let before_summaries = before_ns.workloads.summaries(kind);
+                let after_summaries = after_ns.workloads.summaries(kind);
+                compare(
+                    &mut changes,
+                    kind.label(),
+                    namespace,
+                    &by_name(&before_summaries, |summary| &summary.name),
+                    &by_name(&after_summaries, |summary| &summary.name),
+                    workload_fields,
+                );
+            }
+        }
+
+        let (new_issues, resolved_issues, changed_issues) =
+            match_issues(before.issues_by_severity(), after.issues_by_severity());
+
+        DumpDiff {
+            before: DumpTotals::of(before),
+            after: DumpTotals::of(after),
+            changes,
+            new_issues,
+            resolved_issues,
+            changed_issues,
+        }
+    }
+
+    pub fn changes_of(&self, change: ChangeKind) -> impl Iterator<Item = &ObjectChange> {
+        self.changes
+            .iter()
+            .filter(move |object| object.change == change)
+    }
+
+    pub fn count(&self, change: ChangeKind) -> usize {
+        self.changes_of(change).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+            && self.new_issues.is_empty()
+            && self.resolved_issues.is_empty()
+            && self.changed_issues.is_empty()
+    }
+
+    /// New, resolved and changed issues, then added, removed and changed objects.
+    pub fn rows(&self) -> Vec<DiffRow<'_>> {
+        self.new_issues
+            .iter()
+            .map(DiffRow::NewIssue)
+            .chain(self.resolved_issues.iter().map(DiffRow::ResolvedIssue))
+            .chain(self.changed_issues.iter().map(DiffRow::ChangedIssue))
+            .chain(
+                ChangeKind::ALL
+                    .into_iter()
+                    .flat_map(|change| self.changes_of(change).map(DiffRow::Change)),
+            )
+            .collect()
+    }
+
+    pub fn new_issue_count(&self, severity: IssueSeverity) -> usize {
+        self.new_issues
+            .iter()
+            .filter(|issue| issue.severity == severity)
+            .count()
+    }
+}
+
+/// Objects of one kind present in only one dump are added or removed; objects in both
+/// are changed when one of their fields differs.
+fn compare<T>(
+    changes: &mut Vec<ObjectChange>,
+    kind: &str,
+    namespace: &str,
+    before: &BTreeMap<&str, T>,
+    after: &BTreeMap<&str, T>,
+    fields: fn(&T) -> Vec<(&'static str, String)>,
+) {
+    let names: BTreeSet<&str> = before.keys().chain(after.keys()).copied().collect();
+    for name in names {
+        let (change, details) = match (before.get(name), after.get(name)) {
+            (None, Some(object)) | (Some(object), None) => {
+                let change = if before.contains_key(name) {
+                    ChangeKind::Removed
+                } else {
+                    ChangeKind::Added
+                };
+                let details = fields(object)
+                    .into_iter()
+                    .map(|(field, value)| format!("{}: {}", field, value))
+                    .collect();
+                (change, details)
+            }
+            (Some(before), Some(after)) => {
+                let details: Vec<String> = fields(before)
+                    .into_iter()
+                    .zip(fields(after))
+                    .filter(|((_, old), (_, new))| old != new)
+                    .map(|((field, old), (_, new))| format!("{}: {} → {}", field, old, new))
+                    .collect();
+                if details.is_empty() {
+                    continue;
+                }
+                (ChangeKind::Changed, details)
+            }
+            (None, None) => continue,
+        };
+        changes.push(ObjectChange {
+            change,
+            kind: kind.to_string(),
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            details,
+        });
+    }
+}
+
+fn by_name<T>(objects: &[T], name: fn(&T) -> &String) -> BTreeMap<&str, &T> {
+    objects
+        .iter()
+        .map(|object| (name(object).as_str(), object))
+        .collect()
+}
+
+fn node_fields(node: &&NodeInfo) -> Vec<(&'static str, String)> {
+    vec![
+        ("status", node.status_label()),
+        ("roles", node.roles_label()),
+        (
+            "kubelet",
+            node.kubelet_version
+                .clone()
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+    ]
+}
+
+fn pod_fields(pod: &&PodInfo) -> Vec<(&'static str, String)> {
+    let images: Vec<&str> = pod
+        .containers
+        .iter()
+        .filter_map(|container| container.image.as_deref())
+        .collect();
+    vec![
+        ("status", pod.status.clone()),
+        ("ready", if pod.ready { "yes" } else { "no" }.to_string()),
+        (
+            "restarts",
+            pod.restart_count.clone().unwrap_or_else(|| "-".to_string()),
+        ),
+        (
+            "node",
+            pod.node_name.clone().unwrap_or_else(|| "-".to_string()),
+        ),
+        ("images", images.join(", ")),
+    ]
+}
+
+fn deployment_fields(deployment: &&DeploymentInfo) -> Vec<(&'static str, String)> {
+    vec![
+        ("replicas", deployment.desired_replicas.to_string()),
+        ("ready", deployment.ready_replicas.to_string()),
+        (
+            "strategy",
+            deployment
+                .strategy
+                .clone()
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+        (
+            "image",
+            deployment.image.clone().unwrap_or_else(|| "-".to_string()),
+        ),
+    ]
+}
+
+fn workload_fields(summary: &&WorkloadSummary) -> Vec<(&'static str, String)> {
+    vec![("status", summary.status.clone())]
+}
+
+/// Every image of the pods and deployments of the dump.
+fn images(analysis: &ClusterAnalysis) -> BTreeSet<&str> {
+    analysis
+        .namespaces
+        .iter()
+        .flat_map(|ns_analysis| {
+            let pod_images = ns_analysis
+                .pods
+                .iter()
+                .flat_map(|pod| pod.containers.iter())
+                .filter_map(|container| container.image.as_deref());
+            let deployment_images = ns_analysis
+                .deployments
+                .iter()
+                .filter_map(|deployment| deployment.image.as_deref());
+            pod_images.chain(deployment_images)
+        })
+        .collect()
+}
+
+/// Which issues of the baseline are still there: new, resolved and changed issues, in
+/// the order of the analyses. An issue is the same when the same rule raised it about
+/// the same object and it says the same thing, or the same thing with other numbers
+/// (restart counts, percentages, ...), which makes it changed.
+fn match_issues(
+    before: Vec<&ClusterIssue>,
+    after: Vec<&ClusterIssue>,
+) -> (Vec<ClusterIssue>, Vec<ClusterIssue>, Vec<IssueChange>) {
+    let mut resolved: Vec<Option<&ClusterIssue>> = before.into_iter().map(Some).collect();
+    let mut new: Vec<Option<&ClusterIssue>> = after.into_iter().map(Some).collect();
+    let mut changed = Vec::new();
+
+    // Exact matches first, so numbers only pair issues left over by them
+    for description in [exact_description, description_shape] {
+        let mut unmatched: BTreeMap<_, VecDeque<usize>> = BTreeMap::new();
+        for (index, issue) in resolved.iter().enumerate() {
+            if let Some(issue) = issue {
+                unmatched
+                    .entry(issue_key(issue, description))
+                    .or_default()
+                    .push_back(index);
+            }
+        }
+        for slot in new.iter_mut() {
+            let Some(issue) = *slot else {
+                continue;
+            };
+            let Some(index) = unmatched
+                .get_mut(&issue_key(issue, description))
+                .and_then(VecDeque::pop_front)
+            else {
+                continue;
+            };
+            if let Some(previous) = resolved[index].take() {
+                if previous.severity != issue.severity || previous.description != issue.description
+                {
+                    changed.push(IssueChange {
+                        before: previous.clone(),
+                        after: issue.clone(),
+                    });
+                }
+            }
+            *slot = None;
+        }
+    }
+    changed.sort_by_key(|change| change.after.severity);
+
+    let remaining =
+        |issues: Vec<Option<&ClusterIssue>>| issues.into_iter().flatten().cloned().collect();
+    (remaining(new), remaining(resolved), changed)
+}
+
+fn issue_key(
+    issue: &ClusterIssue,
+    description: fn(&str) -> String,
+) -> (&str, &str, &str, &str, String) {
+    (
+        &issue.namespace,
+        issue.rule,
+        &issue.component_type,
+        &issue.component,
+        description(&issue.description),
+    )
+}
+
+fn exact_description(description: &str) -> String {
+    description.to_string()
+}
+
+/// The description with its numbers blanked out: "restarted # times".
+fn description_shape(description: &str) -> String {
+    let mut shape = String::with_capacity(description.len());
+    for c in description.chars() {
+        if c.is_ascii_digit() {
+            if !shape.ends_with('#') {
+                shape.push('#');
+            }
+        } else {
+            shape.push(c);
+        }
+    }
+    shape
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(
+        severity: IssueSeverity,
+        rule: &'static str,
+        pod: &str,
+        description: &str,
+    ) -> ClusterIssue {
+        ClusterIssue {
+            severity,
+            component: pod.to_string(),
+            component_type: "Pod".to_string(),
+            namespace: "default".to_string(),
+            description: description.to_string(),
+            owner: None,
+            rule,
+        }
+    }
+
+    fn descriptions(issues: &[ClusterIssue]) -> Vec<&str> {
+        issues
+            .iter()
+            .map(|issue| issue.description.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn restart_count_churn_is_a_changed_issue() {
+        let before = [
+            issue(
+                IssueSeverity::Critical,
+                "crash-loop-backoff",
+                "worker-1",
+                "Container worker is in CrashLoopBackOff (7 restarts)",
+            ),
+            issue(
+                IssueSeverity::Warning,
+                "high-restarts",
+                "worker-1",
+                "Container worker restarted 7 times",
+            ),
+        ];
+        let after = [
+            issue(
+                IssueSeverity::Critical,
+                "crash-loop-backoff",
+                "worker-1",
+                "Container worker is in CrashLoopBackOff (8 restarts)",
+            ),
+            issue(
+                IssueSeverity::Warning,
+                "high-restarts",
+                "worker-1",
+                "Container worker restarted 8 times",
+            ),
+        ];
+        let (new, resolved, changed) =
+            match_issues(before.iter().collect(), after.iter().collect());
+
+        assert!(new.is_empty());
+        assert!(resolved.is_empty());
+        assert_eq!(changed.len(), 2);
+        assert_eq!(
+            changed[1].previous_label(),
+            "was: Container worker restarted 7 times"
+        );
+
+        let diff = DumpDiff {
+            new_issues: new,
+            changed_issues: changed,
+            ..DumpDiff::default()
+        };
+        assert_eq!(diff.new_issue_count(IssueSeverity::Critical), 0);
+    }
+
+    #[test]
+    fn unchanged_issues_are_left_out() {
+        let issues = [issue(
+            IssueSeverity::Info,
+            "latest-tag",
+            "web-1",
+            "Container web uses unpinned image nginx:latest",
+        )];
+        let (new, resolved, changed) =
+            match_issues(issues.iter().collect(), issues.iter().collect());
+        assert!(new.is_empty() && resolved.is_empty() && changed.is_empty());
+    }
+
+    #[test]
+    fn other_rules_and_objects_are_new_and_resolved() {
+        let before = [
+            issue(
+                IssueSeverity::Warning,
+                "high-restarts",
+                "worker-1",
+                "Container worker restarted 7 times",
+            ),
+            issue(
+                IssueSeverity::Info,
+                "no-limits",
+                "web-1",
+                "Container web has no cpu/memory limit",
+            ),
+        ];
+        let after = [
+            issue(
+                IssueSeverity::Warning,
+                "high-restarts",
+                "worker-2",
+                "Container worker restarted 7 times",
+            ),
+            issue(
+                IssueSeverity::Critical,
+                "crash-loop-backoff",
+                "web-1",
+                "Container web has no cpu/memory limit",
+            ),
+        ];
+        let (new, resolved, changed) =
+            match_issues(before.iter().collect(), after.iter().collect());
+
+        // In the order of the analyses
+        assert_eq!(
+            descriptions(&new),
+            [
+                "Container worker restarted 7 times",
+                "Container web has no cpu/memory limit"
+            ]
+        );
+        assert_eq!(new[1].rule, "crash-loop-backoff");
+        assert_eq!(resolved.len(), 2);
+        assert!(changed.is_empty());
+    }
+
+    #[test]
+    fn exact_matches_pair_first() {
+        // Two containers of one pod whose names only differ by a number
+        let before = [
+            issue(
+                IssueSeverity::Warning,
+                "high-restarts",
+                "web-1",
+                "Container app1 restarted 5 times",
+            ),
+            issue(
+                IssueSeverity::Warning,
+                "high-restarts",
+                "web-1",
+                "Container app2 restarted 5 times",
+            ),
+        ];
+        let after = [
+            issue(
+                IssueSeverity::Warning,
+                "high-restarts",
+                "web-1",
+                "Container app2 restarted 5 times",
+            ),
+            issue(
+                IssueSeverity::Warning,
+                "high-restarts",
+                "web-1",
+                "Container app1 restarted 6 times",
+            ),
+        ];
+        let (new, resolved, changed) =
+            match_issues(before.iter().collect(), after.iter().collect());
+
+        assert!(new.is_empty() && resolved.is_empty());
+        assert_eq!(changed.len(), 1);
+        assert_eq!(
+            changed[0].before.description,
+            "Container app1 restarted 5 times"
+        );
+    }
+
+    #[test]
+    fn severity_changes_are_changed_issues() {
+        let before = [issue(
+            IssueSeverity::Warning,
+            "deployment-replicas",
+            "web",
+            "Deployment web has 1/2 replicas ready",
+        )];
+        let after = [issue(
+            IssueSeverity::Critical,
+            "deployment-replicas",
+            "web",
+            "Deployment web has 0/2 replicas ready",
+        )];
+        let (new, resolved, changed) =
+            match_issues(before.iter().collect(), after.iter().collect());
+
+        assert!(new.is_empty() && resolved.is_empty());
+        assert_eq!(
+            changed[0].previous_label(),
+            "was: 🟡 Deployment web has 1/2 replicas ready"
+        );
+    }
+
+    #[test]
+    fn blanks_out_numbers() {
+        assert_eq!(
+            description_shape("restarted 12 times (1.5% of 300)"),
+            "restarted # times (#.#% of #)"
+        );
+    }
+}
@@ -0,0 +1,522 @@
+use serde_json::Value;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+
+use crate::error::Result;
+use crate::layout::{must_gather_namespaces_dir, DumpLayout, ListedObjects, API_GROUP_DIRS};
+use crate::manifest::{is_manifest_file_name, manifest_file_names};
+use crate::metrics::MetricsSnapshot;
+use crate::vfs::{decompress, is_gzip_file, open_dump_fs, DirEntry, DumpFs};
+
+/// Default dump location, relative to the working directory.
+pub const DEFAULT_DUMP_DIR: &str = "output";
+
+/// Root of a Kubernetes cluster dump.
+///
+/// Every loader receives a `DumpRoot` instead of building `output/...` paths
+/// itself, so the same code can be pointed at any dump directory or archive. Paths
+/// built by `DumpRoot` are read back through its methods, never with `std::fs`.
+#[derive(Debug, Clone)]
+pub struct DumpRoot {
+    root: PathBuf,
+    fs: Arc<dyn DumpFs>,
+    layout: DumpLayout,
+    // Directory holding the namespaces: the root, or `namespaces/` in must-gather bundles
+    namespaces_dir: PathBuf,
+    // Root-level List files, parsed the first time they are needed
+    lists: Arc<OnceLock<ListedObjects>>,
+    // Pod and node metrics, read the first time they are needed
+    metrics: Arc<OnceLock<MetricsSnapshot>>,
+}
+
+impl DumpRoot {
+    /// A dump directory, or a tar, tar.gz, tar.zst or zip archive of one. The layout
+    /// of the dump is detected here.
+    pub fn open(root: impl Into<PathBuf>) -> Result<Self> {
+        let root = root.into();
+        let fs = open_dump_fs(&root)?;
+        let mut dump = DumpRoot {
+            namespaces_dir: root.clone(),
+            root,
+            fs,
+            layout: DumpLayout::Analyzer,
+            lists: Arc::new(OnceLock::new()),
+            metrics: Arc::new(OnceLock::new()),
+        };
+        match must_gather_namespaces_dir(&dump) {
+            Some(namespaces_dir) => {
+                dump.layout = DumpLayout::MustGather;
+                dump.namespaces_dir = namespaces_dir;
+            }
+            None => dump.layout = DumpLayout::detect(&dump),
+        }
+        Ok(dump)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn layout(&self) -> DumpLayout {
+        self.layout
+    }
+
+    /// Objects of a resource (`pods`, `deployments`, ...) in a namespace, from the
+    /// `kubectl get` List files at the root of the dump.
+    pub fn listed_objects(&self, namespace: &str, resource: &str) -> Vec<Value> {
+        self.lists
+            .get_or_init(|| ListedObjects::load(self))
+            .objects(namespace, resource)
+    }
+
+    /// Usage of the pods and nodes, from the metrics files of the dump.
+    pub fn metrics(&self) -> &MetricsSnapshot {
+        self.metrics.get_or_init(|| MetricsSnapshot::load(self))
+    }
+
+    /// Files holding the objects of a resource (`pods`, `deployments`, ...) in a
+    /// namespace: `<ns>/pods.json` (or `.yaml`, `.yml`), `<ns>/core/pods.yaml` as in
+    /// must-gather bundles, or one file per object in `<ns>/pods/` or
+    /// `<ns>/<group>/pods/`. The first of these found wins.
+    pub fn manifest_files(&self, namespace: &str, resource: &str) -> Vec<PathBuf> {
+        self.find_manifest_files(&self.namespace_dir(namespace), resource)
+    }
+
+    /// Files holding cluster-scoped objects such as nodes: at the root of the dump,
+    /// or under `cluster-scoped-resources/` in must-gather bundles.
+    pub fn cluster_manifest_files(&self, resource: &str) -> Vec<PathBuf> {
+        let mut files = self.find_manifest_files(&self.root, resource);
+        if files.is_empty() && self.layout == DumpLayout::MustGather {
+            if let Some(bundle_dir) = self.namespaces_dir.parent() {
+                files = self
+                    .find_manifest_files(&bundle_dir.join("cluster-scoped-resources"), resource);
+            }
+        }
+        files
+    }
+
+    fn find_manifest_files(&self, base_dir: &Path, resource: &str) -> Vec<PathBuf> {
+        let dirs: Vec<PathBuf> = std::iter::once(base_dir.to_path_buf())
+            .chain(API_GROUP_DIRS.iter().map(|group| base_dir.join(group)))
+            .collect();
+
+        for dir in &dirs {
+            let list_file = manifest_file_names(resource)
+                .map(|file_name| dir.join(file_name))
+                .find(|path| self.is_file(path));
+            if let Some(list_file) = list_file {
+                return vec![list_file];
+            }
+        }
+        for dir in &dirs {
+            let object_files = self.object_files(&dir.join(resource));
+            if !object_files.is_empty() {
+                return object_files;
+            }
+        }
+        Vec::new()
+    }
+
+    /// One manifest per object, directly in `dir` or in `dir/<name>/<name>.yaml`.
+    fn object_files(&self, dir: &Path) -> Vec<PathBuf> {
+        self.read_dir(dir)
+            .into_iter()
+            .filter_map(|entry| {
+                if entry.is_dir {
+                    let object_dir = dir.join(&entry.name);
+                    manifest_file_names(&entry.name)
+                        .map(|file_name| object_dir.join(file_name))
+                        .find(|path| self.is_file(path))
+                } else if is_manifest_file_name(&entry.name) {
+                    Some(dir.join(&entry.name))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    pub fn exists(&self) -> bool {
+        self.fs.exists()
+    }
+
+    pub fn is_file(&self, path: &Path) -> bool {
+        self.fs.is_file(self.relative(path))
+    }
+
+    /// Entries of a directory of the dump, sorted by name.
+    pub fn read_dir(&self, path: &Path) -> Vec<DirEntry> {
+        let mut entries = self.fs.read_dir(self.relative(path));
+        entries.sort();
+        entries
+    }
+
+    /// Open a file of the dump; `.gz` files are decompressed on the fly.
+    pub fn open_file(&self, path: &Path) -> Result<Box<dyn Read + Send>> {
+        Ok(decompress(path, self.fs.open(self.relative(path))?))
+    }
+
+    pub fn read_to_string(&self, path: &Path) -> Result<String> {
+        let mut content = Vec::new();
+        self.open_file(path)?.read_to_end(&mut content)?;
+        Ok(match String::from_utf8(content) {
+            Ok(content) => content,
+            Err(error) => String::from_utf8_lossy(error.as_bytes()).into_owned(),
+        })
+    }
+
+    /// The uncompressed file on disk behind a path, for readers that seek in it.
+    pub fn local_path(&self, path: &Path) -> Option<PathBuf> {
+        if is_gzip_file(path) {
+            return None;
+        }
+        self.fs.local_path(self.relative(path))
+    }
+
+    fn relative<'a>(&self, path: &'a Path) -> &'a Path {
+        path.strip_prefix(&self.root).unwrap_or(path)
+    }
+
+    pub fn namespace_dir(&self, namespace: &str) -> PathBuf {
+        self.namespaces_dir.join(namespace)
+    }
+
+    pub fn component_dir(&self, namespace: &str, component: &str) -> PathBuf {
+        self.namespace_dir(namespace).join(component)
+    }
+
+    /// Per-container log directory inside a pod directory: `<namespace>/<pod>/<container>/`.
+    pub fn container_dir(&self, namespace: &str, pod: &str, container: &str) -> PathBuf {
+        self.component_dir(namespace, pod).join(container)
+    }
+
+    /// Names of all namespace directories in the dump, sorted. Dumps made of List
+    /// files have no directories: their namespaces come from the objects.
+    pub fn namespace_names(&self) -> Result<Vec<String>> {
+        if !self.exists() {
+            return Err(format!("Dump directory not found: {}", self.root.display()).into());
+        }
+        if self.layout == DumpLayout::KubectlLists {
+            return Ok(self
+                .lists
+                .get_or_init(|| ListedObjects::load(self))
+                .namespace_names());
+        }
+        Ok(self.list_subdirectories(&self.namespaces_dir))
+    }
+
+    /// Names of all subdirectories of a namespace (one per pod/component), sorted.
+    pub fn component_names(&self, namespace: &str) -> Vec<String> {
+        self.list_subdirectories(&self.namespace_dir(namespace))
+    }
+
+    /// Names of all subdirectories of a pod directory (one per container), sorted.
+    pub fn container_names(&self, namespace: &str, pod: &str) -> Vec<String> {
+        self.list_subdirectories(&self.component_dir(namespace, pod))
+    }
+
+    fn list_subdirectories(&self, dir: &Path) -> Vec<String> {
+        self.read_dir(dir)
+            .into_iter()
+            .filter(|entry| entry.is_dir)
+            .map(|entry| entry.name)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kubernetes::{load_deployments, load_namespaces, load_nodes, load_pods};
+    use crate::logs::{
+        entry_container, list_log_components, list_log_containers, load_container_logs,
+    };
+    use std::fs;
+
+    const PODS: &str = r#"{"kind": "PodList", "items": [
+        {"metadata": {"name": "web-1", "namespace": "default"}, "status": {"phase": "Running"}},
+        {"metadata": {"name": "api-1", "namespace": "prod"}, "status": {"phase": "Pending"}}
+    ]}"#;
+
+    /// A dump directory holding `files`, under the temp dir; `name` keeps tests apart.
+    fn fixture(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("k8s-analyzer-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (path, content) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        root
+    }
+
+    /// A `.tar.gz` archive of `files`, under the temp dir.
+    fn archive(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "k8s-analyzer-test-{}-{}.tar.gz",
+            name,
+            std::process::id()
+        ));
+        let encoder = flate2::write::GzEncoder::new(
+            fs::File::create(&path).unwrap(),
+            flate2::Compression::fast(),
+        );
+        let mut builder = tar::Builder::new(encoder);
+        for (name, content) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, name, content.as_bytes())
+                .unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap();
+        path
+    }
+
+    fn pod_names(dump: &DumpRoot, namespace: &str) -> Vec<String> {
+        load_pods(dump, namespace)
+            .unwrap()
+            .into_iter()
+            .map(|pod| pod.name)
+            .collect()
+    }
+
+    #[test]
+    fn loads_analyzer_dumps() {
+        let root = fixture(
+            "analyzer",
+            &[
+                ("default/pods.json", PODS),
+                (
+                    "default/deployments.json",
+                    r#"{"items": [{"metadata": {"name": "web"}, "spec": {}, "status": {"readyReplicas": 1}}]}"#,
+                ),
+                (
+                    "default/web-1/logs.txt",
+                    "2024-01-02T15:04:05Z INFO started\n",
+                ),
+                ("default/empty/README", "no logs here"),
+            ],
+        );
+        let dump = DumpRoot::open(&root).unwrap();
+
+        assert_eq!(dump.layout(), DumpLayout::Analyzer);
+        assert_eq!(dump.namespace_names().unwrap(), ["default"]);
+        // The namespace directory decides, not the namespace of the objects
+        assert_eq!(pod_names(&dump, "default"), ["web-1", "api-1"]);
+        let deployments = load_deployments(&dump, "default").unwrap();
+        assert_eq!(deployments[0].desired_replicas, 1);
+        let namespaces = load_namespaces(&dump).unwrap();
+        assert_eq!(
+            (namespaces[0].pod_count, namespaces[0].deployment_count),
+            (2, 1)
+        );
+        assert_eq!(list_log_components(&dump, "default"), ["web-1"]);
+        assert!(load_pods(&dump, "missing").unwrap().is_empty());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn loads_cluster_info_dumps() {
+        let logs = "==== START logs for container app of pod default/web-1 ====\n\
+                    2024-01-02T15:04:05Z INFO app started\n\
+                    ==== END logs for container app of pod default/web-1 ====\n\
+                    ==== START logs for container sidecar of pod default/web-1 ====\n\
+                    2024-01-02T15:04:06Z WARN sidecar slow\n\
+                    2024-01-02T15:04:07Z INFO sidecar ok\n\
+                    ==== END logs for container sidecar of pod default/web-1 ====\n";
+        let root = fixture(
+            "cluster-info",
+            &[
+                (
+                    "nodes.json",
+                    r#"{"items": [{"metadata": {"name": "node-b"}}, {"metadata": {"name": "node-a"}}]}"#,
+                ),
+                ("default/pods.json", PODS),
+                ("default/web-1/logs.txt", logs),
+            ],
+        );
+        let dump = DumpRoot::open(&root).unwrap();
+
+        assert_eq!(dump.layout(), DumpLayout::ClusterInfoDump);
+        let nodes: Vec<String> = load_nodes(&dump)
+            .into_iter()
+            .map(|node| node.name)
+            .collect();
+        assert_eq!(nodes, ["node-a", "node-b"]);
+        assert_eq!(
+            list_log_containers(&dump, "default", "web-1"),
+            ["app", "sidecar"]
+        );
+        let sidecar = load_container_logs(&dump, "default", "web-1", "sidecar").unwrap();
+        assert_eq!(sidecar.entries.len(), 2);
+        assert!(sidecar
+            .entries
+            .iter()
+            .all(|entry| entry_container(entry) == Some("sidecar")));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn loads_kubectl_list_dumps() {
+        let root = fixture(
+            "kubectl-lists",
+            &[
+                ("pods.json", PODS),
+                (
+                    "namespaces.yaml",
+                    "apiVersion: v1\nkind: List\nitems:\n- kind: Namespace\n  metadata:\n    name: empty\n",
+                ),
+            ],
+        );
+        let dump = DumpRoot::open(&root).unwrap();
+
+        assert_eq!(dump.layout(), DumpLayout::KubectlLists);
+        assert_eq!(
+            dump.namespace_names().unwrap(),
+            ["default", "empty", "prod"]
+        );
+        assert_eq!(pod_names(&dump, "default"), ["web-1"]);
+        assert_eq!(pod_names(&dump, "prod"), ["api-1"]);
+        assert!(pod_names(&dump, "empty").is_empty());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn loads_must_gather_bundles() {
+        let bundle = "must-gather.local.1/quay-io-image";
+        let pods = "apiVersion: v1\nkind: PodList\nitems:\n\
+                    - metadata:\n    name: web-1\n  status:\n    phase: Running\n";
+        let deployment =
+            "apiVersion: apps/v1\nkind: Deployment\nmetadata:\n  name: web\nspec:\n  replicas: 3\n";
+        let root = fixture(
+            "must-gather",
+            &[
+                (
+                    &format!("{}/namespaces/default/core/pods.yaml", bundle),
+                    pods,
+                ),
+                (
+                    &format!("{}/namespaces/default/apps/deployments/web.yaml", bundle),
+                    deployment,
+                ),
+                (
+                    &format!("{}/cluster-scoped-resources/core/nodes/node-a.yaml", bundle),
+                    "kind: Node\nmetadata:\n  name: node-a\n",
+                ),
+            ],
+        );
+        let dump = DumpRoot::open(&root).unwrap();
+
+        assert_eq!(dump.layout(), DumpLayout::MustGather);
+        assert_eq!(dump.namespace_names().unwrap(), ["default"]);
+        assert_eq!(pod_names(&dump, "default"), ["web-1"]);
+        assert_eq!(
+            load_deployments(&dump, "default").unwrap()[0].desired_replicas,
+            3
+        );
+        assert_eq!(load_nodes(&dump).len(), 1);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn archives_strip_the_top_level_directory() {
+        let path = archive(
+            "archive-metrics",
+            &[
+                (
+                    "output/top-pods.txt",
+                    "NAMESPACE NAME CPU(cores) MEMORY(bytes)\n",
+                ),
+                ("output/pod-metrics.json", r#"{"items": []}"#),
+                ("output/README", "dump of the staging cluster"),
+                ("output/default/pods.json", PODS),
+                (
+                    "output/default/web-1/logs.txt",
+                    "2024-01-02T15:04:05Z INFO started\n",
+                ),
+            ],
+        );
+        let dump = DumpRoot::open(&path).unwrap();
+
+        assert_eq!(dump.layout(), DumpLayout::Analyzer);
+        assert_eq!(dump.namespace_names().unwrap(), ["default"]);
+        assert_eq!(pod_names(&dump, "default"), ["web-1", "api-1"]);
+        assert_eq!(list_log_components(&dump, "default"), ["web-1"]);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn large_archived_files_are_read_back() {
+        // Over the size kept in memory: the file is read back from the spill file
+        let logs = "2024-01-02T15:04:05Z INFO started\n".repeat(150_000);
+        let path = archive(
+            "archive-large",
+            &[
+                ("output/default/pods.json", PODS),
+                ("output/default/web-1/logs.txt", &logs),
+                (
+                    "output/default/web-2/logs.txt",
+                    "2024-01-02T15:04:06Z WARN slow\n",
+                ),
+            ],
+        );
+        let dump = DumpRoot::open(&path).unwrap();
+        let logs_path = dump.path().join("default/web-1/logs.txt");
+        for _ in 0..2 {
+            assert_eq!(dump.read_to_string(&logs_path).unwrap().len(), logs.len());
+        }
+        assert_eq!(
+            dump.read_to_string(&dump.path().join("default/web-2/logs.txt"))
+                .unwrap(),
+            "2024-01-02T15:04:06Z WARN slow\n"
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn archives_of_list_files_or_a_namespace() {
+        let path = archive(
+            "archive-lists",
+            &[("output/pods.json", PODS), ("output/top-pods.txt", "")],
+        );
+        let dump = DumpRoot::open(&path).unwrap();
+        assert_eq!(dump.layout(), DumpLayout::KubectlLists);
+        assert_eq!(dump.namespace_names().unwrap(), ["default", "prod"]);
+        assert_eq!(pod_names(&dump, "prod"), ["api-1"]);
+        fs::remove_file(path).unwrap();
+
+        // A namespace directory with pod directories is kept as the namespace
+        let path = archive(
+            "archive-namespace",
+            &[
+                ("default/pods.json", PODS),
+                (
+                    "default/web-1/logs.txt",
+                    "2024-01-02T15:04:05Z INFO started\n",
+                ),
+            ],
+        );
+        let dump = DumpRoot::open(&path).unwrap();
+        assert_eq!(dump.layout(), DumpLayout::Analyzer);
+        assert_eq!(dump.namespace_names().unwrap(), ["default"]);
+        assert_eq!(list_log_components(&dump, "default"), ["web-1"]);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn missing_dumps_are_an_error() {
+        let dump = DumpRoot::open(std::env::temp_dir().join("k8s-analyzer-test-missing"));
+        assert!(dump.map_or(true, |dump| dump.namespace_names().is_err()));
+    }
+}
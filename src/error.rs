@@ -6,6 +6,7 @@ pub type Result<T> = std::result::Result<T, AppError>;
 pub enum AppError {
     Io(std::io::Error),
     Json(serde_json::Error),
+    Yaml(serde_yaml::Error),
     Crossterm(Box<dyn std::error::Error + Send + Sync>),
     Custom(String),
 }
@@ -15,6 +16,7 @@ impl fmt::Display for AppError {
         match self {
             AppError::Io(e) => write!(f, "IO error: {}", e),
             AppError::Json(e) => write!(f, "JSON error: {}", e),
+            AppError::Yaml(e) => write!(f, "YAML error: {}", e),
             AppError::Crossterm(e) => write!(f, "Terminal error: {}", e),
             AppError::Custom(msg) => write!(f, "{}", msg),
         }
@@ -35,6 +37,12 @@ impl From<serde_json::Error> for AppError {
     }
 }
 
+impl From<serde_yaml::Error> for AppError {
+    fn from(error: serde_yaml::Error) -> Self {
+        AppError::Yaml(error)
+    }
+}
+
 impl From<Box<dyn std::error::Error + Send + Sync>> for AppError {
     fn from(error: Box<dyn std::error::Error + Send + Sync>) -> Self {
         AppError::Crossterm(error)
@@ -0,0 +1,136 @@
+use serde_json::Value;
+
+use crate::kubernetes::{DeploymentInfo, PodInfo};
+
+pub const EVENTS_FILE: &str = "events.json";
+
+#[derive(Debug, Clone)]
+pub struct EventInfo {
+    pub event_type: String,
+    pub reason: String,
+    pub message: String,
+    pub involved_kind: String,
+    pub involved_name: String,
+    pub count: u32,
+    pub first_timestamp: Option<String>,
+    pub last_timestamp: Option<String>,
+    pub source: Option<String>,
+}
+
+impl EventInfo {
+    pub fn is_warning(&self) -> bool {
+        self.event_type == "Warning"
+    }
+
+    pub fn involves(&self, kind: &str, name: &str) -> bool {
+        self.involved_kind == kind && self.involved_name == name
+    }
+
+    /// Timestamp used to order events: the last occurrence, else the first.
+    pub fn time(&self) -> &str {
+        self.last_timestamp
+            .as_deref()
+            .or(self.first_timestamp.as_deref())
+            .unwrap_or("")
+    }
+
+    pub fn icon(&self) -> &'static str {
+        if self.is_warning() {
+            "🟡"
+        } else {
+            "🔵"
+        }
+    }
+
+    /// "Warning BackOff (x12): Back-off restarting failed container"
+    pub fn summary(&self) -> String {
+        let count = if self.count > 1 {
+            format!(" (x{})", self.count)
+        } else {
+            String::new()
+        };
+        format!(
+            "{} {}{}: {}",
+            self.event_type, self.reason, count, self.message
+        )
+    }
+}
+
+/// Parses both `v1` Events (`involvedObject`, `message`) and `events.k8s.io/v1` Events
+/// (`regarding`, `note`).
+pub fn parse_event(event: &Value) -> Option<EventInfo> {
+    let involved = if event["involvedObject"].is_object() {
+        &event["involvedObject"]
+    } else {
+        &event["regarding"]
+    };
+
+    let count = event["count"]
+        .as_u64()
+        .or_else(|| event["series"]["count"].as_u64())
+        .or_else(|| event["deprecatedCount"].as_u64())
+        .unwrap_or(1) as u32;
+
+    let first_timestamp = string_field(&event["firstTimestamp"])
+        .or_else(|| string_field(&event["eventTime"]))
+        .or_else(|| string_field(&event["metadata"]["creationTimestamp"]));
+    let last_timestamp = string_field(&event["lastTimestamp"])
+        .or_else(|| string_field(&event["series"]["lastObservedTime"]));
+
+    Some(EventInfo {
+        event_type: event["type"].as_str().unwrap_or("Normal").to_string(),
+        reason: event["reason"].as_str().unwrap_or("Unknown").to_string(),
+        message: event["message"]
+            .as_str()
+            .or_else(|| event["note"].as_str())
+            .unwrap_or("")
+            .trim()
+            .to_string(),
+        involved_kind: involved["kind"].as_str()?.to_string(),
+        involved_name: involved["name"].as_str()?.to_string(),
+        count,
+        first_timestamp,
+        last_timestamp,
+        source: string_field(&event["source"]["component"])
+            .or_else(|| string_field(&event["reportingController"])),
+    })
+}
+
+/// Events of a namespace, oldest first.
+pub fn parse_events(items: &[Value]) -> Vec<EventInfo> {
+    let mut events: Vec<EventInfo> = items.iter().filter_map(parse_event).collect();
+    sort_chronologically(&mut events);
+    events
+}
+
+pub fn sort_chronologically(events: &mut [EventInfo]) {
+    // RFC 3339 timestamps in UTC sort correctly as strings
+    events.sort_by(|a, b| a.time().cmp(b.time()));
+}
+
+/// Events of the given object, oldest first.
+pub fn events_for(events: &[EventInfo], kind: &str, name: &str) -> Vec<EventInfo> {
+    events
+        .iter()
+        .filter(|event| event.involves(kind, name))
+        .cloned()
+        .collect()
+}
+
+/// Attach each event to the pod or deployment named in its `involvedObject`.
+pub fn attach_events(
+    pods: &mut [PodInfo],
+    deployments: &mut [DeploymentInfo],
+    events: &[EventInfo],
+) {
+    for pod in pods.iter_mut() {
+        pod.events = events_for(events, "Pod", &pod.name);
+    }
+    for deployment in deployments.iter_mut() {
+        deployment.events = events_for(events, "Deployment", &deployment.name);
+    }
+}
+
+fn string_field(value: &Value) -> Option<String> {
+    value.as_str().map(|s| s.to_string())
+}
@@ -0,0 +1,203 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::thread;
+
+use crate::dump::DumpRoot;
+use crate::logs::{
+    entry_container, filter_level, has_pod_log_file, list_container_log_dirs, list_log_components,
+    load_container_logs, load_pod_logs, LogEntry, LogLevel, LogSearch,
+};
+
+/// Searches stop collecting hits past this many, to keep memory bounded on huge dumps.
+pub const MAX_HITS: usize = 5_000;
+
+/// A matching log entry somewhere in the dump.
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub namespace: String,
+    pub pod: String,
+    pub container: Option<String>, // None for the pod-level log file
+    pub line_number: usize,
+    pub level: LogLevel,
+    pub message: String,
+}
+
+impl SearchHit {
+    /// "namespace/pod[/container]:line"
+    pub fn location(&self) -> String {
+        let container = self
+            .container
+            .as_ref()
+            .map(|container| format!("/{}", container))
+            .unwrap_or_default();
+        format!(
+            "{}/{}{}:{}",
+            self.namespace, self.pod, container, self.line_number
+        )
+    }
+}
+
+enum SearchMessage {
+    Hit(SearchHit),
+    Scanned { logs: usize, total: usize },
+    Done,
+}
+
+/// A search over every log of the dump, running in a background thread. Hits arrive
+/// through `poll` while the thread walks namespaces and components.
+pub struct GlobalSearch {
+    pub hits: Vec<SearchHit>,
+    pub scanned_logs: usize,
+    pub total_logs: usize,
+    pub done: bool,
+    receiver: Receiver<SearchMessage>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl GlobalSearch {
+    /// Start searching for `search` in the entries passing `level_filter` ("error",
+    /// "warning", "info", "debug").
+    pub fn start(dump: DumpRoot, search: LogSearch, level_filter: Option<String>) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let cancelled = Arc::new(AtomicBool::new(false));
+
+        let thread_cancelled = Arc::clone(&cancelled);
+        thread::spawn(move || {
+            search_dump(
+                &dump,
+                &search,
+                level_filter.as_deref(),
+                &sender,
+                &thread_cancelled,
+            );
+            let _ = sender.send(SearchMessage::Done);
+        });
+
+        GlobalSearch {
+            hits: Vec::new(),
+            scanned_logs: 0,
+            total_logs: 0,
+            done: false,
+            receiver,
+            cancelled,
+        }
+    }
+
+    /// Collect what the search thread found since the last call.
+    pub fn poll(&mut self) {
+        while let Ok(message) = self.receiver.try_recv() {
+            match message {
+                SearchMessage::Hit(hit) => self.hits.push(hit),
+                SearchMessage::Scanned { logs, total } => {
+                    self.scanned_logs = logs;
+                    self.total_logs = total;
+                }
+                SearchMessage::Done => self.done = true,
+            }
+        }
+    }
+
+    pub fn limit_reached(&self) -> bool {
+        self.hits.len() >= MAX_HITS
+    }
+}
+
+impl Drop for GlobalSearch {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+/// (namespace, pod, container) of every log in the dump.
+fn log_sources(dump: &DumpRoot) -> Vec<(String, String, Option<String>)> {
+    let mut sources = Vec::new();
+    for namespace in dump.namespace_names().unwrap_or_default() {
+        for pod in list_log_components(dump, &namespace) {
+            if has_pod_log_file(dump, &namespace, &pod) {
+                sources.push((namespace.clone(), pod.clone(), None));
+            }
+            // Container sections of the pod log file are searched with it
+            for container in list_container_log_dirs(dump, &namespace, &pod) {
+                sources.push((namespace.clone(), pod.clone(), Some(container)));
+            }
+        }
+    }
+    sources
+}
+
+fn search_dump(
+    dump: &DumpRoot,
+    search: &LogSearch,
+    level_filter: Option<&str>,
+    sender: &Sender<SearchMessage>,
+    cancelled: &AtomicBool,
+) {
+    let sources = log_sources(dump);
+    let total = sources.len();
+    let level = filter_level(level_filter);
+    let mut hit_count = 0;
+
+    for (scanned, (namespace, pod, container)) in sources.into_iter().enumerate() {
+        if cancelled.load(Ordering::Relaxed) || hit_count >= MAX_HITS {
+            return;
+        }
+
+        let logs = match container {
+            Some(ref container) => load_container_logs(dump, &namespace, &pod, container),
+            None => load_pod_logs(dump, &namespace, &pod),
+        };
+        // Sends a hit; false once the search should stop
+        let mut report = |entry: &LogEntry| {
+            if level.as_ref().is_some_and(|level| entry.level != *level) || !search.matches(entry) {
+                return true;
+            }
+            let hit = SearchHit {
+                namespace: namespace.clone(),
+                pod: pod.clone(),
+                // Pod log files with container sections tag each entry
+                container: container
+                    .clone()
+                    .or_else(|| entry_container(entry).map(str::to_string)),
+                line_number: entry.line_number,
+                level: entry.level.clone(),
+                message: entry.message.clone(),
+            };
+            hit_count += 1;
+            sender.send(SearchMessage::Hit(hit)).is_ok()
+                && hit_count < MAX_HITS
+                && !cancelled.load(Ordering::Relaxed)
+        };
+
+        // Unreadable logs are skipped, like in the logs list
+        let finished = match logs {
+            Ok(mut logs) => match logs.store.clone() {
+                // Large files are read entry by entry rather than window by window
+                Some(store) => {
+                    let mut finished = true;
+                    let _ = store.for_each_entry(|entry| {
+                        finished = report(&entry);
+                        finished
+                    });
+                    finished
+                }
+                None => {
+                    // Hits in file order
+                    if logs.newest_first {
+                        logs.toggle_order();
+                    }
+                    logs.entries.iter().all(&mut report)
+                }
+            },
+            Err(_) => true,
+        };
+        if !finished {
+            return;
+        }
+
+        let _ = sender.send(SearchMessage::Scanned {
+            logs: scanned + 1,
+            total,
+        });
+    }
+}
@@ -1,8 +1,19 @@
 use serde_json::Value;
-use std::fs;
-use std::path::Path;
+use std::collections::BTreeMap;
 
+use crate::dump::DumpRoot;
 use crate::error::Result;
+use crate::events::{self, EventInfo};
+use crate::layout::{DumpLayout, NODES_FILE};
+use crate::manifest::parse_manifest;
+use crate::metrics::{self, MetricsSnapshot, ResourceUsage};
+use crate::networking::{self, NamespaceNetworking};
+use crate::nodes::{parse_nodes, NodeInfo};
+use crate::ownership::{ObjectRef, OwnershipGraph};
+use crate::quantity::Quantity;
+use crate::resources::CapacityReport;
+use crate::rules::RuleRegistry;
+use crate::workloads::{NamespaceWorkloads, WorkloadKind};
 
 #[derive(Debug, Clone)]
 pub struct NamespaceInfo {
@@ -16,10 +27,52 @@ pub struct PodInfo {
     pub name: String,
     pub status: String,
     pub ready: bool,
-    pub cpu_usage: Option<String>,
-    pub memory_usage: Option<String>,
+    pub usage: Option<ResourceUsage>, // From the metrics of the dump, see metrics::attach_usage
     pub restart_count: Option<String>,
     pub image: Option<String>,
+    pub labels: BTreeMap<String, String>,
+    pub node_name: Option<String>, // `spec.nodeName`, once scheduled
+    pub containers: Vec<ContainerInfo>,
+    pub events: Vec<EventInfo>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContainerKind {
+    Init,
+    App,
+    Ephemeral,
+}
+
+impl ContainerKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ContainerKind::Init => "init",
+            ContainerKind::App => "app",
+            ContainerKind::Ephemeral => "ephemeral",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ContainerResources {
+    pub cpu_request: Option<String>,
+    pub cpu_limit: Option<String>,
+    pub memory_request: Option<String>,
+    pub memory_limit: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ContainerInfo {
+    pub name: String,
+    pub kind: ContainerKind,
+    pub image: Option<String>,
+    pub state: String,
+    pub last_termination_reason: Option<String>,
+    pub exit_code: Option<i64>,
+    pub restart_count: u32,
+    pub ready: bool,
+    pub resources: ContainerResources,
+    pub usage: Option<ResourceUsage>,
 }
 
 #[derive(Debug, Clone)]
@@ -29,6 +82,7 @@ pub struct DeploymentInfo {
     pub desired_replicas: u32,
     pub strategy: Option<String>,
     pub image: Option<String>,
+    pub events: Vec<EventInfo>,
 }
 
 #[derive(Debug, Clone)]
@@ -38,20 +92,59 @@ pub struct ClusterIssue {
     pub component_type: String,
     pub namespace: String,
     pub description: String,
+    pub owner: Option<ObjectRef>, // Top-level workload of the component, e.g. the Deployment of a pod
+    pub rule: &'static str,       // Id of the rule that raised it
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum IssueSeverity {
     Critical,
     Warning,
     Info,
 }
 
+impl IssueSeverity {
+    pub const ALL: [IssueSeverity; 3] = [
+        IssueSeverity::Critical,
+        IssueSeverity::Warning,
+        IssueSeverity::Info,
+    ];
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "critical" => Some(IssueSeverity::Critical),
+            "warning" | "warn" => Some(IssueSeverity::Warning),
+            "info" => Some(IssueSeverity::Info),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            IssueSeverity::Critical => "critical",
+            IssueSeverity::Warning => "warning",
+            IssueSeverity::Info => "info",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            IssueSeverity::Critical => "🔴",
+            IssueSeverity::Warning => "🟡",
+            IssueSeverity::Info => "🔵",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ClusterAnalysis {
     pub namespaces: Vec<NamespaceAnalysis>,
+    pub nodes: Vec<NodeInfo>,
+    pub cluster_issues: Vec<ClusterIssue>, // Issues of cluster-scoped objects such as nodes
+    pub capacity: CapacityReport,
     pub total_pods: usize,
     pub total_deployments: usize,
+    pub total_workloads: usize,
     pub total_issues: usize,
 }
 
@@ -60,200 +153,529 @@ pub struct NamespaceAnalysis {
     pub name: String,
     pub pods: Vec<PodInfo>,
     pub deployments: Vec<DeploymentInfo>,
+    pub workloads: NamespaceWorkloads,
+    pub networking: NamespaceNetworking,
+    pub events: Vec<EventInfo>,
     pub issues: Vec<ClusterIssue>,
+    pub ownership: OwnershipGraph,
 }
 
-pub fn load_namespaces() -> Result<Vec<NamespaceInfo>> {
-    let mut namespaces = Vec::new();
+impl NamespaceAnalysis {
+    /// Everything but the issues, parsed from the objects of the namespace.
+    pub fn from_objects(objects: &NamespaceObjects, metrics: &MetricsSnapshot) -> Self {
+        let mut pods: Vec<PodInfo> = objects.pods.iter().filter_map(parse_pod).collect();
+        let mut deployments: Vec<DeploymentInfo> = objects
+            .deployments
+            .iter()
+            .filter_map(parse_deployment)
+            .collect();
+        let events = events::parse_events(&objects.events);
+        events::attach_events(&mut pods, &mut deployments, &events);
+        metrics::attach_usage(&mut pods, &objects.namespace, metrics);
+
+        NamespaceAnalysis {
+            name: objects.namespace.clone(),
+            pods,
+            deployments,
+            workloads: NamespaceWorkloads::from_objects(objects),
+            networking: NamespaceNetworking::from_objects(objects),
+            events,
+            issues: Vec::new(),
+            ownership: OwnershipGraph::from_objects(objects),
+        }
+    }
+
+    /// (name, component_type) of every component, in the order the analysis screen lists them.
+    pub fn components(&self) -> Vec<(String, String)> {
+        let mut components = Vec::new();
+        for pod in &self.pods {
+            components.push((pod.name.clone(), "Pod".to_string()));
+        }
+        for deployment in &self.deployments {
+            components.push((deployment.name.clone(), "Deployment".to_string()));
+        }
+        for kind in WorkloadKind::ALL {
+            for summary in self.workloads.summaries(kind) {
+                components.push((summary.name, kind.label().to_string()));
+            }
+        }
+        components
+    }
 
-    let output_dir = Path::new("output");
-    if !output_dir.exists() {
-        return Err("Output directory not found".into());
+    /// Pods of a workload, through the ownership graph.
+    pub fn pods_of(&self, kind: &str, name: &str) -> Vec<&PodInfo> {
+        let names = self.ownership.pods_of(kind, name);
+        self.pods
+            .iter()
+            .filter(|pod| names.contains(&pod.name.as_str()))
+            .collect()
     }
 
-    for entry in fs::read_dir(output_dir)? {
-        let entry = entry?;
-        if entry.file_type()?.is_dir() {
-            let namespace_name = entry.file_name().to_string_lossy().to_string();
+    /// Issues of a workload and of the objects it owns.
+    pub fn workload_issues(&self, kind: &str, name: &str) -> Vec<&ClusterIssue> {
+        let mut issues: Vec<&ClusterIssue> = self
+            .issues
+            .iter()
+            .filter(|issue| {
+                (issue.component_type == kind && issue.component == name)
+                    || issue
+                        .owner
+                        .as_ref()
+                        .is_some_and(|owner| owner.is(kind, name))
+            })
+            .collect();
+        issues.sort_by_key(|issue| issue.severity);
+        issues
+    }
+}
 
-            // Count pods and deployments
-            let pods = load_pods(&namespace_name).unwrap_or_default();
-            let deployments = load_deployments(&namespace_name).unwrap_or_default();
+pub fn load_namespaces(dump: &DumpRoot) -> Result<Vec<NamespaceInfo>> {
+    let mut namespaces = Vec::new();
 
-            namespaces.push(NamespaceInfo {
-                name: namespace_name,
-                pod_count: pods.len(),
-                deployment_count: deployments.len(),
-            });
-        }
+    for namespace_name in dump.namespace_names()? {
+        // Count pods and deployments
+        let pods = load_pods(dump, &namespace_name).unwrap_or_default();
+        let deployments = load_deployments(dump, &namespace_name).unwrap_or_default();
+
+        namespaces.push(NamespaceInfo {
+            name: namespace_name,
+            pod_count: pods.len(),
+            deployment_count: deployments.len(),
+        });
     }
 
-    namespaces.sort_by(|a, b| a.name.cmp(&b.name));
     Ok(namespaces)
 }
 
-pub fn load_pods(namespace: &str) -> Result<Vec<PodInfo>> {
+/// Raw objects of a namespace, as parsed from the dump. Rules inspect these directly.
+#[derive(Debug, Clone, Default)]
+pub struct NamespaceObjects {
+    pub namespace: String,
+    pub pods: Vec<Value>,
+    pub deployments: Vec<Value>,
+    pub statefulsets: Vec<Value>,
+    pub daemonsets: Vec<Value>,
+    pub replicasets: Vec<Value>,
+    pub jobs: Vec<Value>,
+    pub cronjobs: Vec<Value>,
+    pub services: Vec<Value>,
+    pub endpoints: Vec<Value>,
+    pub ingresses: Vec<Value>,
+    pub events: Vec<Value>,
+}
+
+/// Objects of the whole dump, for rules that look across namespaces.
+#[derive(Debug, Clone, Default)]
+pub struct ClusterObjects {
+    pub nodes: Vec<Value>,
+    pub namespaces: Vec<NamespaceObjects>,
+    pub metrics: MetricsSnapshot,
+}
+
+pub fn load_namespace_objects(dump: &DumpRoot, namespace: &str) -> NamespaceObjects {
+    let load = |file_name: &str| load_objects(dump, namespace, file_name).unwrap_or_default();
+
+    NamespaceObjects {
+        namespace: namespace.to_string(),
+        pods: load("pods.json"),
+        deployments: load("deployments.json"),
+        statefulsets: load(WorkloadKind::StatefulSet.file_name()),
+        daemonsets: load(WorkloadKind::DaemonSet.file_name()),
+        replicasets: load(WorkloadKind::ReplicaSet.file_name()),
+        jobs: load(WorkloadKind::Job.file_name()),
+        cronjobs: load(WorkloadKind::CronJob.file_name()),
+        services: load(networking::SERVICES_FILE),
+        endpoints: load(networking::ENDPOINTS_FILE),
+        ingresses: load(networking::INGRESSES_FILE),
+        events: load(events::EVENTS_FILE),
+    }
+}
+
+/// Pods, deployments, workloads, networking, events and ownership of a namespace,
+/// read with a single load of its objects. The issues are left to the rules.
+pub fn load_namespace_analysis(dump: &DumpRoot, namespace: &str) -> NamespaceAnalysis {
+    NamespaceAnalysis::from_objects(&load_namespace_objects(dump, namespace), dump.metrics())
+}
+
+pub fn load_ownership(dump: &DumpRoot, namespace: &str) -> OwnershipGraph {
+    OwnershipGraph::from_objects(&load_namespace_objects(dump, namespace))
+}
+
+/// Events of a namespace, oldest first.
+pub fn load_events(dump: &DumpRoot, namespace: &str) -> Vec<EventInfo> {
+    let items = load_objects(dump, namespace, events::EVENTS_FILE).unwrap_or_default();
+    events::parse_events(&items)
+}
+
+/// Objects stored in a namespace's dump file (`pods.json`, ...), read from the JSON or
+/// YAML manifests of that resource, whatever the layout of the dump. A missing file
+/// yields no items.
+pub fn load_objects(dump: &DumpRoot, namespace: &str, file_name: &str) -> Result<Vec<Value>> {
+    let resource = file_name.strip_suffix(".json").unwrap_or(file_name);
+    if dump.layout() == DumpLayout::KubectlLists {
+        return Ok(dump.listed_objects(namespace, resource));
+    }
+
+    let mut objects = Vec::new();
+    for file in dump.manifest_files(namespace, resource) {
+        let content = dump.read_to_string(&file)?;
+        objects.extend(parse_manifest(&file, &content)?);
+    }
+    Ok(objects)
+}
+
+/// Cluster-scoped objects stored in a dump file (`nodes.json`, ...), read like
+/// `load_objects`.
+pub fn load_cluster_objects(dump: &DumpRoot, file_name: &str) -> Result<Vec<Value>> {
+    let resource = file_name.strip_suffix(".json").unwrap_or(file_name);
+    if dump.layout() == DumpLayout::KubectlLists {
+        return Ok(dump.listed_objects("", resource));
+    }
+
+    let mut objects = Vec::new();
+    for file in dump.cluster_manifest_files(resource) {
+        let content = dump.read_to_string(&file)?;
+        objects.extend(parse_manifest(&file, &content)?);
+    }
+    Ok(objects)
+}
+
+/// Nodes of the cluster, sorted by name. Dumps without `nodes.json` have none.
+pub fn load_nodes(dump: &DumpRoot) -> Vec<NodeInfo> {
+    parse_nodes(&load_cluster_objects(dump, NODES_FILE).unwrap_or_default())
+}
+
+/// (namespace, pod) of every pod scheduled on a node, across all namespaces.
+pub fn load_scheduled_pods(dump: &DumpRoot) -> Vec<(String, PodInfo)> {
     let mut pods = Vec::new();
-    let pods_file = format!("output/{}/pods.json", namespace);
-
-    if Path::new(&pods_file).exists() {
-        let content = fs::read_to_string(&pods_file)?;
-        let pods_data: Value = serde_json::from_str(&content)?;
-
-        if let Some(items) = pods_data["items"].as_array() {
-            for pod in items.iter() {
-                if let (Some(name), Some(status)) = (
-                    pod["metadata"]["name"].as_str(),
-                    pod["status"]["phase"].as_str(),
-                ) {
-                    let ready = pod["status"]["conditions"]
-                        .as_array()
-                        .and_then(|conditions| conditions.iter().find(|c| c["type"] == "Ready"))
-                        .and_then(|ready_condition| ready_condition["status"].as_str())
-                        .map(|status| status == "True")
-                        .unwrap_or(false);
-
-                    // Extract additional fields
-                    let cpu_usage = pod["usage"]["cpu"].as_str().map(|s| s.to_string());
-                    let memory_usage = pod["usage"]["memory"].as_str().map(|s| s.to_string());
-                    let restart_count = pod["status"]["containerStatuses"]
-                        .as_array()
-                        .and_then(|containers| containers.get(0))
-                        .and_then(|container| container["restartCount"].as_u64())
-                        .map(|count| count.to_string());
-
-                    let image = pod["spec"]["containers"]
-                        .as_array()
-                        .and_then(|containers| containers.get(0))
-                        .and_then(|container| container["image"].as_str())
-                        .map(|s| s.to_string());
-
-                    pods.push(PodInfo {
-                        name: name.to_string(),
-                        status: status.to_string(),
-                        ready,
-                        cpu_usage,
-                        memory_usage,
-                        restart_count,
-                        image,
-                    });
-                }
-            }
-        }
+    for namespace in dump.namespace_names().unwrap_or_default() {
+        let items = load_objects(dump, &namespace, "pods.json").unwrap_or_default();
+        let mut namespace_pods: Vec<PodInfo> = items
+            .iter()
+            .filter_map(parse_pod)
+            .filter(|pod| pod.node_name.is_some())
+            .collect();
+        metrics::attach_usage(&mut namespace_pods, &namespace, dump.metrics());
+        pods.extend(
+            namespace_pods
+                .into_iter()
+                .map(|pod| (namespace.clone(), pod)),
+        );
     }
+    pods
+}
 
+pub fn load_pods(dump: &DumpRoot, namespace: &str) -> Result<Vec<PodInfo>> {
+    let items = load_objects(dump, namespace, "pods.json")?;
+    let mut pods: Vec<PodInfo> = items.iter().filter_map(parse_pod).collect();
+    events::attach_events(&mut pods, &mut [], &load_events(dump, namespace));
+    metrics::attach_usage(&mut pods, namespace, dump.metrics());
     Ok(pods)
 }
 
-pub fn load_deployments(namespace: &str) -> Result<Vec<DeploymentInfo>> {
-    let mut deployments = Vec::new();
-    let deployments_file = format!("output/{}/deployments.json", namespace);
-
-    if Path::new(&deployments_file).exists() {
-        let content = fs::read_to_string(&deployments_file)?;
-        let deployments_data: Value = serde_json::from_str(&content)?;
-
-        if let Some(items) = deployments_data["items"].as_array() {
-            for deployment in items.iter() {
-                if let Some(name) = deployment["metadata"]["name"].as_str() {
-                    let ready_replicas =
-                        deployment["status"]["readyReplicas"].as_u64().unwrap_or(0) as u32;
-                    let desired_replicas =
-                        deployment["spec"]["replicas"].as_u64().unwrap_or(0) as u32;
-
-                    // Extract additional fields
-                    let strategy = deployment["spec"]["strategy"]["type"]
-                        .as_str()
-                        .map(|s| s.to_string());
-                    let image = deployment["spec"]["template"]["spec"]["containers"]
-                        .as_array()
-                        .and_then(|containers| containers.get(0))
-                        .and_then(|container| container["image"].as_str())
-                        .map(|s| s.to_string());
-
-                    deployments.push(DeploymentInfo {
-                        name: name.to_string(),
-                        ready_replicas,
-                        desired_replicas,
-                        strategy,
-                        image,
-                    });
-                }
-            }
+pub fn load_deployments(dump: &DumpRoot, namespace: &str) -> Result<Vec<DeploymentInfo>> {
+    let items = load_objects(dump, namespace, "deployments.json")?;
+    let mut deployments: Vec<DeploymentInfo> = items.iter().filter_map(parse_deployment).collect();
+    events::attach_events(&mut [], &mut deployments, &load_events(dump, namespace));
+    Ok(deployments)
+}
+
+pub fn parse_pod(pod: &Value) -> Option<PodInfo> {
+    let name = pod["metadata"]["name"].as_str()?;
+    let status = pod["status"]["phase"].as_str()?;
+
+    let ready = pod["status"]["conditions"]
+        .as_array()
+        .and_then(|conditions| conditions.iter().find(|c| c["type"] == "Ready"))
+        .and_then(|ready_condition| ready_condition["status"].as_str())
+        .map(|status| status == "True")
+        .unwrap_or(false);
+
+    // Older analyzer dumps store the usage in the pod itself; metrics files override it
+    let usage = Quantity::from_value(&pod["usage"]["cpu"])
+        .zip(Quantity::from_value(&pod["usage"]["memory"]))
+        .map(|(cpu, memory)| ResourceUsage { cpu, memory });
+    let containers = parse_containers(pod);
+
+    // Restarts of every container, not only the first one
+    let restart_count = if pod["status"]["containerStatuses"].is_array() {
+        Some(
+            containers
+                .iter()
+                .map(|c| c.restart_count)
+                .sum::<u32>()
+                .to_string(),
+        )
+    } else {
+        None
+    };
+
+    // The image of the main (first app) container
+    let image = containers
+        .iter()
+        .find(|c| c.kind == ContainerKind::App)
+        .and_then(|c| c.image.clone());
+    let labels = networking::string_map(&pod["metadata"]["labels"]);
+
+    Some(PodInfo {
+        name: name.to_string(),
+        status: status.to_string(),
+        ready,
+        usage,
+        restart_count,
+        image,
+        labels,
+        node_name: pod["spec"]["nodeName"].as_str().map(|s| s.to_string()),
+        containers,
+        events: Vec::new(),
+    })
+}
+
+/// Init, app and ephemeral containers of a pod, with their spec and status merged by name.
+pub fn parse_containers(pod: &Value) -> Vec<ContainerInfo> {
+    let groups = [
+        (
+            ContainerKind::Init,
+            "initContainers",
+            "initContainerStatuses",
+        ),
+        (ContainerKind::App, "containers", "containerStatuses"),
+        (
+            ContainerKind::Ephemeral,
+            "ephemeralContainers",
+            "ephemeralContainerStatuses",
+        ),
+    ];
+
+    let mut containers = Vec::new();
+    for (kind, spec_key, status_key) in groups {
+        let statuses = pod["status"][status_key].as_array();
+
+        for spec in pod["spec"][spec_key].as_array().into_iter().flatten() {
+            let name = match spec["name"].as_str() {
+                Some(name) => name,
+                None => continue,
+            };
+            let status = statuses
+                .and_then(|statuses| statuses.iter().find(|s| s["name"] == name))
+                .unwrap_or(&Value::Null);
+
+            // The current termination if the container is stopped, otherwise the previous one
+            let terminated = if status["state"]["terminated"].is_object() {
+                &status["state"]["terminated"]
+            } else {
+                &status["lastState"]["terminated"]
+            };
+
+            let resources = &spec["resources"];
+            containers.push(ContainerInfo {
+                name: name.to_string(),
+                kind,
+                image: spec["image"].as_str().map(|s| s.to_string()),
+                state: container_state(&status["state"]),
+                last_termination_reason: terminated["reason"].as_str().map(|s| s.to_string()),
+                exit_code: terminated["exitCode"].as_i64(),
+                restart_count: status["restartCount"].as_u64().unwrap_or(0) as u32,
+                ready: status["ready"].as_bool().unwrap_or(false),
+                resources: ContainerResources {
+                    cpu_request: quantity_string(&resources["requests"]["cpu"]),
+                    cpu_limit: quantity_string(&resources["limits"]["cpu"]),
+                    memory_request: quantity_string(&resources["requests"]["memory"]),
+                    memory_limit: quantity_string(&resources["limits"]["memory"]),
+                },
+                usage: None,
+            });
         }
     }
 
-    Ok(deployments)
+    containers
 }
 
-pub fn analyze_cluster() -> Result<ClusterAnalysis> {
-    let namespaces = load_namespaces()?;
+/// "Running", "Waiting: CrashLoopBackOff", "Terminated: Completed", ...
+fn container_state(state: &Value) -> String {
+    for (key, label) in [
+        ("running", "Running"),
+        ("waiting", "Waiting"),
+        ("terminated", "Terminated"),
+    ] {
+        if state[key].is_object() {
+            return match state[key]["reason"].as_str() {
+                Some(reason) => format!("{}: {}", label, reason),
+                None => label.to_string(),
+            };
+        }
+    }
+    "Unknown".to_string()
+}
+
+/// Resource quantities may be written as strings ("500m") or bare numbers (1).
+fn quantity_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(quantity) => Some(quantity.clone()),
+        Value::Number(quantity) => Some(quantity.to_string()),
+        _ => None,
+    }
+}
+
+pub fn parse_deployment(deployment: &Value) -> Option<DeploymentInfo> {
+    let name = deployment["metadata"]["name"].as_str()?;
+    let ready_replicas = deployment["status"]["readyReplicas"].as_u64().unwrap_or(0) as u32;
+    // An omitted `spec.replicas` means 1, as the API server defaults it
+    let desired_replicas = deployment["spec"]["replicas"].as_u64().unwrap_or(1) as u32;
+
+    // Extract additional fields
+    let strategy = deployment["spec"]["strategy"]["type"]
+        .as_str()
+        .map(|s| s.to_string());
+    let image = deployment["spec"]["template"]["spec"]["containers"]
+        .as_array()
+        .and_then(|containers| containers.first())
+        .and_then(|container| container["image"].as_str())
+        .map(|s| s.to_string());
+
+    Some(DeploymentInfo {
+        name: name.to_string(),
+        ready_replicas,
+        desired_replicas,
+        strategy,
+        image,
+        events: Vec::new(),
+    })
+}
+
+impl ClusterAnalysis {
+    /// Number of selectable components on the analysis screen.
+    pub fn component_count(&self) -> usize {
+        self.namespaces
+            .iter()
+            .map(|ns_analysis| {
+                ns_analysis.pods.len()
+                    + ns_analysis.deployments.len()
+                    + ns_analysis.workloads.total()
+            })
+            .sum()
+    }
+
+    /// Issues of every namespace, then the cluster-scoped ones.
+    fn all_issues(&self) -> impl Iterator<Item = &ClusterIssue> {
+        self.namespaces
+            .iter()
+            .flat_map(|ns_analysis| ns_analysis.issues.iter())
+            .chain(self.cluster_issues.iter())
+    }
+
+    /// All issues across namespaces, most severe first.
+    pub fn issues_by_severity(&self) -> Vec<&ClusterIssue> {
+        let mut issues: Vec<&ClusterIssue> = self.all_issues().collect();
+        issues.sort_by_key(|issue| issue.severity);
+        issues
+    }
+
+    /// Issues grouped by namespace, most severe first inside each group. Namespaces
+    /// with the most severe issues come first; namespaces without issues are skipped.
+    /// Cluster-scoped issues are grouped under the empty namespace name.
+    pub fn issues_by_namespace(&self) -> Vec<(&str, Vec<&ClusterIssue>)> {
+        let mut groups: Vec<(&str, Vec<&ClusterIssue>)> = self
+            .namespaces
+            .iter()
+            .map(|ns_analysis| (ns_analysis.name.as_str(), &ns_analysis.issues))
+            .chain(std::iter::once(("", &self.cluster_issues)))
+            .filter(|(_, issues)| !issues.is_empty())
+            .map(|(name, issues)| {
+                let mut issues: Vec<&ClusterIssue> = issues.iter().collect();
+                issues.sort_by_key(|issue| issue.severity);
+                (name, issues)
+            })
+            .collect();
+        groups.sort_by_key(|(name, issues)| (issues[0].severity, *name));
+        groups
+    }
+
+    pub fn issue_count(&self, severity: IssueSeverity) -> usize {
+        self.all_issues()
+            .filter(|issue| issue.severity == severity)
+            .count()
+    }
+}
+
+pub fn analyze_cluster(dump: &DumpRoot, rules: &RuleRegistry) -> Result<ClusterAnalysis> {
+    let namespaces = load_namespaces(dump)?;
     let mut namespace_analyses = Vec::new();
     let mut total_pods = 0;
     let mut total_deployments = 0;
+    let mut total_workloads = 0;
     let mut total_issues = 0;
+    let mut cluster = ClusterObjects {
+        nodes: load_cluster_objects(dump, NODES_FILE).unwrap_or_default(),
+        namespaces: Vec::new(),
+        metrics: dump.metrics().clone(),
+    };
 
     for namespace in &namespaces {
-        let pods = load_pods(&namespace.name).unwrap_or_default();
-        let deployments = load_deployments(&namespace.name).unwrap_or_default();
+        let objects = load_namespace_objects(dump, &namespace.name);
+        let mut ns_analysis = NamespaceAnalysis::from_objects(&objects, &cluster.metrics);
 
         // Analyze issues in this namespace
-        let mut issues = Vec::new();
-
-        // Check for pod issues
-        for pod in &pods {
-            if !pod.ready || pod.status != "Running" {
-                issues.push(ClusterIssue {
-                    severity: IssueSeverity::Warning,
-                    component: pod.name.clone(),
-                    component_type: "Pod".to_string(),
-                    namespace: namespace.name.clone(),
-                    description: format!(
-                        "Pod {} is not ready or not running (status: {})",
-                        pod.name, pod.status
-                    ),
-                });
-            }
-        }
+        ns_analysis.issues = rules.check_namespace(&objects);
 
-        // Check for deployment issues
-        for deployment in &deployments {
-            if deployment.ready_replicas != deployment.desired_replicas {
-                let severity = if deployment.ready_replicas == 0 {
-                    IssueSeverity::Critical
-                } else {
-                    IssueSeverity::Warning
-                };
-
-                issues.push(ClusterIssue {
-                    severity,
-                    component: deployment.name.clone(),
-                    component_type: "Deployment".to_string(),
-                    namespace: namespace.name.clone(),
-                    description: format!(
-                        "Deployment {} has {}/{} replicas ready",
-                        deployment.name, deployment.ready_replicas, deployment.desired_replicas
-                    ),
-                });
-            }
+        total_pods += ns_analysis.pods.len();
+        total_deployments += ns_analysis.deployments.len();
+        total_workloads += ns_analysis.workloads.total();
+        total_issues += ns_analysis.issues.len();
+
+        namespace_analyses.push(ns_analysis);
+        cluster.namespaces.push(objects);
+    }
+
+    // Issues found across namespaces go with the namespace of their object
+    let mut cluster_issues = Vec::new();
+    for issue in rules.check_cluster(&cluster) {
+        total_issues += 1;
+        match namespace_analyses
+            .iter_mut()
+            .find(|ns_analysis| ns_analysis.name == issue.namespace)
+        {
+            Some(ns_analysis) => ns_analysis.issues.push(issue),
+            None => cluster_issues.push(issue),
         }
+    }
 
-        total_pods += pods.len();
-        total_deployments += deployments.len();
-        total_issues += issues.len();
+    // Roll the issues of pods, ReplicaSets and Jobs up to the workload that owns them
+    for ns_analysis in namespace_analyses.iter_mut() {
+        for issue in ns_analysis.issues.iter_mut() {
+            issue.owner = ns_analysis
+                .ownership
+                .root_owner(&issue.component_type, &issue.component)
+                .cloned();
+        }
+    }
 
-        namespace_analyses.push(NamespaceAnalysis {
-            name: namespace.name.clone(),
-            pods,
-            deployments,
-            issues,
-        });
+    let nodes = parse_nodes(&cluster.nodes);
+    let mut capacity = CapacityReport::new(
+        &nodes,
+        namespace_analyses.iter().flat_map(|ns_analysis| {
+            ns_analysis
+                .pods
+                .iter()
+                .map(|pod| (ns_analysis.name.as_str(), pod))
+        }),
+    );
+
+    for allocation in capacity.nodes.iter_mut() {
+        allocation.usage = cluster
+            .metrics
+            .node(&allocation.node)
+            .map(|metrics| metrics.usage);
     }
 
     Ok(ClusterAnalysis {
         namespaces: namespace_analyses,
+        nodes,
+        cluster_issues,
+        capacity,
         total_pods,
         total_deployments,
+        total_workloads,
         total_issues,
     })
 }
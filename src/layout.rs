@@ -0,0 +1,223 @@
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use crate::dump::DumpRoot;
+use crate::manifest::{is_manifest_file_name, parse_manifest};
+
+/// Top-level file written by `kubectl cluster-info dump`.
+pub const NODES_FILE: &str = "nodes.json";
+
+/// Directories of a must-gather namespace holding the objects of each API group.
+pub const API_GROUP_DIRS: [&str; 6] = [
+    "core",
+    "apps",
+    "batch",
+    "networking.k8s.io",
+    "extensions",
+    "discovery.k8s.io",
+];
+
+/// Resource name (the stem of the dump files) of each kind of object.
+const KIND_RESOURCES: [(&str, &str); 15] = [
+    ("Pod", "pods"),
+    ("Deployment", "deployments"),
+    ("StatefulSet", "statefulsets"),
+    ("DaemonSet", "daemonsets"),
+    ("ReplicaSet", "replicasets"),
+    ("Job", "jobs"),
+    ("CronJob", "cronjobs"),
+    ("Service", "services"),
+    ("Endpoints", "endpoints"),
+    ("Ingress", "ingresses"),
+    ("Event", "events"),
+    ("Node", "nodes"),
+    ("Namespace", "namespaces"),
+    ("PodMetrics", "pod-metrics"),
+    ("NodeMetrics", "node-metrics"),
+];
+
+/// How the files of a dump are organised.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DumpLayout {
+    /// `<ns>/pods.json` and `<ns>/<pod>/logs.txt`, one directory per container or
+    /// a single log file per pod.
+    Analyzer,
+    /// `kubectl cluster-info dump --output-directory`: like `Analyzer`, plus a top-level
+    /// `nodes.json`, and the logs of all the containers of a pod concatenated in
+    /// `<ns>/<pod>/logs.txt` between `==== START logs for container ...` markers.
+    ClusterInfoDump,
+    /// `kubectl get <kind> -A -o json > <kind>.json` files at the root of the dump, each
+    /// holding the objects of every namespace.
+    KubectlLists,
+    /// OpenShift must-gather and `oc adm inspect` bundles: YAML files under
+    /// `namespaces/<ns>/<group>/`, somewhere below the root of the dump.
+    MustGather,
+}
+
+impl DumpLayout {
+    /// Look at the top of the dump to tell how it is organised. Must-gather bundles
+    /// are found by `must_gather_namespaces_dir` instead.
+    pub fn detect(dump: &DumpRoot) -> Self {
+        let entries = dump.read_dir(dump.path());
+        let has_manifest = |dir: &Path| {
+            dump.read_dir(dir)
+                .iter()
+                .any(|entry| !entry.is_dir && is_manifest_file_name(&entry.name))
+        };
+
+        let has_namespace_dirs = entries
+            .iter()
+            .filter(|entry| entry.is_dir)
+            .any(|entry| has_manifest(&dump.path().join(&entry.name)));
+        let has_root_lists = has_manifest(dump.path());
+
+        if has_namespace_dirs {
+            if dump.is_file(&dump.path().join(NODES_FILE)) {
+                DumpLayout::ClusterInfoDump
+            } else {
+                DumpLayout::Analyzer
+            }
+        } else if has_root_lists {
+            DumpLayout::KubectlLists
+        } else {
+            DumpLayout::Analyzer
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DumpLayout::Analyzer => "analyzer dump",
+            DumpLayout::ClusterInfoDump => "kubectl cluster-info dump",
+            DumpLayout::KubectlLists => "kubectl List files",
+            DumpLayout::MustGather => "must-gather",
+        }
+    }
+}
+
+/// The `namespaces` directory of a must-gather bundle, looked for at the root of the
+/// dump and two levels below (`must-gather.local.<id>/<image>/namespaces`).
+pub fn must_gather_namespaces_dir(dump: &DumpRoot) -> Option<PathBuf> {
+    let mut dirs = vec![dump.path().to_path_buf()];
+    for _ in 0..3 {
+        let mut children = Vec::new();
+        for dir in dirs {
+            let namespaces_dir = dir.join("namespaces");
+            if is_must_gather_namespaces_dir(dump, &namespaces_dir) {
+                return Some(namespaces_dir);
+            }
+            children.extend(
+                dump.read_dir(&dir)
+                    .into_iter()
+                    .filter(|entry| entry.is_dir)
+                    .map(|entry| dir.join(entry.name)),
+            );
+        }
+        dirs = children;
+    }
+    None
+}
+
+/// A directory of namespaces with API group subdirectories, like `<ns>/core/`.
+fn is_must_gather_namespaces_dir(dump: &DumpRoot, dir: &Path) -> bool {
+    dump.read_dir(dir)
+        .into_iter()
+        .filter(|namespace| namespace.is_dir)
+        .any(|namespace| {
+            dump.read_dir(&dir.join(&namespace.name))
+                .iter()
+                .any(|group| group.is_dir && API_GROUP_DIRS.contains(&group.name.as_str()))
+        })
+}
+
+/// The objects of the `kubectl get -o json` (or `-o yaml`) files at the root of a dump,
+/// split by namespace and by resource.
+#[derive(Debug, Default)]
+pub struct ListedObjects {
+    objects: HashMap<String, HashMap<&'static str, Vec<Value>>>,
+    namespaces: BTreeSet<String>,
+}
+
+impl ListedObjects {
+    /// Read every manifest file at the root of the dump. Files that are not Kubernetes
+    /// objects or Lists are skipped.
+    pub fn load(dump: &DumpRoot) -> Self {
+        let mut listed = ListedObjects::default();
+        for entry in dump.read_dir(dump.path()) {
+            if entry.is_dir || !is_manifest_file_name(&entry.name) {
+                continue;
+            }
+            let path = dump.path().join(&entry.name);
+            let Ok(content) = dump.read_to_string(&path) else {
+                continue;
+            };
+            let Ok(objects) = parse_manifest(&path, &content) else {
+                continue;
+            };
+            let file_stem = path.file_stem().unwrap_or_default().to_string_lossy();
+            listed.add_objects(&file_stem, objects);
+        }
+        listed
+    }
+
+    fn add_objects(&mut self, file_stem: &str, objects: Vec<Value>) {
+        for object in objects {
+            let resource = match object["kind"].as_str() {
+                Some(kind) => resource_of_kind(kind),
+                None => known_resource(file_stem),
+            };
+            let Some(resource) = resource else {
+                continue;
+            };
+
+            if resource == "namespaces" {
+                if let Some(name) = object["metadata"]["name"].as_str() {
+                    self.namespaces.insert(name.to_string());
+                }
+                continue;
+            }
+            let namespace = object["metadata"]["namespace"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string();
+            if !namespace.is_empty() {
+                self.namespaces.insert(namespace.clone());
+            }
+            self.objects
+                .entry(namespace)
+                .or_default()
+                .entry(resource)
+                .or_default()
+                .push(object);
+        }
+    }
+
+    /// Names of the namespaces that hold objects or were listed themselves, sorted.
+    pub fn namespace_names(&self) -> Vec<String> {
+        self.namespaces.iter().cloned().collect()
+    }
+
+    /// Objects of a resource (`pods`, `deployments`, ...) in a namespace. Cluster-scoped
+    /// objects like nodes are in the `""` namespace.
+    pub fn objects(&self, namespace: &str, resource: &str) -> Vec<Value> {
+        self.objects
+            .get(namespace)
+            .and_then(|resources| resources.get(resource))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+fn resource_of_kind(kind: &str) -> Option<&'static str> {
+    KIND_RESOURCES
+        .iter()
+        .find(|(candidate, _)| *candidate == kind)
+        .map(|(_, resource)| *resource)
+}
+
+fn known_resource(name: &str) -> Option<&'static str> {
+    KIND_RESOURCES
+        .iter()
+        .find(|(_, candidate)| *candidate == name)
+        .map(|(_, resource)| *resource)
+}
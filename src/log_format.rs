@@ -0,0 +1,526 @@
+use serde_json::Value;
+
+use crate::logs::LogLevel;
+
+/// A structured log line format, e.g. klog or logfmt.
+pub trait LogFormat {
+    /// Short name shown in the logs viewer, e.g. `klog`.
+    fn name(&self) -> &'static str;
+    /// Parse one line, without its CRI prefix. `None` if the line is not in this format.
+    fn parse_line(&self, line: &str) -> Option<ParsedLine>;
+    /// False for formats that only ever show up in a few lines of a file, which must not
+    /// be picked as the format of the whole file.
+    fn is_file_format(&self) -> bool {
+        true
+    }
+}
+
+/// The parts of a log line a format could extract. Unknown parts are left to the
+/// plain-text heuristics of `logs.rs`.
+#[derive(Debug, Clone, Default)]
+pub struct ParsedLine {
+    pub timestamp: Option<String>,
+    pub level: Option<LogLevel>,
+    pub message: String,
+    pub source: Option<String>,
+    pub fields: Vec<(String, String)>, // Extra key/value pairs, in line order
+}
+
+/// `<RFC 3339 timestamp> <stdout|stderr> <F|P> ` prefix the container runtime writes in
+/// front of every line of `/var/log/pods` files.
+#[derive(Debug, Clone)]
+pub struct CriPrefix {
+    pub timestamp: String,
+    pub stream: String,
+    pub partial: bool, // `P`: the line continues in the next one
+}
+
+/// Picks the format most lines of a log file are written in. Lines in another format
+/// (e.g. a Go panic after JSON logs) are still tried against every format.
+pub struct FormatDetector {
+    formats: Vec<Box<dyn LogFormat>>,
+}
+
+/// How many non-empty lines `detect` looks at.
+const DETECTION_SAMPLE: usize = 50;
+
+impl FormatDetector {
+    pub fn builtin() -> Self {
+        FormatDetector {
+            formats: vec![
+                Box::new(JsonLines),
+                Box::new(Klog),
+                Box::new(Logfmt),
+                Box::new(GoPanic),
+            ],
+        }
+    }
+
+    /// Index of the format that parses the most sample lines, if any parses at least half.
+    pub fn detect<'a>(&self, lines: impl Iterator<Item = &'a str>) -> Option<usize> {
+        let sample: Vec<&str> = lines
+            .map(|line| strip_cri_prefix(line).map_or(line, |(_, payload)| payload))
+            .filter(|line| !line.trim().is_empty())
+            // Panic traces and the like say nothing about the rest of the file
+            .filter(|line| {
+                !self
+                    .formats
+                    .iter()
+                    .any(|format| !format.is_file_format() && format.parse_line(line).is_some())
+            })
+            .take(DETECTION_SAMPLE)
+            .collect();
+        if sample.is_empty() {
+            return None;
+        }
+
+        let (index, parsed) = self
+            .formats
+            .iter()
+            .map(|format| {
+                if !format.is_file_format() {
+                    return 0;
+                }
+                sample
+                    .iter()
+                    .filter(|line| format.parse_line(line).is_some())
+                    .count()
+            })
+            .enumerate()
+            .max_by_key(|(index, parsed)| (*parsed, std::cmp::Reverse(*index)))?;
+        (parsed > 0 && parsed * 2 >= sample.len()).then_some(index)
+    }
+
+    pub fn name(&self, index: usize) -> &'static str {
+        self.formats[index].name()
+    }
+
+    /// Parse a line with the detected format first, then with the others.
+    pub fn parse_line(&self, detected: Option<usize>, line: &str) -> Option<ParsedLine> {
+        let preferred = detected.and_then(|index| self.formats.get(index));
+        preferred
+            .into_iter()
+            .chain(self.formats.iter())
+            .find_map(|format| format.parse_line(line))
+    }
+}
+
+/// Split off the CRI prefix of a line, if it has one.
+pub fn strip_cri_prefix(line: &str) -> Option<(CriPrefix, &str)> {
+    let mut parts = line.splitn(4, ' ');
+    let timestamp = parts.next()?;
+    let stream = parts.next()?;
+    let tag = parts.next()?;
+    let payload = parts.next().unwrap_or("");
+
+    if !matches!(stream, "stdout" | "stderr") || !matches!(tag, "F" | "P") {
+        return None;
+    }
+    // The runtime always writes RFC 3339 with nanoseconds
+    if timestamp.len() < 20 || timestamp.as_bytes()[10] != b'T' {
+        return None;
+    }
+
+    Some((
+        CriPrefix {
+            timestamp: timestamp.to_string(),
+            stream: stream.to_string(),
+            partial: tag == "P",
+        },
+        payload,
+    ))
+}
+
+/// Newline-delimited JSON, as written by zap, logrus, slog, bunyan/pino and the Docker
+/// `json-file` driver.
+struct JsonLines;
+
+const JSON_TIME_KEYS: [&str; 5] = ["time", "ts", "timestamp", "@timestamp", "T"];
+const JSON_LEVEL_KEYS: [&str; 5] = ["level", "lvl", "severity", "L", "loglevel"];
+const JSON_MESSAGE_KEYS: [&str; 5] = ["msg", "message", "M", "log", "text"];
+const JSON_SOURCE_KEYS: [&str; 5] = ["logger", "caller", "source", "component", "N"];
+
+impl LogFormat for JsonLines {
+    fn name(&self) -> &'static str {
+        "json"
+    }
+
+    fn parse_line(&self, line: &str) -> Option<ParsedLine> {
+        let line = line.trim();
+        if !line.starts_with('{') {
+            return None;
+        }
+        let object = match serde_json::from_str::<Value>(line).ok()? {
+            Value::Object(object) => object,
+            _ => return None,
+        };
+
+        let mut parsed = ParsedLine::default();
+        let mut used = Vec::new();
+        let mut take = |keys: &[&str]| {
+            let key = keys.iter().find(|key| object.contains_key(**key))?;
+            used.push(key.to_string());
+            object.get(*key)
+        };
+
+        parsed.timestamp = take(&JSON_TIME_KEYS).map(json_text);
+        parsed.level = take(&JSON_LEVEL_KEYS).map(json_level);
+        parsed.message = take(&JSON_MESSAGE_KEYS)
+            .map(json_text)
+            .unwrap_or_default()
+            .trim_end()
+            .to_string();
+        parsed.source = take(&JSON_SOURCE_KEYS).map(json_source);
+
+        parsed.fields = object
+            .iter()
+            .filter(|(key, _)| !used.contains(key))
+            .map(|(key, value)| (key.clone(), json_text(value)))
+            .collect();
+        Some(parsed)
+    }
+}
+
+fn json_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Named levels, or bunyan/pino numeric ones (10 trace … 60 fatal).
+fn json_level(value: &Value) -> LogLevel {
+    match value.as_u64() {
+        Some(level) if level >= 50 => LogLevel::Error,
+        Some(level) if level >= 40 => LogLevel::Warning,
+        Some(level) if level >= 30 => LogLevel::Info,
+        Some(_) => LogLevel::Debug,
+        None => LogLevel::from_str(&json_text(value)),
+    }
+}
+
+/// slog writes the source as `{"function": ..., "file": ..., "line": ...}`.
+fn json_source(value: &Value) -> String {
+    match (value["file"].as_str(), value["line"].as_u64()) {
+        (Some(file), Some(line)) => format!("{}:{}", file, line),
+        _ => json_text(value),
+    }
+}
+
+/// Kubernetes components: `I0102 15:04:05.123456    1 controller.go:42] message`, with
+/// klog v2 structured lines written as `"message" key="value" ...`.
+struct Klog;
+
+impl LogFormat for Klog {
+    fn name(&self) -> &'static str {
+        "klog"
+    }
+
+    fn parse_line(&self, line: &str) -> Option<ParsedLine> {
+        let level = match line.chars().next()? {
+            'I' => LogLevel::Info,
+            'W' => LogLevel::Warning,
+            'E' | 'F' => LogLevel::Error,
+            _ => return None,
+        };
+
+        let (header, message) = line
+            .split_once("] ")
+            .or_else(|| line.strip_suffix(']').map(|header| (header, "")))?;
+        // Lmmdd hh:mm:ss.uuuuuu threadid file:line
+        let mut parts = header[1..].split_whitespace();
+        let date = parts.next()?;
+        let clock = parts.next()?;
+        let _thread_id: u64 = parts.next()?.parse().ok()?;
+        let location = parts.next()?;
+        if date.len() != 4
+            || !date.bytes().all(|b| b.is_ascii_digit())
+            || !clock.contains(':')
+            || parts.next().is_some()
+        {
+            return None;
+        }
+
+        let (message, fields) = match message.strip_prefix('"') {
+            Some(quoted) => match take_quoted(quoted) {
+                Some((text, rest)) => (text, parse_pairs(rest, true).unwrap_or_default()),
+                None => (message.to_string(), Vec::new()),
+            },
+            None => (message.to_string(), Vec::new()),
+        };
+
+        Some(ParsedLine {
+            timestamp: Some(format!("{} {}", date, clock)),
+            level: Some(level),
+            message,
+            source: Some(location.to_string()),
+            fields,
+        })
+    }
+}
+
+/// `time=... level=error msg="..." key=value`, as written by logrus' text formatter,
+/// go-kit and slog's TextHandler.
+struct Logfmt;
+
+impl LogFormat for Logfmt {
+    fn name(&self) -> &'static str {
+        "logfmt"
+    }
+
+    fn parse_line(&self, line: &str) -> Option<ParsedLine> {
+        let pairs = parse_pairs(line, false)?;
+        let is_key = |key: &str, names: &[&str]| names.contains(&key);
+        if !pairs
+            .iter()
+            .any(|(key, _)| is_key(key, &["level", "lvl", "msg", "message"]))
+        {
+            return None;
+        }
+
+        let mut parsed = ParsedLine::default();
+        for (key, value) in pairs {
+            match key.as_str() {
+                "time" | "ts" | "timestamp" | "t" if parsed.timestamp.is_none() => {
+                    parsed.timestamp = Some(value)
+                }
+                "level" | "lvl" | "severity" if parsed.level.is_none() => {
+                    parsed.level = Some(LogLevel::from_str(&value))
+                }
+                "msg" | "message" if parsed.message.is_empty() => parsed.message = value,
+                "logger" | "caller" | "source" | "component" if parsed.source.is_none() => {
+                    parsed.source = Some(value)
+                }
+                _ => parsed.fields.push((key, value)),
+            }
+        }
+        Some(parsed)
+    }
+}
+
+/// Go runtime panics and fatal errors, with their goroutine stack traces.
+struct GoPanic;
+
+impl LogFormat for GoPanic {
+    fn name(&self) -> &'static str {
+        "go-panic"
+    }
+
+    fn is_file_format(&self) -> bool {
+        false
+    }
+
+    fn parse_line(&self, line: &str) -> Option<ParsedLine> {
+        let trimmed = line.trim_end();
+        let is_panic_line = trimmed.starts_with("panic: ")
+            || trimmed.starts_with("fatal error: ")
+            || trimmed.starts_with("[signal ")
+            || trimmed.starts_with("created by ")
+            || (trimmed.starts_with("goroutine ") && trimmed.ends_with("]:"))
+            || (trimmed.starts_with('\t') && trimmed.contains(".go:"))
+            || is_stack_frame(trimmed);
+        if !is_panic_line {
+            return None;
+        }
+
+        Some(ParsedLine {
+            level: Some(LogLevel::Error),
+            message: trimmed.to_string(),
+            source: Some("panic".to_string()),
+            ..ParsedLine::default()
+        })
+    }
+}
+
+/// `main.main()`, `net/http.(*conn).serve(0xc000128000, {0x7c1a98, 0xc00009e0c0})`
+fn is_stack_frame(line: &str) -> bool {
+    let Some(paren) = line.find('(') else {
+        return false;
+    };
+    let function = &line[..paren];
+    let function = function.strip_suffix('.').unwrap_or(function);
+    line.ends_with(')')
+        && function.contains('.')
+        && function
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '.' | '/' | '_' | '-' | '*' | '(' | ')'))
+        && !function.is_empty()
+}
+
+/// Lines that continue the log entry before them: indented lines, Java/Python/Go stack
+/// trace lines and chained exception headers.
+pub fn is_continuation_line(line: &str) -> bool {
+    let trimmed = line.trim();
+    line.starts_with([' ', '\t'])
+        || trimmed.starts_with("at ")
+        || (trimmed.starts_with("... ") && trimmed.ends_with(" more"))
+        || trimmed.starts_with("Caused by:")
+        || trimmed.starts_with("Suppressed:")
+        || trimmed.starts_with("Traceback (most recent call last)")
+        || trimmed.starts_with("During handling of the above exception")
+        || trimmed.starts_with("[signal ")
+        || trimmed.starts_with("created by ")
+        || (trimmed.starts_with("goroutine ") && trimmed.ends_with("]:"))
+        || is_stack_frame(trimmed)
+}
+
+/// `java.lang.IllegalStateException: message`, `ValueError: message`, `KeyError`
+pub fn is_exception_line(line: &str) -> bool {
+    let name = line.split(':').next().unwrap_or("").trim_end();
+    let simple_name = name.rsplit('.').next().unwrap_or(name);
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '.' | '_' | '$'))
+        && ["Exception", "Error", "Throwable", "Panic"]
+            .iter()
+            .any(|suffix| simple_name.ends_with(suffix))
+}
+
+/// `key=value key2="quoted value"` pairs. Unless `lenient`, any token that is not a pair
+/// makes the whole line fail to parse.
+fn parse_pairs(text: &str, lenient: bool) -> Option<Vec<(String, String)>> {
+    let mut pairs = Vec::new();
+    let mut rest = text.trim_start();
+
+    while !rest.is_empty() {
+        let token_end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let Some(equals) = rest[..token_end].find('=').filter(|&equals| equals > 0) else {
+            if !lenient {
+                return None;
+            }
+            rest = rest[token_end..].trim_start();
+            continue;
+        };
+
+        let key = rest[..equals].to_string();
+        let after = &rest[equals + 1..];
+        let (value, remainder) = match after.strip_prefix('"') {
+            Some(quoted) => take_quoted(quoted)?,
+            None => {
+                let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                (after[..end].to_string(), &after[end..])
+            }
+        };
+        pairs.push((key, value));
+        rest = remainder.trim_start();
+    }
+
+    Some(pairs)
+}
+
+/// Text up to the closing quote (the opening one already removed), with `\"`, `\\`,
+/// `\n` and `\t` unescaped. Returns the text and what follows the closing quote.
+fn take_quoted(text: &str) -> Option<(String, &str)> {
+    let mut value = String::new();
+    let mut chars = text.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, &text[i + 1..])),
+            '\\' => match chars.next()?.1 {
+                'n' => value.push('\n'),
+                't' => value.push('\t'),
+                other => value.push(other),
+            },
+            other => value.push(other),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detected_name(lines: &[&str]) -> Option<&'static str> {
+        let detector = FormatDetector::builtin();
+        detector
+            .detect(lines.iter().copied())
+            .map(|index| detector.name(index))
+    }
+
+    #[test]
+    fn detects_json_lines() {
+        let lines = [
+            r#"{"level":"info","ts":"2024-01-02T15:04:05Z","msg":"started"}"#,
+            r#"{"level":"error","ts":"2024-01-02T15:04:06Z","msg":"failed","err":"boom"}"#,
+        ];
+        assert_eq!(detected_name(&lines), Some("json"));
+
+        let parsed = FormatDetector::builtin()
+            .parse_line(None, lines[1])
+            .unwrap();
+        assert_eq!(parsed.level, Some(LogLevel::Error));
+        assert_eq!(parsed.message, "failed");
+        assert_eq!(parsed.timestamp.as_deref(), Some("2024-01-02T15:04:06Z"));
+    }
+
+    #[test]
+    fn detects_klog() {
+        let lines = [
+            "I0102 15:04:05.000000       1 server.go:42] Starting server",
+            "W0102 15:04:06.000000       1 server.go:50] Slow request",
+        ];
+        assert_eq!(detected_name(&lines), Some("klog"));
+    }
+
+    #[test]
+    fn detects_logfmt() {
+        let lines = [
+            r#"time=2024-01-02T15:04:05Z level=info msg="listening on :8080""#,
+            r#"time=2024-01-02T15:04:06Z level=warn msg="slow client" duration=2s"#,
+        ];
+        assert_eq!(detected_name(&lines), Some("logfmt"));
+    }
+
+    #[test]
+    fn detects_the_format_behind_cri_prefixes() {
+        let lines = [
+            r#"2024-01-02T15:04:05.123456789Z stdout F {"level":"info","msg":"a"}"#,
+            r#"2024-01-02T15:04:06.123456789Z stderr F {"level":"warn","msg":"b"}"#,
+        ];
+        assert_eq!(detected_name(&lines), Some("json"));
+
+        let (prefix, payload) = strip_cri_prefix(lines[1]).unwrap();
+        assert_eq!(prefix.stream, "stderr");
+        assert!(!prefix.partial);
+        assert_eq!(payload, r#"{"level":"warn","msg":"b"}"#);
+    }
+
+    #[test]
+    fn panic_traces_do_not_decide_the_format() {
+        let lines = [
+            r#"{"level":"info","msg":"started"}"#,
+            "panic: runtime error: index out of range",
+            "goroutine 1 [running]:",
+            "main.main()",
+            "\t/app/main.go:12 +0x1d",
+        ];
+        assert_eq!(detected_name(&lines), Some("json"));
+    }
+
+    #[test]
+    fn plain_text_has_no_format() {
+        let lines = ["Starting the app", "Listening on port 8080", "Ready"];
+        assert_eq!(detected_name(&lines), None);
+        assert_eq!(detected_name(&[]), None);
+    }
+
+    #[test]
+    fn recognises_continuation_and_exception_lines() {
+        assert!(is_continuation_line(
+            "    at com.example.App.main(App.java:10)"
+        ));
+        assert!(is_continuation_line(
+            "Caused by: java.io.IOException: closed"
+        ));
+        assert!(is_continuation_line("... 12 more"));
+        assert!(!is_continuation_line("INFO request served"));
+        assert!(is_exception_line(
+            "java.lang.IllegalStateException: bad state"
+        ));
+        assert!(is_exception_line("KeyError"));
+        assert!(!is_exception_line("Error count: 3 requests failed"));
+    }
+}
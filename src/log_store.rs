@@ -0,0 +1,404 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+
+use crate::error::Result;
+use crate::log_format::FormatDetector;
+use crate::logs::{entry_container, LogEntry, LogLevel, TextLogParser};
+
+/// Log files at least this big are opened through a `LogStore` instead of being
+/// parsed whole.
+pub const LARGE_LOG_FILE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Entries parsed at a time when showing a large log file.
+pub const WINDOW_ENTRIES: usize = 2_000;
+
+/// One entry in this many has its byte offset in the index.
+const CHECKPOINT_INTERVAL: usize = 256;
+
+/// Lines read at open time to detect the format.
+const DETECT_LINES: usize = 200;
+
+/// Entries the indexing thread collects before publishing them.
+const INDEX_BATCH: usize = 16_384;
+
+/// Where an indexed entry starts in the file.
+#[derive(Debug, Clone, Default)]
+struct Checkpoint {
+    offset: u64,
+    line: usize, // 0-based
+    // Container section the entry is in, for pod logs with sections
+    container: Option<String>,
+}
+
+/// Sparse index of a log file: a checkpoint every `CHECKPOINT_INTERVAL` entries and the
+/// level of every entry (one byte each), so even huge files only cost a few bytes per
+/// entry.
+#[derive(Default)]
+struct LogIndex {
+    checkpoints: Vec<Checkpoint>,
+    levels: Vec<LogLevel>,
+    error_count: usize,
+    warning_count: usize,
+    bytes_indexed: u64,
+    done: bool,
+    error: Option<String>,
+}
+
+impl LogIndex {
+    fn append(&mut self, batch: &mut LogIndex) {
+        self.checkpoints.append(&mut batch.checkpoints);
+        self.levels.append(&mut batch.levels);
+        self.error_count += batch.error_count;
+        self.warning_count += batch.warning_count;
+        batch.error_count = 0;
+        batch.warning_count = 0;
+    }
+}
+
+/// How far the background indexing of a `LogStore` got.
+#[derive(Debug, Clone, Default)]
+pub struct IndexProgress {
+    pub entries: usize,
+    pub error_count: usize,
+    pub warning_count: usize,
+    pub bytes_indexed: u64,
+    pub file_size: u64,
+    pub done: bool,
+    pub error: Option<String>,
+}
+
+impl IndexProgress {
+    pub fn percent(&self) -> u64 {
+        if self.done || self.file_size == 0 {
+            100
+        } else {
+            self.bytes_indexed * 100 / self.file_size
+        }
+    }
+}
+
+/// A text log file parsed on demand: a background thread indexes where the entries
+/// start, and only the entries asked for with `load_entries` are parsed.
+///
+/// Entry positions are in file order. Whole-document JSON logs are not supported and
+/// are read line by line like any other text log.
+pub struct LogStore {
+    path: PathBuf,
+    file_size: u64,
+    detected: Option<usize>,
+    format: &'static str,
+    index: Arc<Mutex<LogIndex>>,
+    indexing: AtomicBool,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl fmt::Debug for LogStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogStore")
+            .field("path", &self.path)
+            .field("format", &self.format)
+            .finish()
+    }
+}
+
+/// True if the file is big enough to be opened through a `LogStore`.
+pub fn is_large_log_file(path: &Path) -> bool {
+    path.metadata()
+        .map(|metadata| metadata.len() >= LARGE_LOG_FILE_BYTES)
+        .unwrap_or(false)
+}
+
+impl LogStore {
+    /// Open a log file and detect its format from the first lines. Indexing only
+    /// starts with `start_indexing`.
+    pub fn open(path: &Path) -> Result<Self> {
+        let file = File::open(path)?;
+        let file_size = file.metadata()?.len();
+
+        let mut reader = BufReader::new(file);
+        let mut sample = Vec::new();
+        let mut buffer = Vec::new();
+        while sample.len() < DETECT_LINES {
+            match read_line(&mut reader, &mut buffer)? {
+                Some((line, _)) => sample.push(line),
+                None => break,
+            }
+        }
+        let detected = FormatDetector::builtin().detect(sample.iter().map(String::as_str));
+        let format = TextLogParser::new(detected).format();
+
+        Ok(LogStore {
+            path: path.to_path_buf(),
+            file_size,
+            detected,
+            format,
+            index: Arc::new(Mutex::new(LogIndex::default())),
+            indexing: AtomicBool::new(false),
+            cancelled: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    pub fn format(&self) -> &'static str {
+        self.format
+    }
+
+    /// Start indexing the file in a background thread, the first time it is called.
+    pub fn start_indexing(&self) {
+        if self.indexing.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        let path = self.path.clone();
+        let detected = self.detected;
+        let index = Arc::clone(&self.index);
+        let cancelled = Arc::clone(&self.cancelled);
+        thread::spawn(move || {
+            let result = build_index(&path, detected, &index, &cancelled);
+            let mut index = index
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            if let Err(error) = result {
+                index.error = Some(error.to_string());
+            }
+            index.done = true;
+        });
+    }
+
+    pub fn progress(&self) -> IndexProgress {
+        let index = self.lock_index();
+        IndexProgress {
+            entries: index.levels.len(),
+            error_count: index.error_count,
+            warning_count: index.warning_count,
+            bytes_indexed: index.bytes_indexed,
+            file_size: self.file_size,
+            done: index.done,
+            error: index.error.clone(),
+        }
+    }
+
+    /// Parse `count` entries starting at entry `start`. Works past the indexed part of
+    /// the file too, by reading on from the last checkpoint.
+    pub fn load_entries(&self, start: usize, count: usize) -> Result<Vec<LogEntry>> {
+        let mut entries = Vec::with_capacity(count.min(WINDOW_ENTRIES));
+        if count == 0 {
+            return Ok(entries);
+        }
+        self.scan_from(start / CHECKPOINT_INTERVAL, |position, entry| {
+            if position >= start {
+                entries.push(entry);
+            }
+            entries.len() < count
+        })?;
+        Ok(entries)
+    }
+
+    /// Call `visit` with every entry of the file in order, until it returns false.
+    pub fn for_each_entry(&self, mut visit: impl FnMut(LogEntry) -> bool) -> Result<()> {
+        self.scan_from(0, |_, entry| visit(entry))
+    }
+
+    /// Position of the entry holding the 1-based `line_number`.
+    pub fn entry_at_line(&self, line_number: usize) -> Result<Option<usize>> {
+        let checkpoint = {
+            let index = self.lock_index();
+            index
+                .checkpoints
+                .partition_point(|checkpoint| checkpoint.line < line_number)
+                .saturating_sub(1)
+        };
+        let mut found = None;
+        self.scan_from(checkpoint, |position, entry| {
+            if entry.line_number > line_number {
+                return false;
+            }
+            found = Some(position);
+            true
+        })?;
+        Ok(found)
+    }
+
+    /// Nearest indexed entry with `level` from position `from` (included), looking
+    /// forward or backward.
+    pub fn find_level(&self, from: usize, forward: bool, level: &LogLevel) -> Option<usize> {
+        let index = self.lock_index();
+        if forward {
+            let start = from.min(index.levels.len());
+            index.levels[start..]
+                .iter()
+                .position(|candidate| candidate == level)
+                .map(|offset| start + offset)
+        } else {
+            let end = (from + 1).min(index.levels.len());
+            index.levels[..end]
+                .iter()
+                .rposition(|candidate| candidate == level)
+        }
+    }
+
+    /// Parse the file from a checkpoint, calling `visit` with the position and content
+    /// of each entry until it returns false.
+    fn scan_from(
+        &self,
+        checkpoint: usize,
+        mut visit: impl FnMut(usize, LogEntry) -> bool,
+    ) -> Result<()> {
+        let (checkpoint, start) = {
+            let index = self.lock_index();
+            match index.checkpoints.len().checked_sub(1) {
+                Some(last) => {
+                    let checkpoint = checkpoint.min(last);
+                    (
+                        index.checkpoints[checkpoint].clone(),
+                        checkpoint * CHECKPOINT_INTERVAL,
+                    )
+                }
+                None => (Checkpoint::default(), 0),
+            }
+        };
+
+        let mut file = File::open(&self.path)?;
+        file.seek(SeekFrom::Start(checkpoint.offset))?;
+        let mut reader = BufReader::new(file);
+        let mut parser = TextLogParser::resume(self.detected, checkpoint.container);
+        let mut buffer = Vec::new();
+        let mut line_num = checkpoint.line;
+        let mut position = start;
+
+        while let Some((line, _)) = read_line(&mut reader, &mut buffer)? {
+            if let Some(entry) = parser.push_line(&line, line_num) {
+                if !visit(position, entry) {
+                    return Ok(());
+                }
+                position += 1;
+            }
+            line_num += 1;
+        }
+        for entry in parser.finish() {
+            if !visit(position, entry) {
+                break;
+            }
+            position += 1;
+        }
+        Ok(())
+    }
+
+    fn lock_index(&self) -> MutexGuard<'_, LogIndex> {
+        // The indexing thread never panics while holding the lock, but a poisoned index
+        // is still usable
+        self.index
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Drop for LogStore {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Next line of the file without its line terminator, and the bytes it took.
+fn read_line(reader: &mut impl BufRead, buffer: &mut Vec<u8>) -> Result<Option<(String, u64)>> {
+    buffer.clear();
+    let read = reader.read_until(b'\n', buffer)?;
+    if read == 0 {
+        return Ok(None);
+    }
+    let mut line = buffer.as_slice();
+    if let Some(stripped) = line.strip_suffix(b"\n") {
+        line = stripped;
+    }
+    if let Some(stripped) = line.strip_suffix(b"\r") {
+        line = stripped;
+    }
+    Ok(Some((
+        String::from_utf8_lossy(line).into_owned(),
+        read as u64,
+    )))
+}
+
+/// Collects the index of the entries coming out of the parser.
+#[derive(Default)]
+struct IndexBuilder {
+    batch: LogIndex,
+    entry_count: usize,
+    // Offsets of the lines read since the start of the entry being parsed
+    line_offsets: VecDeque<(usize, u64)>,
+}
+
+impl IndexBuilder {
+    fn record(&mut self, entry: LogEntry) {
+        let first_line = entry.line_number - 1;
+        while self
+            .line_offsets
+            .front()
+            .is_some_and(|&(line, _)| line < first_line)
+        {
+            self.line_offsets.pop_front();
+        }
+        if self.entry_count.is_multiple_of(CHECKPOINT_INTERVAL) {
+            let offset = self.line_offsets.front().map_or(0, |&(_, offset)| offset);
+            self.batch.checkpoints.push(Checkpoint {
+                offset,
+                line: first_line,
+                container: entry_container(&entry).map(str::to_string),
+            });
+        }
+        match entry.level {
+            LogLevel::Error => self.batch.error_count += 1,
+            LogLevel::Warning => self.batch.warning_count += 1,
+            _ => {}
+        }
+        self.batch.levels.push(entry.level);
+        self.entry_count += 1;
+    }
+
+    fn publish(&mut self, index: &Mutex<LogIndex>, bytes_indexed: u64) {
+        let mut index = index
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        index.append(&mut self.batch);
+        index.bytes_indexed = bytes_indexed;
+    }
+}
+
+fn build_index(
+    path: &Path,
+    detected: Option<usize>,
+    index: &Mutex<LogIndex>,
+    cancelled: &AtomicBool,
+) -> Result<()> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut parser = TextLogParser::new(detected);
+    let mut builder = IndexBuilder::default();
+    let mut buffer = Vec::new();
+    let mut offset = 0;
+    let mut line_num = 0;
+
+    while let Some((line, read)) = read_line(&mut reader, &mut buffer)? {
+        if cancelled.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        builder.line_offsets.push_back((line_num, offset));
+        if let Some(entry) = parser.push_line(&line, line_num) {
+            builder.record(entry);
+        }
+        offset += read;
+        line_num += 1;
+
+        if builder.batch.levels.len() >= INDEX_BATCH {
+            builder.publish(index, offset);
+        }
+    }
+    for entry in parser.finish() {
+        builder.record(entry);
+    }
+    builder.publish(index, offset);
+    Ok(())
+}
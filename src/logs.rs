@@ -1,18 +1,59 @@
+use regex::{Captures, Regex, RegexBuilder};
 use serde_json::Value;
-use std::fs;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
 
+use crate::dump::DumpRoot;
 use crate::error::Result;
+use crate::layout::DumpLayout;
+use crate::log_format::{
+    is_continuation_line, is_exception_line, strip_cri_prefix, CriPrefix, FormatDetector,
+    ParsedLine,
+};
+use crate::log_store::{is_large_log_file, IndexProgress, LogStore, WINDOW_ENTRIES};
+use crate::timestamp::{leading_timestamp, parse_timestamp, Timestamp};
 
 #[derive(Debug, Clone)]
 pub struct LogEntry {
     pub timestamp: String,
+    pub time: Option<Timestamp>, // Parsed `timestamp`, used for sorting
+    pub line_number: usize,      // 1-based position in the log file
     pub level: LogLevel,
     pub message: String,
     pub source: String,
+    pub fields: Vec<(String, String)>, // Extra key/value pairs of structured formats
+    pub continuation: Vec<String>,     // Following lines folded into this entry (stack traces)
 }
 
-#[derive(Debug, Clone)]
+impl LogEntry {
+    pub fn new(timestamp: String, level: LogLevel, message: String, source: String) -> Self {
+        LogEntry {
+            time: parse_timestamp(&timestamp),
+            timestamp,
+            line_number: 0,
+            level,
+            message,
+            source,
+            fields: Vec::new(),
+            continuation: Vec::new(),
+        }
+    }
+
+    /// `key=value` pairs of the extra fields, space separated. The pod of merged workload
+    /// logs is left out: the logs viewer shows it in a column of its own.
+    pub fn fields_label(&self) -> String {
+        self.fields
+            .iter()
+            .filter(|(key, _)| key != POD_FIELD)
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum LogLevel {
     Error,
     Warning,
@@ -25,8 +66,23 @@ pub struct ComponentLogs {
     pub component_name: String,
     pub component_type: String,
     pub namespace: String,
+    pub container: Option<String>, // Set when the logs come from a single container
     pub entries: Vec<LogEntry>,
     pub total_entries: usize,
+    pub newest_first: bool,
+    pub format: &'static str,         // Detected log format, e.g. "klog"
+    pub store: Option<Arc<LogStore>>, // Large files: `entries` only holds a window of the file
+    pub window_start: usize,          // Display position of `entries[0]` in the whole file
+    pub pods: Vec<MergedPod>,         // Pods of merged workload logs, numbered from 1
+    pub hidden_pods: HashSet<String>, // Pods of merged workload logs the viewer hides
+}
+
+/// A pod whose logs are part of merged workload logs.
+#[derive(Debug, Clone)]
+pub struct MergedPod {
+    pub name: String,
+    pub entries: usize,
+    pub truncated: bool, // A large log file of the pod only contributed its first window
 }
 
 impl LogLevel {
@@ -76,86 +132,302 @@ impl LogLevel {
     }
 }
 
-pub fn load_pod_logs(namespace: &str, pod_name: &str) -> Result<ComponentLogs> {
+pub fn load_pod_logs(dump: &DumpRoot, namespace: &str, pod_name: &str) -> Result<ComponentLogs> {
     // Per la struttura reale che hai mostrato: output/cert-manager/cert-manager-cainjector-dc95f9d66-t6rg9/logs.txt
-    let logs_path = format!("output/{}/{}/logs.txt", namespace, pod_name);
-    load_component_logs(&logs_path, pod_name, "Pod", namespace)
-}
-
-pub fn load_deployment_logs(namespace: &str, deployment_name: &str) -> Result<ComponentLogs> {
-    // Prima prova deployment diretto, poi cerca tra i pod del deployment
-    let logs_path = format!("output/{}/{}/logs.txt", namespace, deployment_name);
-
-    // Se non trova deployment diretto, cerca pod correlati
-    if !Path::new(&logs_path).exists() {
-        // Cerca pod che iniziano con il nome del deployment
-        let namespace_dir = format!("output/{}", namespace);
-        if let Ok(entries) = fs::read_dir(&namespace_dir) {
-            for entry in entries.flatten() {
-                if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
-                    let dir_name = entry.file_name().to_string_lossy().to_string();
-                    if dir_name.starts_with(deployment_name) {
-                        // Trovato un pod del deployment, usa quello
-                        return load_component_logs(
-                            &format!("output/{}/{}/logs.txt", namespace, dir_name),
-                            &dir_name,
-                            "Pod",
-                            namespace,
-                        );
-                    }
+    let logs_path = dump.component_dir(namespace, pod_name).join("logs.txt");
+    let logs = load_component_logs(dump, &logs_path, pod_name, "Pod", namespace)?;
+
+    // Pods dumped with one directory per container have no pod-level log file
+    if logs.entries.is_empty() {
+        if let Some(container) = list_log_containers(dump, namespace, pod_name).first() {
+            return load_container_logs(dump, namespace, pod_name, container);
+        }
+    }
+    Ok(logs)
+}
+
+/// Logs of one container, from `<namespace>/<pod>/<container>/logs.txt`, or from its
+/// section of the pod log file in `kubectl cluster-info dump` layouts.
+pub fn load_container_logs(
+    dump: &DumpRoot,
+    namespace: &str,
+    pod_name: &str,
+    container_name: &str,
+) -> Result<ComponentLogs> {
+    let container_dir = dump.container_dir(namespace, pod_name, container_name);
+    let mut logs = match find_log_file(dump, &container_dir) {
+        Some(log_path) => match large_log_file(dump, &log_path) {
+            Some(local_path) => windowed_component_logs(&local_path, pod_name, "Pod", namespace)?,
+            None => {
+                let (entries, format) = parse_log_content(&dump.read_to_string(&log_path)?)?;
+                into_component_logs(entries, format, pod_name, "Pod", namespace)
+            }
+        },
+        None => match find_log_file(dump, &dump.component_dir(namespace, pod_name)) {
+            Some(log_path) => {
+                let (mut entries, format) = parse_log_content(&dump.read_to_string(&log_path)?)?;
+                entries.retain(|entry| entry_container(entry) == Some(container_name));
+                into_component_logs(entries, format, pod_name, "Pod", namespace)
+            }
+            None => into_component_logs(Vec::new(), PLAIN_TEXT_FORMAT, pod_name, "Pod", namespace),
+        },
+    };
+    logs.container = Some(container_name.to_string());
+    Ok(logs)
+}
+
+/// Names of the containers of a pod that have their own logs: a log directory, or a
+/// section of the pod log file.
+pub fn list_log_containers(dump: &DumpRoot, namespace: &str, pod_name: &str) -> Vec<String> {
+    let mut containers = list_container_log_dirs(dump, namespace, pod_name);
+    for container in list_log_sections(dump, namespace, pod_name) {
+        if !containers.contains(&container) {
+            containers.push(container);
+        }
+    }
+    containers
+}
+
+/// Names of the containers of a pod that have their own log directory.
+pub fn list_container_log_dirs(dump: &DumpRoot, namespace: &str, pod_name: &str) -> Vec<String> {
+    dump.container_names(namespace, pod_name)
+        .into_iter()
+        .filter(|container_name| {
+            find_log_file(
+                dump,
+                &dump.container_dir(namespace, pod_name, container_name),
+            )
+            .is_some()
+        })
+        .collect()
+}
+
+/// Containers with a section in the pod log file of a `kubectl cluster-info dump`, in
+/// file order.
+fn list_log_sections(dump: &DumpRoot, namespace: &str, pod_name: &str) -> Vec<String> {
+    let mut containers = Vec::new();
+    if dump.layout() != DumpLayout::ClusterInfoDump {
+        return containers;
+    }
+    let Some(log_path) = find_log_file(dump, &dump.component_dir(namespace, pod_name)) else {
+        return containers;
+    };
+    let Ok(file) = dump.open_file(&log_path) else {
+        return containers;
+    };
+    for line in BufReader::new(file).lines().map_while(|line| line.ok()) {
+        if let Some(SectionMarker::Start(container)) = section_marker(&line) {
+            if !containers.iter().any(|known| known == container) {
+                containers.push(container.to_string());
+            }
+        }
+    }
+    containers
+}
+
+/// Container an entry of a pod log file with sections came from.
+pub fn entry_container(entry: &LogEntry) -> Option<&str> {
+    entry
+        .fields
+        .iter()
+        .find(|(key, _)| key == CONTAINER_FIELD)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Pod an entry of merged workload logs came from.
+pub fn entry_pod(entry: &LogEntry) -> Option<&str> {
+    entry
+        .fields
+        .iter()
+        .find(|(key, _)| key == POD_FIELD)
+        .map(|(_, value)| value.as_str())
+}
+
+/// True if the pod directory itself holds a log file (as opposed to per-container ones).
+pub fn has_pod_log_file(dump: &DumpRoot, namespace: &str, pod_name: &str) -> bool {
+    find_log_file(dump, &dump.component_dir(namespace, pod_name)).is_some()
+}
+
+/// Logs of a workload: its own log file when the dump has one, otherwise the logs of
+/// the pods it owns (see `OwnershipGraph::pods_of`) and of their containers merged into
+/// one timeline. Merged entries get a `pod` field, and a `container` field when they come
+/// from a container log directory; a large log file contributes its first window, and
+/// its pod is marked as truncated.
+pub fn load_workload_logs(
+    dump: &DumpRoot,
+    namespace: &str,
+    kind: &str,
+    name: &str,
+    pods: &[&str],
+) -> Result<ComponentLogs> {
+    let logs_path = dump.component_dir(namespace, name).join("logs.txt");
+    if pods.is_empty() || dump.is_file(&logs_path) {
+        return load_component_logs(dump, &logs_path, name, kind, namespace);
+    }
+
+    // Every log source of every pod: the pod log file and the container log directories
+    let mut sources = Vec::new();
+    for pod in pods {
+        if has_pod_log_file(dump, namespace, pod) {
+            let pod_logs_path = dump.component_dir(namespace, pod).join("logs.txt");
+            if let Ok(logs) = load_component_logs(dump, &pod_logs_path, pod, "Pod", namespace) {
+                sources.push((*pod, None, logs));
+            }
+        }
+        for container in list_container_log_dirs(dump, namespace, pod) {
+            if let Ok(logs) = load_container_logs(dump, namespace, pod, &container) {
+                sources.push((*pod, Some(container), logs));
+            }
+        }
+    }
+
+    let mut merged_pods: Vec<MergedPod> = pods
+        .iter()
+        .map(|pod| MergedPod {
+            name: pod.to_string(),
+            entries: 0,
+            truncated: false,
+        })
+        .collect();
+    let mut keyed = Vec::new();
+    let mut format = PLAIN_TEXT_FORMAT;
+    for (source_index, (pod, container, mut logs)) in sources.into_iter().enumerate() {
+        if logs.newest_first {
+            logs.entries.reverse();
+        }
+        if !logs.entries.is_empty() {
+            format = logs.format;
+        }
+        if let Some(merged) = merged_pods.iter_mut().find(|merged| merged.name == pod) {
+            merged.entries += logs.entries.len();
+            merged.truncated |= logs.store.is_some();
+        }
+        // Entries without a timestamp stay after the entry preceding them in their file
+        let mut last_time = None;
+        for mut entry in logs.entries {
+            if entry.time.is_some() {
+                last_time = entry.time;
+            }
+            entry.fields.push((POD_FIELD.to_string(), pod.to_string()));
+            if let Some(ref container) = container {
+                if entry_container(&entry).is_none() {
+                    entry
+                        .fields
+                        .push((CONTAINER_FIELD.to_string(), container.clone()));
                 }
             }
+            keyed.push(((last_time, source_index, entry.line_number), entry));
         }
     }
+    keyed.sort_by_key(|(key, _)| *key);
 
-    load_component_logs(&logs_path, deployment_name, "Deployment", namespace)
+    // Line numbers identify entries: number them in the merged order
+    let entries = keyed
+        .into_iter()
+        .enumerate()
+        .map(|(index, (_, mut entry))| {
+            entry.line_number = index + 1;
+            entry
+        })
+        .collect();
+    let mut logs = into_component_logs(entries, format, name, kind, namespace);
+    logs.pods = merged_pods;
+    Ok(logs)
 }
 
-pub fn load_service_logs(namespace: &str, service_name: &str) -> Result<ComponentLogs> {
-    let logs_path = format!("output/{}/services/{}/logs.json", namespace, service_name);
-    load_component_logs(&logs_path, service_name, "Service", namespace)
+/// Names of the component directories in a namespace that contain a log file, directly
+/// or in per-container subdirectories.
+pub fn list_log_components(dump: &DumpRoot, namespace: &str) -> Vec<String> {
+    dump.component_names(namespace)
+        .into_iter()
+        .filter(|component_name| {
+            has_pod_log_file(dump, namespace, component_name)
+                || !list_log_containers(dump, namespace, component_name).is_empty()
+        })
+        .collect()
+}
+
+const LOG_FILE_NAMES: [&str; 5] = [
+    "logs.txt",
+    "log.txt",
+    "logs.json",
+    "logs.txt.gz",
+    "log.txt.gz",
+];
+
+fn find_log_file(dump: &DumpRoot, dir: &Path) -> Option<PathBuf> {
+    LOG_FILE_NAMES
+        .iter()
+        .map(|file_name| dir.join(file_name))
+        .find(|path| dump.is_file(path))
+}
+
+/// Plain or gzip-compressed `.txt` and `.log` files.
+pub fn is_log_file_name(name: &str) -> bool {
+    let name = name.strip_suffix(".gz").unwrap_or(name);
+    name.ends_with(".txt") || name.ends_with(".log")
+}
+
+/// The file on disk behind a log path, when it is big enough for a `LogStore`.
+/// Compressed and archived logs are always parsed whole.
+fn large_log_file(dump: &DumpRoot, log_path: &Path) -> Option<PathBuf> {
+    dump.local_path(log_path)
+        .filter(|local_path| is_large_log_file(local_path))
 }
 
 fn load_component_logs(
-    logs_path: &str,
+    dump: &DumpRoot,
+    logs_path: &Path,
     component_name: &str,
     component_type: &str,
     namespace: &str,
 ) -> Result<ComponentLogs> {
     let mut entries = Vec::new();
+    let mut format = PLAIN_TEXT_FORMAT;
     let mut found_logs = false;
 
+    let namespace_dir = dump.namespace_dir(namespace);
+    let component_dir = dump.component_dir(namespace, component_name);
+
     // Lista di possibili percorsi per i log
     let possible_paths = vec![
         // Struttura originale
-        logs_path.to_string(),
+        logs_path.to_path_buf(),
         // Struttura reale osservata
-        format!("output/{}/{}/logs.txt", namespace, component_name),
-        format!("output/{}/{}/log.txt", namespace, component_name),
+        component_dir.join("logs.txt"),
+        component_dir.join("log.txt"),
+        // Log ruotati e compressi
+        component_dir.join("logs.txt.gz"),
+        component_dir.join("log.txt.gz"),
         // Alternative comuni
-        format!("output/{}/logs/{}.log", namespace, component_name),
-        format!("output/{}/logs/{}.txt", namespace, component_name),
-        format!("output/{}/{}-logs.txt", namespace, component_name),
+        namespace_dir
+            .join("logs")
+            .join(format!("{}.log", component_name)),
+        namespace_dir
+            .join("logs")
+            .join(format!("{}.txt", component_name)),
+        namespace_dir.join(format!("{}-logs.txt", component_name)),
         // Prova anche con il tipo di componente
-        format!(
-            "output/{}/{}-{}/logs.txt",
-            namespace,
-            component_type.to_lowercase(),
-            component_name
-        ),
+        namespace_dir
+            .join(format!(
+                "{}-{}",
+                component_type.to_lowercase(),
+                component_name
+            ))
+            .join("logs.txt"),
     ];
 
     for log_path in possible_paths {
-        if Path::new(&log_path).exists() {
-            let content = fs::read_to_string(&log_path)?;
-
-            // Try to parse as JSON logs first
-            if let Ok(json) = serde_json::from_str::<Value>(&content) {
-                entries = parse_json_logs(&json)?;
-            } else {
-                // Fall back to plain text logs
-                entries = parse_text_logs(&content);
+        if dump.is_file(&log_path) {
+            if let Some(local_path) = large_log_file(dump, &log_path) {
+                return windowed_component_logs(
+                    &local_path,
+                    component_name,
+                    component_type,
+                    namespace,
+                );
             }
+            (entries, format) = parse_log_content(&dump.read_to_string(&log_path)?)?;
             found_logs = true;
             break;
         }
@@ -163,55 +435,117 @@ fn load_component_logs(
 
     // Se non troviamo log, cerca tutti i file .txt o .log nella directory del componente
     if !found_logs {
-        let component_dir = format!("output/{}/{}", namespace, component_name);
-        if let Ok(dir_entries) = fs::read_dir(&component_dir) {
-            for entry in dir_entries.flatten() {
-                let path = entry.path();
-                if let Some(extension) = path.extension() {
-                    if extension == "txt" || extension == "log" {
-                        if let Ok(content) = fs::read_to_string(&path) {
-                            entries = parse_text_logs(&content);
-                            found_logs = true;
-                            break;
-                        }
-                    }
-                }
+        for entry in dump.read_dir(&component_dir) {
+            if entry.is_dir || !is_log_file_name(&entry.name) {
+                continue;
+            }
+            let path = component_dir.join(&entry.name);
+            if let Some(local_path) = large_log_file(dump, &path) {
+                return windowed_component_logs(
+                    &local_path,
+                    component_name,
+                    component_type,
+                    namespace,
+                );
+            }
+            if let Ok(content) = dump.read_to_string(&path) {
+                (entries, format) = parse_text_logs(&content);
+                break;
             }
         }
     }
 
+    Ok(into_component_logs(
+        entries,
+        format,
+        component_name,
+        component_type,
+        namespace,
+    ))
+}
+
+const PLAIN_TEXT_FORMAT: &str = "text";
+
+/// Entries of a log file and the name of its format.
+fn parse_log_content(content: &str) -> Result<(Vec<LogEntry>, &'static str)> {
+    // Try to parse as JSON logs first
+    if let Ok(json) = serde_json::from_str::<Value>(content) {
+        Ok((parse_json_logs(&json)?, "json"))
+    } else {
+        // Fall back to plain text logs
+        Ok(parse_text_logs(content))
+    }
+}
+
+fn into_component_logs(
+    entries: Vec<LogEntry>,
+    format: &'static str,
+    component_name: &str,
+    component_type: &str,
+    namespace: &str,
+) -> ComponentLogs {
     let total_entries = entries.len();
 
-    // Sort by timestamp (most recent first) se abbiamo timestamp validi
-    entries.sort_by(|a, b| {
-        // Prova a confrontare timestamp, fallback su ordine alfabetico
-        match (parse_timestamp(&a.timestamp), parse_timestamp(&b.timestamp)) {
-            (Some(time_a), Some(time_b)) => time_b.cmp(&time_a), // Più recenti prima
-            _ => b.timestamp.cmp(&a.timestamp),                  // Fallback alfabetico inverso
+    // Chronological order. Entries without a timestamp stay right after the entry that
+    // precedes them in the file, and files without timestamps keep their line order.
+    let mut last_time = None;
+    let mut sort_keys = Vec::with_capacity(entries.len());
+    for entry in &entries {
+        if entry.time.is_some() {
+            last_time = entry.time;
         }
-    });
+        sort_keys.push((last_time, entry.line_number));
+    }
+    let mut keyed: Vec<_> = sort_keys.into_iter().zip(entries).collect();
+    keyed.sort_by_key(|(key, _)| *key);
+    let mut entries: Vec<LogEntry> = keyed.into_iter().map(|(_, entry)| entry).collect();
+
+    // Most recent first by default
+    entries.reverse();
 
-    Ok(ComponentLogs {
+    ComponentLogs {
         component_name: component_name.to_string(),
         component_type: component_type.to_string(),
         namespace: namespace.to_string(),
+        container: None,
         entries,
         total_entries,
-    })
+        newest_first: true,
+        format,
+        store: None,
+        window_start: 0,
+        pods: Vec::new(),
+        hidden_pods: HashSet::new(),
+    }
 }
 
-// Funzione helper per parsing timestamp
-fn parse_timestamp(timestamp: &str) -> Option<std::time::SystemTime> {
-    // Prova vari formati comuni
-    if timestamp.contains('T') && timestamp.contains('Z') {
-        // ISO format: 2024-01-01T10:00:00Z
-        return None; // Per ora, implementazione semplice
-    }
-    if timestamp.starts_with('[') && timestamp.contains(']') {
-        // Bracket format: [2024-01-01 10:00:00]
-        return None;
-    }
-    None
+/// Logs of a file too big to parse whole: only the first window of entries is parsed,
+/// and the rest is indexed in the background once `poll_store` is called.
+fn windowed_component_logs(
+    log_path: &Path,
+    component_name: &str,
+    component_type: &str,
+    namespace: &str,
+) -> Result<ComponentLogs> {
+    let store = LogStore::open(log_path)?;
+    let mut logs = ComponentLogs {
+        component_name: component_name.to_string(),
+        component_type: component_type.to_string(),
+        namespace: namespace.to_string(),
+        container: None,
+        entries: Vec::new(),
+        total_entries: 0,
+        // Entries are shown in file order: sorting would need the whole file
+        newest_first: false,
+        format: store.format(),
+        store: Some(Arc::new(store)),
+        window_start: 0,
+        pods: Vec::new(),
+        hidden_pods: HashSet::new(),
+    };
+    logs.load_window(0)?;
+    logs.total_entries = logs.entries.len();
+    Ok(logs)
 }
 
 fn parse_json_logs(json: &Value) -> Result<Vec<LogEntry>> {
@@ -219,15 +553,17 @@ fn parse_json_logs(json: &Value) -> Result<Vec<LogEntry>> {
 
     match json {
         Value::Array(logs) => {
-            for log in logs {
-                if let Some(entry) = parse_single_json_log(log) {
+            for (index, log) in logs.iter().enumerate() {
+                if let Some(mut entry) = parse_single_json_log(log) {
+                    entry.line_number = index + 1;
                     entries.push(entry);
                 }
             }
         }
         Value::Object(_) => {
             // Single log entry
-            if let Some(entry) = parse_single_json_log(json) {
+            if let Some(mut entry) = parse_single_json_log(json) {
+                entry.line_number = 1;
                 entries.push(entry);
             }
         }
@@ -267,27 +603,258 @@ fn parse_single_json_log(log: &Value) -> Option<LogEntry> {
         .unwrap_or("unknown")
         .to_string();
 
-    Some(LogEntry {
+    Some(LogEntry::new(
         timestamp,
-        level: LogLevel::from_str(level_str),
+        LogLevel::from_str(level_str),
         message,
         source,
-    })
+    ))
 }
 
-fn parse_text_logs(content: &str) -> Vec<LogEntry> {
+fn parse_text_logs(content: &str) -> (Vec<LogEntry>, &'static str) {
+    let detected = FormatDetector::builtin().detect(content.lines());
+    let mut parser = TextLogParser::new(detected);
     let mut entries = Vec::new();
 
     for (line_num, line) in content.lines().enumerate() {
+        entries.extend(parser.push_line(line, line_num));
+    }
+    entries.extend(parser.finish());
+
+    (entries, parser.format())
+}
+
+/// Field naming the container of the entries of a pod log file with sections.
+const CONTAINER_FIELD: &str = "container";
+/// Field naming the pod an entry of merged workload logs comes from.
+const POD_FIELD: &str = "pod";
+
+/// `kubectl cluster-info dump` concatenates the logs of the containers of a pod, each
+/// between `==== START logs for container <name> of pod <ns>/<pod> ====` and the
+/// matching `==== END ...` line.
+const SECTION_START: &str = "==== START logs for container ";
+const SECTION_END: &str = "==== END logs for container ";
+
+enum SectionMarker<'a> {
+    Start(&'a str),
+    End,
+}
+
+fn section_marker(line: &str) -> Option<SectionMarker<'_>> {
+    if let Some(rest) = line.strip_prefix(SECTION_START) {
+        return rest.split_whitespace().next().map(SectionMarker::Start);
+    }
+    line.starts_with(SECTION_END).then_some(SectionMarker::End)
+}
+
+/// Turns the lines of a text log into entries, one line at a time: joins the partial
+/// lines of CRI logs, folds continuation lines into the entry they belong to and tags
+/// the entries of container sections, so an entry is only complete once the line
+/// after it arrives.
+pub struct TextLogParser {
+    detector: FormatDetector,
+    detected: Option<usize>,
+    // CRI splits long lines into partial (`P`) chunks terminated by a full (`F`) one
+    partial: Option<(CriPrefix, String, usize)>,
+    pending: Option<LogEntry>,
+    // Container of the section being read, and whether a section marker came after
+    // the pending entry
+    container: Option<String>,
+    section_ended: bool,
+}
+
+impl TextLogParser {
+    /// A parser for lines in the format found by `FormatDetector::detect`.
+    pub fn new(detected: Option<usize>) -> Self {
+        Self::resume(detected, None)
+    }
+
+    /// A parser for lines read from the middle of a file, inside the section of
+    /// `container` if any.
+    pub fn resume(detected: Option<usize>, container: Option<String>) -> Self {
+        TextLogParser {
+            detector: FormatDetector::builtin(),
+            detected,
+            partial: None,
+            pending: None,
+            container,
+            section_ended: false,
+        }
+    }
+
+    /// Name of the detected format.
+    pub fn format(&self) -> &'static str {
+        self.detected
+            .map(|index| self.detector.name(index))
+            .unwrap_or(PLAIN_TEXT_FORMAT)
+    }
+
+    /// Feed the line at 0-based `line_num`; returns the entry it completed, if any.
+    pub fn push_line(&mut self, line: &str, line_num: usize) -> Option<LogEntry> {
         if line.trim().is_empty() {
-            continue;
+            return None;
         }
+        if let Some(marker) = section_marker(line) {
+            return self.push_section_marker(marker);
+        }
+
+        let (cri, payload, line_num) = match strip_cri_prefix(line) {
+            Some((prefix, payload)) => {
+                let is_partial = prefix.partial;
+                let (prefix, payload, line_num) = match self.partial.take() {
+                    Some((first, mut joined, first_line)) => {
+                        joined.push_str(payload);
+                        (first, joined, first_line)
+                    }
+                    None => (prefix, payload.to_string(), line_num),
+                };
+                if is_partial {
+                    self.partial = Some((prefix, payload, line_num));
+                    return None;
+                }
+                (Some(prefix), payload, line_num)
+            }
+            None => (None, line.to_string(), line_num),
+        };
+        self.push_payload(&payload, cri.as_ref(), line_num)
+    }
 
-        let entry = parse_single_text_log(line, line_num);
-        entries.push(entry);
+    /// The entries still held back at the end of the input.
+    pub fn finish(&mut self) -> Vec<LogEntry> {
+        let mut entries = Vec::new();
+        // A partial line cut off by the end of the file
+        if let Some((prefix, payload, line_num)) = self.partial.take() {
+            entries.extend(self.push_payload(&payload, Some(&prefix), line_num));
+        }
+        entries.extend(self.pending.take());
+        entries
     }
 
-    entries
+    /// Entries never span two sections: complete the partial line and keep the
+    /// pending entry from folding in the lines of the next section.
+    fn push_section_marker(&mut self, marker: SectionMarker) -> Option<LogEntry> {
+        let completed = self.partial.take().and_then(|(prefix, payload, line_num)| {
+            self.push_payload(&payload, Some(&prefix), line_num)
+        });
+        self.section_ended = true;
+        self.container = match marker {
+            SectionMarker::Start(name) => Some(name.to_string()),
+            SectionMarker::End => None,
+        };
+        completed
+    }
+
+    /// Start a new entry with the line, or fold it into the pending entry when it
+    /// continues it (stack trace frames, `Caused by:`, ...).
+    fn push_payload(
+        &mut self,
+        payload: &str,
+        cri: Option<&CriPrefix>,
+        line_num: usize,
+    ) -> Option<LogEntry> {
+        if !std::mem::take(&mut self.section_ended) {
+            if let Some(ref mut previous) = self.pending {
+                if continues_entry(payload, previous) {
+                    previous.continuation.push(payload.to_string());
+                    return None;
+                }
+            }
+        }
+        let mut entry = parse_line_entry(&self.detector, self.detected, payload, cri, line_num);
+        if let Some(ref container) = self.container {
+            entry
+                .fields
+                .push((CONTAINER_FIELD.to_string(), container.clone()));
+        }
+        self.pending.replace(entry)
+    }
+}
+
+fn continues_entry(line: &str, previous: &LogEntry) -> bool {
+    if leading_timestamp(line).is_some() {
+        return false;
+    }
+    // `java.lang.IllegalStateException: ...` right after the line that logged it
+    let follows_problem = matches!(previous.level, LogLevel::Error | LogLevel::Warning)
+        || !previous.continuation.is_empty();
+    is_continuation_line(line) || (follows_problem && is_exception_line(line))
+}
+
+fn parse_line_entry(
+    detector: &FormatDetector,
+    detected: Option<usize>,
+    payload: &str,
+    cri: Option<&CriPrefix>,
+    line_num: usize,
+) -> LogEntry {
+    let mut entry = match detector.parse_line(detected, payload) {
+        Some(parsed) => structured_log_entry(parsed, cri, line_num),
+        None => text_log_entry(payload, cri, line_num),
+    };
+    entry.line_number = line_num + 1;
+    // Formats the patterns below do not extract (syslog, epoch, ...)
+    if entry.time.is_none() {
+        entry.time = leading_timestamp(payload).map(|(time, _)| time);
+    }
+    entry
+}
+
+fn structured_log_entry(parsed: ParsedLine, cri: Option<&CriPrefix>, line_num: usize) -> LogEntry {
+    let level = parsed
+        .level
+        .or_else(|| extract_log_level_from_line(&parsed.message))
+        .or_else(|| cri.map(stream_level))
+        .unwrap_or(LogLevel::Info);
+    // The runtime timestamp has a year and nanoseconds, unlike klog's
+    let timestamp = cri
+        .map(|cri| cri.timestamp.clone())
+        .or(parsed.timestamp)
+        .unwrap_or_else(|| format!("line-{}", line_num + 1));
+    let source = parsed.source.unwrap_or_else(|| "app".to_string());
+
+    let mut entry = LogEntry::new(timestamp, level, parsed.message, source);
+    entry.fields = parsed.fields;
+    add_stream_field(&mut entry, cri);
+    entry
+}
+
+fn text_log_entry(payload: &str, cri: Option<&CriPrefix>, line_num: usize) -> LogEntry {
+    let mut entry = parse_single_text_log(payload, line_num);
+    if let Some(cri) = cri {
+        if entry.time.is_none() {
+            entry = LogEntry::new(
+                cri.timestamp.clone(),
+                entry.level,
+                entry.message,
+                entry.source,
+            );
+        }
+        if entry.source == "raw" {
+            // No level in the line itself: go by the stream it was written to
+            if extract_log_level_from_line(payload).is_none() {
+                entry.level = stream_level(cri);
+            }
+            entry.source = "container".to_string();
+        }
+        add_stream_field(&mut entry, Some(cri));
+    }
+    entry
+}
+
+fn stream_level(cri: &CriPrefix) -> LogLevel {
+    if cri.stream == "stderr" {
+        LogLevel::Error
+    } else {
+        LogLevel::Info
+    }
+}
+
+fn add_stream_field(entry: &mut LogEntry, cri: Option<&CriPrefix>) {
+    if let Some(cri) = cri.filter(|cri| cri.stream == "stderr") {
+        entry
+            .fields
+            .push(("stream".to_string(), cri.stream.clone()));
+    }
 }
 
 fn parse_single_text_log(line: &str, line_num: usize) -> LogEntry {
@@ -297,64 +864,38 @@ fn parse_single_text_log(line: &str, line_num: usize) -> LogEntry {
     // [INFO] plugin/reload: Running configuration...
     // [ERROR] plugin/kubernetes: Failed to watch...
     if let Some(captures) = parse_kubernetes_bracket_log(line) {
-        return LogEntry {
-            timestamp: format!("line-{}", line_num + 1),
-            level: LogLevel::from_str(&captures.0),
-            message: captures.1,
-            source: "kubernetes".to_string(),
-        };
+        return LogEntry::new(
+            format!("line-{}", line_num + 1),
+            LogLevel::from_str(&captures.0),
+            captures.1,
+            "kubernetes".to_string(),
+        );
     }
 
-    // Pattern 2: ISO timestamp + level
+    // Pattern 2: Timestamp + optional level
     // 2024-01-01T10:00:00Z INFO: Message here
-    if let Some(captures) = regex_extract_iso_log(line) {
-        return LogEntry {
-            timestamp: captures.0,
-            level: LogLevel::from_str(&captures.1),
-            message: captures.2,
-            source: "app".to_string(),
-        };
-    }
-
-    // Pattern 3: Simple timestamp + level
     // [2024-01-01 10:00:00] ERROR: Message here
-    if let Some(captures) = regex_extract_bracket_log(line) {
-        return LogEntry {
-            timestamp: captures.0,
-            level: LogLevel::from_str(&captures.1),
-            message: captures.2,
-            source: "app".to_string(),
-        };
+    if let Some(captures) = extract_timestamped_log(line) {
+        return LogEntry::new(captures.0, captures.1, captures.2, "app".to_string());
     }
 
-    // Pattern 4: Kubernetes pod logs
-    // 2024-01-01T10:00:00.000000000Z stderr F Message here
-    if let Some(captures) = regex_extract_k8s_log(line) {
-        return LogEntry {
-            timestamp: captures.0,
-            level: LogLevel::from_str(&captures.1),
-            message: captures.2,
-            source: "container".to_string(),
-        };
-    }
-
-    // Pattern 5: Check for log level anywhere in the line
+    // Pattern 3: Check for log level anywhere in the line
     if let Some(level) = extract_log_level_from_line(line) {
-        return LogEntry {
-            timestamp: format!("line-{}", line_num + 1),
+        return LogEntry::new(
+            format!("line-{}", line_num + 1),
             level,
-            message: line.to_string(),
-            source: "raw".to_string(),
-        };
+            line.to_string(),
+            "raw".to_string(),
+        );
     }
 
     // Fallback: treat entire line as message
-    LogEntry {
-        timestamp: format!("line-{}", line_num + 1),
-        level: LogLevel::Info,
-        message: line.to_string(),
-        source: "raw".to_string(),
-    }
+    LogEntry::new(
+        format!("line-{}", line_num + 1),
+        LogLevel::Info,
+        line.to_string(),
+        "raw".to_string(),
+    )
 }
 
 // Parser specifico per log Kubernetes con [LEVEL]
@@ -396,79 +937,227 @@ fn extract_log_level_from_line(line: &str) -> Option<LogLevel> {
     }
 }
 
-// Simple regex-like extraction (without regex crate to keep dependencies minimal)
-fn regex_extract_iso_log(line: &str) -> Option<(String, String, String)> {
-    // Look for ISO timestamp pattern
-    if let Some(t_pos) = line.find('T') {
-        if let Some(z_pos) = line[t_pos..].find('Z') {
-            let timestamp = line[..t_pos + z_pos + 1].to_string();
-            let rest = &line[t_pos + z_pos + 1..].trim();
+const LEVEL_WORDS: [&str; 8] = [
+    "ERROR", "ERR", "FATAL", "WARN", "WARNING", "INFO", "DEBUG", "TRACE",
+];
 
-            // Look for level
-            let parts: Vec<&str> = rest.splitn(2, ':').collect();
-            if parts.len() == 2 {
-                let level = parts[0].trim().to_string();
-                let message = parts[1].trim().to_string();
-                return Some((timestamp, level, message));
-            }
-        }
+// Line starting with a timestamp, optionally followed by a level word
+fn extract_timestamped_log(line: &str) -> Option<(String, LogLevel, String)> {
+    let (_, rest) = leading_timestamp(line)?;
+    let timestamp = line[..line.len() - rest.len()]
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_string();
+
+    let first_word = rest.split_whitespace().next().unwrap_or("");
+    let level_word = first_word
+        .trim_start_matches('[')
+        .trim_end_matches(':')
+        .trim_end_matches(']');
+    if LEVEL_WORDS.contains(&level_word.to_uppercase().as_str()) {
+        let message = rest[first_word.len()..]
+            .trim_start()
+            .trim_start_matches(':')
+            .trim()
+            .to_string();
+        return Some((timestamp, LogLevel::from_str(level_word), message));
     }
-    None
+
+    let level = extract_log_level_from_line(rest).unwrap_or(LogLevel::Info);
+    Some((timestamp, level, rest.to_string()))
+}
+
+/// Full-text search of the logs viewer. The query is a plain substring unless `regex` is
+/// set; matching is case-insensitive unless `case_sensitive` is set.
+#[derive(Debug, Clone, Default)]
+pub struct LogSearch {
+    pub query: String,
+    pub case_sensitive: bool,
+    pub regex: bool,
+    pub error: Option<String>, // Invalid regular expression
+    pattern: Option<Regex>,
 }
 
-fn regex_extract_bracket_log(line: &str) -> Option<(String, String, String)> {
-    if line.starts_with('[') {
-        if let Some(end_bracket) = line.find(']') {
-            let timestamp = line[1..end_bracket].to_string();
-            let rest = &line[end_bracket + 1..].trim();
+impl LogSearch {
+    pub fn set_query(&mut self, query: String) {
+        self.query = query;
+        self.compile();
+    }
+
+    pub fn toggle_case_sensitive(&mut self) {
+        self.case_sensitive = !self.case_sensitive;
+        self.compile();
+    }
 
-            let parts: Vec<&str> = rest.splitn(2, ':').collect();
-            if parts.len() == 2 {
-                let level = parts[0].trim().to_string();
-                let message = parts[1].trim().to_string();
-                return Some((timestamp, level, message));
+    pub fn toggle_regex(&mut self) {
+        self.regex = !self.regex;
+        self.compile();
+    }
+
+    /// A search is active when the query is non-empty and valid.
+    pub fn is_active(&self) -> bool {
+        self.pattern.is_some()
+    }
+
+    pub fn matches(&self, entry: &LogEntry) -> bool {
+        match self.pattern {
+            Some(ref pattern) => {
+                pattern.is_match(&entry.message)
+                    || pattern.is_match(&entry.source)
+                    || entry.continuation.iter().any(|line| pattern.is_match(line))
+                    || entry
+                        .fields
+                        .iter()
+                        .any(|(key, value)| pattern.is_match(key) || pattern.is_match(value))
             }
+            None => false,
+        }
+    }
+
+    /// Byte ranges of the non-empty matches in `text`.
+    pub fn match_ranges(&self, text: &str) -> Vec<(usize, usize)> {
+        match self.pattern {
+            Some(ref pattern) => pattern
+                .find_iter(text)
+                .filter(|m| !m.is_empty())
+                .map(|m| (m.start(), m.end()))
+                .collect(),
+            None => Vec::new(),
         }
     }
-    None
-}
 
-fn regex_extract_k8s_log(line: &str) -> Option<(String, String, String)> {
-    let parts: Vec<&str> = line.splitn(4, ' ').collect();
-    if parts.len() >= 4 {
-        let timestamp = parts[0].to_string();
-        let stream = parts[1]; // stdout/stderr
-        let _partial = parts[2]; // F/P
-        let message = parts[3..].join(" ");
+    fn compile(&mut self) {
+        self.error = None;
+        self.pattern = None;
+        if self.query.is_empty() {
+            return;
+        }
 
-        let level = if stream == "stderr" { "ERROR" } else { "INFO" };
-        return Some((timestamp, level.to_string(), message));
+        let source = if self.regex {
+            self.query.clone()
+        } else {
+            regex::escape(&self.query)
+        };
+        match RegexBuilder::new(&source)
+            .case_insensitive(!self.case_sensitive)
+            .build()
+        {
+            Ok(pattern) => self.pattern = Some(pattern),
+            Err(e) => self.error = Some(e.to_string()),
+        }
     }
-    None
 }
 
-pub fn filter_logs_by_level<'a>(
-    logs: &'a ComponentLogs,
-    min_level: &LogLevel,
-) -> Vec<&'a LogEntry> {
-    let min_priority = level_priority(min_level);
-
-    logs.entries
+/// Positions in `visible` (indices into `logs.entries`) of the entries matching the search.
+pub fn search_logs(logs: &ComponentLogs, visible: &[usize], search: &LogSearch) -> Vec<usize> {
+    if !search.is_active() {
+        return Vec::new();
+    }
+    visible
         .iter()
-        .filter(|entry| level_priority(&entry.level) >= min_priority)
+        .enumerate()
+        .filter(|(_, &index)| search.matches(&logs.entries[index]))
+        .map(|(position, _)| position)
         .collect()
 }
 
-pub fn search_logs<'a>(logs: &'a ComponentLogs, query: &str) -> Vec<&'a LogEntry> {
-    let query_lower = query.to_lowercase();
+/// Log entries whose messages only differ in numbers, IDs and addresses.
+#[derive(Debug, Clone)]
+pub struct LogPattern {
+    pub template: String,
+    pub level: LogLevel,
+    pub count: usize,
+    pub first_seen: Option<Timestamp>,
+    pub last_seen: Option<Timestamp>,
+    pub line_numbers: HashSet<usize>, // `LogEntry::line_number` of the matching entries
+}
+
+/// Variable parts of a message and what they are replaced with in its template. UUIDs
+/// and addresses go first so their digits are not masked as plain numbers.
+fn template_masks() -> &'static [(Regex, &'static str)] {
+    static MASKS: OnceLock<Vec<(Regex, &'static str)>> = OnceLock::new();
+    MASKS.get_or_init(|| {
+        [
+            (
+                r"\b[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}\b",
+                "<uuid>",
+            ),
+            (r"\b\d{1,3}(\.\d{1,3}){3}(:\d+)?\b", "<ip>"),
+            (r"\b([0-9a-fA-F]{1,4}:){3,7}[0-9a-fA-F]{1,4}\b", "<ip>"),
+            (r"\b0x[0-9a-fA-F]+\b", "<hex>"),
+            (r"\b[0-9a-fA-F]{8,}\b", "<hex>"),
+            (r"\b-?\d+(\.\d+)?", "<num>"),
+        ]
+        .into_iter()
+        .map(|(pattern, mask)| (Regex::new(pattern).expect("valid mask pattern"), mask))
+        .collect()
+    })
+}
 
-    logs.entries
+/// The message with numbers, UUIDs, IPs and hex IDs masked, e.g.
+/// `dial tcp <ip>: i/o timeout after <num>ms`.
+pub fn message_template(message: &str) -> String {
+    let first_line = message.lines().next().unwrap_or("");
+    template_masks()
         .iter()
-        .filter(|entry| {
-            entry.message.to_lowercase().contains(&query_lower)
-                || entry.source.to_lowercase().contains(&query_lower)
+        .fold(first_line.to_string(), |text, (pattern, mask)| {
+            pattern
+                .replace_all(&text, |captures: &Captures| {
+                    let matched = &captures[0];
+                    // Long hex strings are IDs only with both digits and letters;
+                    // plain numbers are masked as such, words are kept
+                    let is_hex_id = matched.bytes().any(|b| b.is_ascii_digit())
+                        && matched.bytes().any(|b| b.is_ascii_alphabetic());
+                    if *mask == "<hex>" && !is_hex_id {
+                        matched.to_string()
+                    } else {
+                        mask.to_string()
+                    }
+                })
+                .into_owned()
         })
-        .collect()
+}
+
+/// Patterns of the given entries, most frequent first.
+pub fn log_patterns(logs: &ComponentLogs, indices: &[usize]) -> Vec<LogPattern> {
+    let mut patterns: Vec<LogPattern> = Vec::new();
+    let mut positions: HashMap<(String, u8), usize> = HashMap::new();
+
+    for &index in indices {
+        let entry = &logs.entries[index];
+        let template = message_template(&entry.message);
+        let key = (template, level_priority(&entry.level));
+
+        let position = *positions.entry(key.clone()).or_insert_with(|| {
+            patterns.push(LogPattern {
+                template: key.0.clone(),
+                level: entry.level.clone(),
+                count: 0,
+                first_seen: None,
+                last_seen: None,
+                line_numbers: HashSet::new(),
+            });
+            patterns.len() - 1
+        });
+
+        let pattern = &mut patterns[position];
+        pattern.count += 1;
+        pattern.line_numbers.insert(entry.line_number);
+        if let Some(time) = entry.time {
+            pattern.first_seen = Some(pattern.first_seen.map_or(time, |first| first.min(time)));
+            pattern.last_seen = Some(pattern.last_seen.map_or(time, |last| last.max(time)));
+        }
+    }
+
+    // Most frequent first, the most severe first among equally frequent ones
+    patterns.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then(level_priority(&b.level).cmp(&level_priority(&a.level)))
+            .then(b.last_seen.cmp(&a.last_seen))
+    });
+    patterns
 }
 
 fn level_priority(level: &LogLevel) -> u8 {
@@ -481,7 +1170,52 @@ fn level_priority(level: &LogLevel) -> u8 {
 }
 
 impl ComponentLogs {
+    /// Switch between newest-first and oldest-first display order.
+    pub fn toggle_order(&mut self) {
+        self.newest_first = !self.newest_first;
+        if self.store.is_none() {
+            self.entries.reverse();
+        } else if self.load_window(0).is_err() {
+            // Keep showing the current window
+            self.newest_first = !self.newest_first;
+        }
+    }
+
+    /// Indices of the entries shown for a level filter ("error", "warning", "info",
+    /// "debug"); every entry when there is no filter. Entries of hidden pods are left out.
+    pub fn visible_indices(&self, level_filter: Option<&str>) -> Vec<usize> {
+        let level = filter_level(level_filter);
+        self.entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| match level {
+                Some(ref level) => entry.level == *level,
+                None => true,
+            })
+            .filter(|(_, entry)| entry_pod(entry).is_none_or(|pod| !self.hidden_pods.contains(pod)))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Hide the entries of the `number`th pod (from 1) of merged logs, or show them again.
+    pub fn toggle_pod(&mut self, number: usize) {
+        let Some(pod) = number.checked_sub(1).and_then(|i| self.pods.get(i)) else {
+            return;
+        };
+        if !self.hidden_pods.remove(&pod.name) {
+            self.hidden_pods.insert(pod.name.clone());
+        }
+    }
+
+    /// Position (from 0) of a pod of merged logs.
+    pub fn pod_index(&self, pod: &str) -> Option<usize> {
+        self.pods.iter().position(|merged| merged.name == pod)
+    }
+
     pub fn get_error_count(&self) -> usize {
+        if let Some(progress) = self.index_progress() {
+            return progress.error_count;
+        }
         self.entries
             .iter()
             .filter(|e| matches!(e.level, LogLevel::Error))
@@ -489,14 +1223,143 @@ impl ComponentLogs {
     }
 
     pub fn get_warning_count(&self) -> usize {
+        if let Some(progress) = self.index_progress() {
+            return progress.warning_count;
+        }
         self.entries
             .iter()
             .filter(|e| matches!(e.level, LogLevel::Warning))
             .count()
     }
+}
 
-    pub fn get_recent_logs(&self, count: usize) -> &[LogEntry] {
-        let end = std::cmp::min(count, self.entries.len());
-        &self.entries[..end]
+/// Level selected by a level filter name ("error", "warning", "info", "debug").
+pub fn filter_level(level_filter: Option<&str>) -> Option<LogLevel> {
+    match level_filter {
+        Some("error") => Some(LogLevel::Error),
+        Some("warning") => Some(LogLevel::Warning),
+        Some("info") => Some(LogLevel::Info),
+        Some("debug") => Some(LogLevel::Debug),
+        _ => None,
+    }
+}
+
+/// Windows over the entries of large log files. `entries` holds up to `WINDOW_ENTRIES`
+/// consecutive entries in display order, starting at display position `window_start`.
+impl ComponentLogs {
+    /// How far the indexing of a large file got; `None` for files parsed whole.
+    pub fn index_progress(&self) -> Option<IndexProgress> {
+        self.store.as_ref().map(|store| store.progress())
+    }
+
+    /// Load the window starting at display position `start`.
+    pub fn load_window(&mut self, start: usize) -> Result<()> {
+        let Some(store) = self.store.clone() else {
+            return Ok(());
+        };
+        self.entries = if self.newest_first {
+            let end = store.progress().entries.saturating_sub(start);
+            let first = end.saturating_sub(WINDOW_ENTRIES);
+            let mut entries = store.load_entries(first, end - first)?;
+            entries.reverse();
+            entries
+        } else {
+            store.load_entries(start, WINDOW_ENTRIES)?
+        };
+        self.window_start = start;
+        Ok(())
+    }
+
+    /// Start or follow the indexing of a large file: updates `total_entries` and fills
+    /// a window that was cut short by the end of the indexed part. Returns true while
+    /// indexing is still running.
+    pub fn poll_store(&mut self) -> bool {
+        let Some(store) = self.store.clone() else {
+            return false;
+        };
+        store.start_indexing();
+        let progress = store.progress();
+        self.total_entries = progress.entries.max(self.window_start + self.entries.len());
+
+        let window_end = self.window_start + self.entries.len();
+        if self.entries.len() < WINDOW_ENTRIES && progress.entries > window_end {
+            let _ = self.load_window(self.window_start);
+        }
+        !progress.done
+    }
+
+    /// Move the window forward or backward, to the next entries passing the level
+    /// filter when there is one. Returns false when there is nothing more to show.
+    pub fn shift_window(&mut self, forward: bool, level_filter: Option<&str>) -> Result<bool> {
+        let Some(store) = self.store.clone() else {
+            return Ok(false);
+        };
+        let start = self.window_start;
+        let end = start + self.entries.len();
+
+        let target = match filter_level(level_filter) {
+            None if forward => {
+                if self.entries.len() < WINDOW_ENTRIES {
+                    return Ok(false);
+                }
+                start + WINDOW_ENTRIES / 2
+            }
+            None => {
+                if start == 0 {
+                    return Ok(false);
+                }
+                start.saturating_sub(WINDOW_ENTRIES / 2)
+            }
+            Some(level) => {
+                // Jump straight to the next window with an entry of that level
+                let total = store.progress().entries;
+                let from = if forward {
+                    end
+                } else {
+                    match start.checked_sub(1) {
+                        Some(from) => from,
+                        None => return Ok(false),
+                    }
+                };
+                if from >= total {
+                    return Ok(false);
+                }
+                let to_file = |position: usize| {
+                    if self.newest_first {
+                        total - 1 - position
+                    } else {
+                        position
+                    }
+                };
+                let found = store.find_level(to_file(from), forward != self.newest_first, &level);
+                let Some(position) = found.map(to_file) else {
+                    return Ok(false);
+                };
+                let target = position.saturating_sub(WINDOW_ENTRIES / 2);
+                if forward {
+                    target.max(start + 1)
+                } else {
+                    target
+                }
+            }
+        };
+        self.load_window(target)?;
+        Ok(true)
+    }
+
+    /// Load the window around the entry holding `line_number`.
+    pub fn show_line(&mut self, line_number: usize) -> Result<()> {
+        let Some(store) = self.store.clone() else {
+            return Ok(());
+        };
+        let Some(position) = store.entry_at_line(line_number)? else {
+            return Ok(());
+        };
+        let position = if self.newest_first {
+            store.progress().entries.saturating_sub(position + 1)
+        } else {
+            position
+        };
+        self.load_window(position.saturating_sub(WINDOW_ENTRIES / 2))
     }
 }
@@ -1,36 +1,146 @@
 use crossterm::{
-    event::{self, Event, KeyCode},
+    event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
-use std::io;
-use std::path::Path;
+use std::io::{self, Write};
+use std::time::Duration;
 
 mod app;
+mod cli;
+mod config;
+mod diff;
+mod dump;
 mod error;
+mod events;
+mod global_search;
 mod kubernetes;
+mod layout;
+mod log_format;
+mod log_store;
 mod logs;
+mod manifest;
+mod metrics;
+mod networking;
+mod nodes;
+mod ownership;
+mod quantity;
+mod report;
+mod resources;
+mod rules;
+mod timestamp;
 mod ui;
+mod vfs;
+mod workloads;
 
-use app::{App, Screen};
+use app::{App, LogView, Screen};
+use cli::Command;
+use config::Config;
 use error::Result;
+use kubernetes::IssueSeverity;
+use rules::RuleRegistry;
 
 fn main() -> Result<()> {
-    // Check if output directory exists
-    if !Path::new("output").exists() {
-        eprintln!("❌ Error: 'output' directory not found!");
-        eprintln!("Please ensure you have the Kubernetes cluster dump in the 'output' directory.");
+    let args = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("❌ Error: {}", e);
+            eprintln!();
+            eprintln!("{}", cli::usage());
+            std::process::exit(1);
+        }
+    };
+
+    match args.command {
+        Command::Help => {
+            println!("{}", cli::usage());
+            return Ok(());
+        }
+        Command::Version => {
+            println!("k8s-analyzer {}", env!("CARGO_PKG_VERSION"));
+            return Ok(());
+        }
+        Command::Tui | Command::Analyze { .. } | Command::Diff { .. } | Command::Rules => {}
+    }
+
+    let config = match Config::load(args.config.as_deref()) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("❌ Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let rules = match RuleRegistry::from_config(&config.rules) {
+        Ok(rules) => rules,
+        Err(e) => {
+            eprintln!("❌ Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    if let Command::Rules = args.command {
+        print_rules(&rules);
+        return Ok(());
+    }
+
+    if let Command::Diff {
+        before,
+        after,
+        format,
+    } = args.command
+    {
+        let before = open_dump(before);
+        let after = open_dump(after);
+        return run_diff(&before, &after, &rules, format);
+    }
+
+    let dump = match config::resolve_dump_root(args.dump, &config) {
+        Ok(dump) => dump,
+        Err(e) => {
+            eprintln!("❌ Error: cannot open dump archive: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    // Check if the dump directory exists
+    if !dump.exists() {
+        let root = dump.path().display();
+        eprintln!("❌ Error: dump directory '{}' not found!", root);
+        eprintln!(
+            "Please point the analyzer at a Kubernetes cluster dump with --dump <DIR|ARCHIVE>,"
+        );
+        eprintln!(
+            "${} or the 'dump' key of the config file.",
+            config::DUMP_ENV
+        );
         eprintln!("Expected structure:");
-        eprintln!("output/");
+        eprintln!("{}/", root);
         eprintln!("├── namespace1/");
         eprintln!("│   ├── pods.json");
         eprintln!("│   ├── deployments.json");
+        eprintln!("│   ├── statefulsets.json, daemonsets.json, replicasets.json (optional)");
+        eprintln!("│   ├── jobs.json, cronjobs.json (optional)");
+        eprintln!("│   ├── services.json, endpoints.json, ingresses.json (optional)");
+        eprintln!("│   ├── events.json (optional)");
         eprintln!("│   └── ...");
         eprintln!("└── namespace2/");
+        eprintln!(
+            "Object files can be JSON or YAML. A 'kubectl cluster-info dump --output-directory',"
+        );
+        eprintln!(
+            "a must-gather bundle or a directory of 'kubectl get <kind> -A -o json > <kind>.json'"
+        );
+        eprintln!("files work too.");
         std::process::exit(1);
     }
 
+    if let Command::Analyze { format } = args.command {
+        return run_analyze(&dump, &rules, format);
+    }
+
+    let baseline = args.baseline.map(open_dump);
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -39,7 +149,7 @@ fn main() -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     // Create app
-    let mut app = App::new()?;
+    let mut app = App::new(dump, baseline, rules)?;
 
     // Main loop
     let result = run_app(&mut terminal, &mut app);
@@ -61,17 +171,132 @@ fn main() -> Result<()> {
     }
 }
 
+/// Write a report to stdout. A reader that stops early (`analyze | head`) is not an error.
+fn print_report(report: &str) -> Result<()> {
+    match writeln!(io::stdout().lock(), "{}", report) {
+        Err(e) if e.kind() != io::ErrorKind::BrokenPipe => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Headless mode: print the analysis report and exit non-zero on Critical issues.
+fn run_analyze(
+    dump: &dump::DumpRoot,
+    rules: &RuleRegistry,
+    format: report::ReportFormat,
+) -> Result<()> {
+    let analysis = kubernetes::analyze_cluster(dump, rules)?;
+    print_report(&report::render_analysis(
+        &analysis,
+        &dump.path().display().to_string(),
+        format,
+    ))?;
+
+    if analysis.issue_count(IssueSeverity::Critical) > 0 {
+        std::process::exit(cli::EXIT_CRITICAL_ISSUES);
+    }
+    Ok(())
+}
+
+/// Headless comparison of two dumps: print the diff and exit non-zero when the second
+/// dump has new Critical issues.
+fn run_diff(
+    before: &dump::DumpRoot,
+    after: &dump::DumpRoot,
+    rules: &RuleRegistry,
+    format: report::ReportFormat,
+) -> Result<()> {
+    let before_analysis = kubernetes::analyze_cluster(before, rules)?;
+    let after_analysis = kubernetes::analyze_cluster(after, rules)?;
+    let diff = diff::DumpDiff::new(&before_analysis, &after_analysis);
+    print_report(&report::render_diff(
+        &diff,
+        &before.path().display().to_string(),
+        &after.path().display().to_string(),
+        format,
+    ))?;
+
+    if diff.new_issue_count(IssueSeverity::Critical) > 0 {
+        std::process::exit(cli::EXIT_CRITICAL_ISSUES);
+    }
+    Ok(())
+}
+
+/// Open a dump given on the command line other than the main one, or exit.
+fn open_dump(path: std::path::PathBuf) -> dump::DumpRoot {
+    let dump = match dump::DumpRoot::open(&path) {
+        Ok(dump) => dump,
+        Err(e) => {
+            eprintln!("❌ Error: cannot open dump archive: {}", e);
+            std::process::exit(1);
+        }
+    };
+    if !dump.exists() {
+        eprintln!("❌ Error: dump directory '{}' not found!", path.display());
+        std::process::exit(1);
+    }
+    dump
+}
+
+fn print_rules(rules: &RuleRegistry) {
+    for (id, description, severity, enabled) in rules.summary() {
+        println!(
+            "{} {:<24} {:<9} {}{}",
+            severity.icon(),
+            id,
+            severity.label(),
+            description,
+            if enabled { "" } else { " (disabled)" }
+        );
+    }
+}
+
+// One arm per key, checking the current screen inside the arm
+#[allow(clippy::collapsible_match)]
 fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()> {
     loop {
+        app.poll_global_search();
+        let indexing = app.poll_log_store();
         terminal.draw(|f| ui::draw(f, app))?;
 
+        // Redraw periodically while a global search streams hits in or a large log file
+        // is being indexed
+        let busy = indexing || app.global_search_running();
+        if busy && !event::poll(Duration::from_millis(100))? {
+            continue;
+        }
+
         if let Event::Key(key) = event::read()? {
+            if app.log_search_editing {
+                handle_log_search_key(app, key);
+                continue;
+            }
+            if app.global_search_editing {
+                handle_global_search_key(app, key);
+                continue;
+            }
+
             match key.code {
                 KeyCode::Char('q') => break,
                 KeyCode::Esc => {
                     if app.current_screen == Screen::MainMenu {
                         break;
                     }
+                    if app.current_screen == Screen::LogsViewer {
+                        // Close the innermost view first
+                        if app.log_view == LogView::Patterns {
+                            app.toggle_log_view();
+                            continue;
+                        }
+                        if app.log_detail_open {
+                            app.toggle_log_detail();
+                            continue;
+                        }
+                        if app.log_pattern_filter.is_some() {
+                            app.close_pattern_filter();
+                            continue;
+                        }
+                    }
                     app.back();
                 }
                 KeyCode::Down => {
@@ -93,45 +318,49 @@ fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, app: &mut A
                     }
                 }
                 KeyCode::Enter => {
-                    if let Err(e) = app.select() {
-                        if e.to_string() == "exit" {
-                            break;
+                    if app.current_screen == Screen::LogsViewer {
+                        if app.log_view == LogView::Patterns {
+                            app.open_selected_pattern();
+                        } else {
+                            app.toggle_log_detail();
+                        }
+                    } else {
+                        if let Err(e) = app.select() {
+                            if e.to_string() == "exit" {
+                                break;
+                            }
                         }
                     }
                 }
+                KeyCode::Char('p') | KeyCode::Tab => {
+                    if app.current_screen == Screen::LogsViewer {
+                        app.toggle_log_view();
+                    }
+                }
+                KeyCode::Left => {
+                    if app.current_screen == Screen::LogsViewer {
+                        app.scroll_logs_horizontally(false);
+                    }
+                }
+                KeyCode::Right => {
+                    if app.current_screen == Screen::LogsViewer {
+                        app.scroll_logs_horizontally(true);
+                    }
+                }
+                KeyCode::PageDown => {
+                    if app.current_screen == Screen::LogsViewer {
+                        app.scroll_log_detail(true);
+                    }
+                }
+                KeyCode::PageUp => {
+                    if app.current_screen == Screen::LogsViewer {
+                        app.scroll_log_detail(false);
+                    }
+                }
                 KeyCode::Char('l') => {
-                    // Open logs from component details
                     if app.current_screen == Screen::ComponentDetails {
-                        if let Some((ref name, ref comp_type)) = app.selected_component.clone() {
-                            // Find the namespace for this component
-                            if let Some(ref analysis) = app.cluster_analysis {
-                                for ns_analysis in &analysis.namespaces {
-                                    let found = match comp_type.as_str() {
-                                        "Pod" => ns_analysis.pods.iter().any(|p| p.name == *name),
-                                        "Deployment" => {
-                                            ns_analysis.deployments.iter().any(|d| d.name == *name)
-                                        }
-                                        _ => false,
-                                    };
-
-                                    if found {
-                                        // Try to load logs for this component
-                                        match crate::logs::load_pod_logs(&ns_analysis.name, name) {
-                                            Ok(logs) => {
-                                                app.current_logs = Some(logs);
-                                                app.current_screen = Screen::LogsViewer;
-                                                app.selected_namespace =
-                                                    Some(ns_analysis.name.clone());
-                                            }
-                                            Err(_) => {
-                                                // Could show error message, for now just ignore
-                                            }
-                                        }
-                                        break;
-                                    }
-                                }
-                            }
-                        }
+                        // Stay on the details when the logs cannot be read
+                        let _ = app.open_component_logs();
                     }
                 }
                 // Log filtering shortcuts
@@ -143,34 +372,114 @@ fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, app: &mut A
                             Some("error") => app.toggle_log_filter("warning"),
                             Some("warning") => app.toggle_log_filter("info"),
                             Some("info") => app.toggle_log_filter("debug"),
-                            Some("debug") => app.log_filter = None,
-                            _ => app.log_filter = None,
+                            _ => app.clear_log_filter(),
                         }
                     }
                 }
                 KeyCode::Char('e') => {
                     if app.current_screen == Screen::LogsViewer {
                         app.toggle_log_filter("error");
+                    } else if app.current_screen == Screen::GlobalSearch {
+                        app.set_global_level_filter(Some("error"));
                     }
                 }
                 KeyCode::Char('w') => {
                     if app.current_screen == Screen::LogsViewer {
                         app.toggle_log_filter("warning");
+                    } else if app.current_screen == Screen::GlobalSearch {
+                        app.set_global_level_filter(Some("warning"));
                     }
                 }
                 KeyCode::Char('i') => {
                     if app.current_screen == Screen::LogsViewer {
                         app.toggle_log_filter("info");
+                    } else if app.current_screen == Screen::GlobalSearch {
+                        app.set_global_level_filter(Some("info"));
                     }
                 }
                 KeyCode::Char('d') => {
                     if app.current_screen == Screen::LogsViewer {
                         app.toggle_log_filter("debug");
+                    } else if app.current_screen == Screen::GlobalSearch {
+                        app.set_global_level_filter(Some("debug"));
+                    }
+                }
+                KeyCode::Char('c') => {
+                    if app.current_screen == Screen::LogsViewer {
+                        // Keep the current logs if the other container cannot be read
+                        let _ = app.cycle_log_container();
+                    }
+                }
+                // Pods of merged workload logs: 1-9 hide or show a pod, h hides the pod of
+                // the selected entry, 0 shows them all
+                KeyCode::Char('h') => {
+                    if app.current_screen == Screen::LogsViewer {
+                        app.hide_selected_log_pod();
+                    }
+                }
+                KeyCode::Char('0') => {
+                    if app.current_screen == Screen::LogsViewer {
+                        app.show_all_log_pods();
+                    }
+                }
+                KeyCode::Char(digit @ '1'..='9') => {
+                    if app.current_screen == Screen::LogsViewer {
+                        app.toggle_log_pod(digit as usize - '0' as usize);
+                    }
+                }
+                KeyCode::Char('o') => {
+                    if app.current_screen == Screen::LogsViewer {
+                        app.toggle_log_order();
                     }
                 }
                 KeyCode::Char('a') => {
                     if app.current_screen == Screen::LogsViewer {
-                        app.log_filter = None; // Show all
+                        app.clear_log_filter(); // Show all
+                    } else if app.current_screen == Screen::GlobalSearch {
+                        app.set_global_level_filter(None);
+                    }
+                }
+                KeyCode::Char('x') => {
+                    if app.current_screen == Screen::LogsViewer {
+                        app.toggle_selected_log_entry();
+                    }
+                }
+                KeyCode::Char('X') => {
+                    if app.current_screen == Screen::LogsViewer {
+                        app.toggle_all_log_entries();
+                    }
+                }
+                // Full-text search
+                KeyCode::Char('/') => {
+                    if app.current_screen == Screen::LogsViewer {
+                        app.start_log_search();
+                    } else if app.current_screen == Screen::GlobalSearch {
+                        // Global search
+                        app.edit_global_search();
+                    }
+                }
+                KeyCode::Char('n') => {
+                    if app.current_screen == Screen::LogsViewer {
+                        app.next_log_search_hit(true);
+                    }
+                }
+                KeyCode::Char('N') => {
+                    if app.current_screen == Screen::LogsViewer {
+                        app.next_log_search_hit(false);
+                    }
+                }
+                KeyCode::Char('C') => {
+                    if app.current_screen == Screen::LogsViewer {
+                        app.toggle_log_search_case();
+                    } else if app.current_screen == Screen::GlobalSearch {
+                        app.toggle_global_search_case();
+                    }
+                }
+                KeyCode::Char('R') => {
+                    if app.current_screen == Screen::LogsViewer {
+                        app.toggle_log_search_regex();
+                    } else if app.current_screen == Screen::GlobalSearch {
+                        app.toggle_global_search_regex();
                     }
                 }
                 _ => {}
@@ -180,3 +489,48 @@ fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, app: &mut A
 
     Ok(())
 }
+
+/// Keys of the logs viewer `/` prompt: text input, Enter to confirm, Esc to cancel,
+/// Alt+C / Alt+R to toggle case sensitivity and regex.
+fn handle_log_search_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Enter => app.confirm_log_search(),
+        KeyCode::Esc => app.cancel_log_search(),
+        KeyCode::Backspace => app.pop_log_search_char(),
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::ALT) => {
+            app.toggle_log_search_case()
+        }
+        KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::ALT) => {
+            app.toggle_log_search_regex()
+        }
+        KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.push_log_search_char(c)
+        }
+        _ => {}
+    }
+}
+
+/// Keys of the global search query prompt: text input, Enter to search, Esc to stop
+/// editing (or leave the screen when nothing was searched yet).
+fn handle_global_search_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Enter => app.start_global_search(),
+        KeyCode::Esc => {
+            app.global_search_editing = false;
+            if app.global_search.is_none() {
+                app.back();
+            }
+        }
+        KeyCode::Backspace => app.pop_global_query_char(),
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::ALT) => {
+            app.toggle_global_search_case()
+        }
+        KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::ALT) => {
+            app.toggle_global_search_regex()
+        }
+        KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.push_global_query_char(c)
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,67 @@
+use serde::Deserialize;
+use serde_json::Value;
+use std::path::Path;
+
+use crate::error::Result;
+
+/// Extensions of the files holding Kubernetes objects.
+const MANIFEST_EXTENSIONS: [&str; 3] = ["json", "yaml", "yml"];
+
+/// True for `.json`, `.yaml` and `.yml` files.
+pub fn is_manifest_file_name(name: &str) -> bool {
+    Path::new(name)
+        .extension()
+        .is_some_and(|extension| MANIFEST_EXTENSIONS.iter().any(|known| extension == *known))
+}
+
+/// `<stem>.json`, `<stem>.yaml` and `<stem>.yml`, in that order.
+pub fn manifest_file_names(stem: &str) -> impl Iterator<Item = String> + '_ {
+    MANIFEST_EXTENSIONS
+        .iter()
+        .map(move |extension| format!("{}.{}", stem, extension))
+}
+
+/// The objects in a JSON or YAML manifest: the items of `List` documents and single
+/// objects, from every document of a multi-document YAML file. Items of typed Lists
+/// (`PodList`, ...) get the `kind` that `kubectl get` leaves out.
+pub fn parse_manifest(path: &Path, content: &str) -> Result<Vec<Value>> {
+    let mut objects = Vec::new();
+    let is_json = path
+        .extension()
+        .is_some_and(|extension| extension == "json");
+    if is_json {
+        collect_objects(serde_json::from_str(content)?, &mut objects);
+    } else {
+        for document in serde_yaml::Deserializer::from_str(content) {
+            collect_objects(Value::deserialize(document)?, &mut objects);
+        }
+    }
+    Ok(objects)
+}
+
+fn collect_objects(document: Value, objects: &mut Vec<Value>) {
+    let Value::Object(mut document) = document else {
+        // Empty YAML documents (`---` at the end of the file) and stray scalars
+        return;
+    };
+    if !document.get("items").is_some_and(Value::is_array) {
+        objects.push(Value::Object(document));
+        return;
+    }
+    let Some(Value::Array(items)) = document.remove("items") else {
+        return;
+    };
+
+    let item_kind = document
+        .get("kind")
+        .and_then(Value::as_str)
+        .and_then(|kind| kind.strip_suffix("List"))
+        .filter(|kind| !kind.is_empty());
+    for mut item in items {
+        if let (Some(kind), Some(item)) = (item_kind, item.as_object_mut()) {
+            item.entry("kind")
+                .or_insert_with(|| Value::String(kind.to_string()));
+        }
+        objects.push(item);
+    }
+}
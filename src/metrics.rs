@@ -0,0 +1,319 @@
+use serde_json::Value;
+
+use crate::dump::DumpRoot;
+use crate::kubernetes::{load_cluster_objects, load_objects, PodInfo};
+use crate::quantity::Quantity;
+
+/// `kubectl get --raw /apis/metrics.k8s.io/v1beta1/pods` (a `PodMetricsList`), at the
+/// root of the dump for every namespace or in a namespace directory.
+pub const POD_METRICS_FILE: &str = "pod-metrics.json";
+/// `kubectl get --raw /apis/metrics.k8s.io/v1beta1/nodes` (a `NodeMetricsList`).
+pub const NODE_METRICS_FILE: &str = "node-metrics.json";
+/// `kubectl top pods` output, with or without `-A` and `--containers`.
+pub const TOP_PODS_FILE: &str = "top-pods.txt";
+/// `kubectl top nodes` output.
+pub const TOP_NODES_FILE: &str = "top-nodes.txt";
+
+/// CPU and memory in use when the metrics were taken.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ResourceUsage {
+    pub cpu: Quantity,
+    pub memory: Quantity,
+}
+
+impl ResourceUsage {
+    /// "cpu 120m, memory 300Mi"
+    pub fn label(&self) -> String {
+        format!(
+            "cpu {}, memory {}",
+            self.cpu.cpu_label(),
+            self.memory.memory_label()
+        )
+    }
+}
+
+impl std::ops::Add for ResourceUsage {
+    type Output = ResourceUsage;
+
+    fn add(self, other: ResourceUsage) -> ResourceUsage {
+        ResourceUsage {
+            cpu: self.cpu + other.cpu,
+            memory: self.memory + other.memory,
+        }
+    }
+}
+
+/// `used` with its share of a request and a limit: `120m (24% of request, 6% of limit)`.
+/// Zero requests and limits are left out.
+pub fn usage_against(
+    used: Quantity,
+    request: Quantity,
+    limit: Quantity,
+    label: fn(&Quantity) -> String,
+) -> String {
+    let shares: Vec<String> = [(request, "request"), (limit, "limit")]
+        .into_iter()
+        .filter_map(|(total, name)| {
+            used.percent_of(total)
+                .map(|percent| format!("{:.0}% of {}", percent, name))
+        })
+        .collect();
+    if shares.is_empty() {
+        label(&used)
+    } else {
+        format!("{} ({})", label(&used), shares.join(", "))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PodMetrics {
+    pub namespace: Option<String>, // None for `kubectl top pods` without a NAMESPACE column
+    pub name: String,
+    pub usage: ResourceUsage,
+    pub containers: Vec<(String, ResourceUsage)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NodeMetrics {
+    pub name: String,
+    pub usage: ResourceUsage,
+}
+
+/// Pod and node usage found in the dump, from metrics-server JSON or `kubectl top`.
+#[derive(Debug, Clone, Default)]
+pub struct MetricsSnapshot {
+    pub pods: Vec<PodMetrics>,
+    pub nodes: Vec<NodeMetrics>,
+}
+
+impl MetricsSnapshot {
+    /// Read every metrics file of the dump. When a pod or node appears in several
+    /// files, the metrics-server JSON wins over `kubectl top`.
+    pub fn load(dump: &DumpRoot) -> Self {
+        let mut snapshot = MetricsSnapshot::default();
+
+        let root_pods = load_cluster_objects(dump, POD_METRICS_FILE).unwrap_or_default();
+        snapshot.pods.extend(parse_pod_metrics(&root_pods, None));
+        let namespaces = dump.namespace_names().unwrap_or_default();
+        for namespace in &namespaces {
+            let items = load_objects(dump, namespace, POD_METRICS_FILE).unwrap_or_default();
+            snapshot
+                .pods
+                .extend(parse_pod_metrics(&items, Some(namespace)));
+        }
+        if let Ok(text) = dump.read_to_string(&dump.path().join(TOP_PODS_FILE)) {
+            snapshot.pods.extend(parse_top_pods(&text, None));
+        }
+        for namespace in &namespaces {
+            let path = dump.namespace_dir(namespace).join(TOP_PODS_FILE);
+            if let Ok(text) = dump.read_to_string(&path) {
+                snapshot.pods.extend(parse_top_pods(&text, Some(namespace)));
+            }
+        }
+
+        let node_items = load_cluster_objects(dump, NODE_METRICS_FILE).unwrap_or_default();
+        snapshot.nodes.extend(parse_node_metrics(&node_items));
+        if let Ok(text) = dump.read_to_string(&dump.path().join(TOP_NODES_FILE)) {
+            snapshot.nodes.extend(parse_top_nodes(&text));
+        }
+
+        snapshot
+    }
+
+    /// Metrics of a pod. Rows of `kubectl top` without a namespace match any namespace.
+    pub fn pod(&self, namespace: &str, name: &str) -> Option<&PodMetrics> {
+        self.pods.iter().find(|pod| {
+            pod.name == name && pod.namespace.as_deref().is_none_or(|ns| ns == namespace)
+        })
+    }
+
+    pub fn node(&self, name: &str) -> Option<&NodeMetrics> {
+        self.nodes.iter().find(|node| node.name == name)
+    }
+}
+
+/// Set the usage of the pods of a namespace, and of their containers when the
+/// metrics have per-container rows.
+pub fn attach_usage(pods: &mut [PodInfo], namespace: &str, metrics: &MetricsSnapshot) {
+    for pod in pods.iter_mut() {
+        let Some(pod_metrics) = metrics.pod(namespace, &pod.name) else {
+            continue;
+        };
+        pod.usage = Some(pod_metrics.usage);
+        for container in pod.containers.iter_mut() {
+            container.usage = pod_metrics
+                .containers
+                .iter()
+                .find(|(name, _)| *name == container.name)
+                .map(|(_, usage)| *usage);
+        }
+    }
+}
+
+/// `PodMetrics` objects. `namespace` is used for items without `metadata.namespace`.
+pub fn parse_pod_metrics(items: &[Value], namespace: Option<&str>) -> Vec<PodMetrics> {
+    items
+        .iter()
+        .filter_map(|item| {
+            let containers: Vec<(String, ResourceUsage)> = item["containers"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|container| {
+                    Some((
+                        container["name"].as_str()?.to_string(),
+                        parse_usage(&container["usage"])?,
+                    ))
+                })
+                .collect();
+            let usage = containers
+                .iter()
+                .fold(ResourceUsage::default(), |total, (_, usage)| total + *usage);
+
+            Some(PodMetrics {
+                namespace: item["metadata"]["namespace"]
+                    .as_str()
+                    .or(namespace)
+                    .map(|s| s.to_string()),
+                name: item["metadata"]["name"].as_str()?.to_string(),
+                usage,
+                containers,
+            })
+        })
+        .collect()
+}
+
+pub fn parse_node_metrics(items: &[Value]) -> Vec<NodeMetrics> {
+    items
+        .iter()
+        .filter_map(|item| {
+            Some(NodeMetrics {
+                name: item["metadata"]["name"].as_str()?.to_string(),
+                usage: parse_usage(&item["usage"])?,
+            })
+        })
+        .collect()
+}
+
+fn parse_usage(usage: &Value) -> Option<ResourceUsage> {
+    Some(ResourceUsage {
+        cpu: Quantity::from_value(&usage["cpu"])?,
+        memory: Quantity::from_value(&usage["memory"])?,
+    })
+}
+
+/// `kubectl top pods` output:
+///
+/// ```text
+/// NAMESPACE     NAME          CPU(cores)   MEMORY(bytes)
+/// kube-system   coredns-5d7   3m           12Mi
+/// ```
+///
+/// The NAMESPACE column is there with `-A`; with `--containers`, POD holds the pod and
+/// NAME the container. `namespace` is used when there is no NAMESPACE column.
+pub fn parse_top_pods(text: &str, namespace: Option<&str>) -> Vec<PodMetrics> {
+    let mut pods: Vec<PodMetrics> = Vec::new();
+    let Some(table) = TopTable::parse(text) else {
+        return pods;
+    };
+    let per_container = table.column("POD").is_some();
+
+    for row in &table.rows {
+        let Some(usage) = table.usage(row) else {
+            continue;
+        };
+        let row_namespace = table
+            .value(row, "NAMESPACE")
+            .or(namespace)
+            .map(|s| s.to_string());
+        let (pod_name, container) = if per_container {
+            (table.value(row, "POD"), table.value(row, "NAME"))
+        } else {
+            (table.value(row, "NAME"), None)
+        };
+        let Some(pod_name) = pod_name else {
+            continue;
+        };
+
+        let existing = pods
+            .iter_mut()
+            .find(|pod| pod.name == pod_name && pod.namespace == row_namespace);
+        let pod = match existing {
+            Some(pod) => pod,
+            None => {
+                pods.push(PodMetrics {
+                    namespace: row_namespace,
+                    name: pod_name.to_string(),
+                    usage: ResourceUsage::default(),
+                    containers: Vec::new(),
+                });
+                pods.last_mut().expect("pod was just pushed")
+            }
+        };
+        pod.usage = pod.usage + usage;
+        if let Some(container) = container {
+            pod.containers.push((container.to_string(), usage));
+        }
+    }
+    pods
+}
+
+/// `kubectl top nodes` output:
+///
+/// ```text
+/// NAME       CPU(cores)   CPU%   MEMORY(bytes)   MEMORY%
+/// worker-1   250m         6%     1024Mi          7%
+/// ```
+pub fn parse_top_nodes(text: &str) -> Vec<NodeMetrics> {
+    let Some(table) = TopTable::parse(text) else {
+        return Vec::new();
+    };
+    table
+        .rows
+        .iter()
+        .filter_map(|row| {
+            Some(NodeMetrics {
+                name: table.value(row, "NAME")?.to_string(),
+                usage: table.usage(row)?,
+            })
+        })
+        .collect()
+}
+
+/// Whitespace-separated columns under a header line, as printed by `kubectl top`.
+struct TopTable<'a> {
+    header: Vec<&'a str>,
+    rows: Vec<Vec<&'a str>>,
+}
+
+impl<'a> TopTable<'a> {
+    fn parse(text: &'a str) -> Option<Self> {
+        let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+        let header: Vec<&str> = lines.next()?.split_whitespace().collect();
+        if !header.contains(&"CPU(cores)") {
+            return None;
+        }
+        // Rows of another width are `--sum` totals or wrapped lines
+        let rows = lines
+            .map(|line| line.split_whitespace().collect::<Vec<_>>())
+            .filter(|row| row.len() == header.len())
+            .collect();
+        Some(TopTable { header, rows })
+    }
+
+    fn column(&self, name: &str) -> Option<usize> {
+        self.header.iter().position(|column| *column == name)
+    }
+
+    fn value(&self, row: &[&'a str], column: &str) -> Option<&'a str> {
+        row.get(self.column(column)?).copied()
+    }
+
+    /// None for `<unknown>` rows of nodes without metrics.
+    fn usage(&self, row: &[&'a str]) -> Option<ResourceUsage> {
+        Some(ResourceUsage {
+            cpu: Quantity::parse(self.value(row, "CPU(cores)")?)?,
+            memory: Quantity::parse(self.value(row, "MEMORY(bytes)")?)?,
+        })
+    }
+}
@@ -0,0 +1,327 @@
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::kubernetes::{NamespaceObjects, PodInfo};
+
+pub const SERVICES_FILE: &str = "services.json";
+pub const ENDPOINTS_FILE: &str = "endpoints.json";
+pub const INGRESSES_FILE: &str = "ingresses.json";
+
+#[derive(Debug, Clone)]
+pub struct ServicePort {
+    pub name: Option<String>,
+    pub port: u32,
+    pub target_port: Option<String>,
+    pub protocol: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ServiceInfo {
+    pub name: String,
+    pub service_type: String,
+    pub cluster_ip: Option<String>,
+    pub selector: BTreeMap<String, String>,
+    pub ports: Vec<ServicePort>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EndpointsInfo {
+    pub name: String,
+    pub ready_addresses: Vec<String>,
+    pub not_ready_addresses: Vec<String>,
+}
+
+/// Port an Ingress backend refers to: by number, by service port name, or unspecified.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BackendPort {
+    Number(u32),
+    Name(String),
+    Unspecified,
+}
+
+#[derive(Debug, Clone)]
+pub struct IngressBackend {
+    pub host: Option<String>,
+    pub path: Option<String>,
+    pub service: String,
+    pub port: BackendPort,
+}
+
+#[derive(Debug, Clone)]
+pub struct IngressInfo {
+    pub name: String,
+    pub class: Option<String>,
+    pub backends: Vec<IngressBackend>,
+}
+
+/// Services, Endpoints and Ingresses of a namespace.
+#[derive(Debug, Clone, Default)]
+pub struct NamespaceNetworking {
+    pub services: Vec<ServiceInfo>,
+    pub endpoints: Vec<EndpointsInfo>,
+    pub ingresses: Vec<IngressInfo>,
+}
+
+impl NamespaceNetworking {
+    pub fn from_objects(objects: &NamespaceObjects) -> Self {
+        NamespaceNetworking {
+            services: objects.services.iter().filter_map(parse_service).collect(),
+            endpoints: objects
+                .endpoints
+                .iter()
+                .filter_map(parse_endpoints)
+                .collect(),
+            ingresses: objects.ingresses.iter().filter_map(parse_ingress).collect(),
+        }
+    }
+
+    pub fn service(&self, name: &str) -> Option<&ServiceInfo> {
+        self.services.iter().find(|service| service.name == name)
+    }
+
+    /// Endpoints share the name of their Service.
+    pub fn endpoints_for(&self, service_name: &str) -> Option<&EndpointsInfo> {
+        self.endpoints
+            .iter()
+            .find(|endpoints| endpoints.name == service_name)
+    }
+
+    /// (ingress name, backend) for every Ingress rule routing to the service.
+    pub fn ingress_routes_to(&self, service_name: &str) -> Vec<(&str, &IngressBackend)> {
+        self.ingresses
+            .iter()
+            .flat_map(|ingress| {
+                ingress
+                    .backends
+                    .iter()
+                    .filter(|backend| backend.service == service_name)
+                    .map(move |backend| (ingress.name.as_str(), backend))
+            })
+            .collect()
+    }
+}
+
+impl ServiceInfo {
+    /// Services without a selector have manually managed Endpoints and select no pods.
+    pub fn has_selector(&self) -> bool {
+        !self.selector.is_empty()
+    }
+
+    pub fn matching_pods<'a>(&self, pods: &'a [PodInfo]) -> Vec<&'a PodInfo> {
+        pods.iter()
+            .filter(|pod| selector_matches(&self.selector, &pod.labels))
+            .collect()
+    }
+
+    pub fn exposes(&self, port: &BackendPort) -> bool {
+        match port {
+            BackendPort::Number(number) => self.ports.iter().any(|p| p.port == *number),
+            BackendPort::Name(name) => self
+                .ports
+                .iter()
+                .any(|p| p.name.as_deref() == Some(name.as_str())),
+            BackendPort::Unspecified => true,
+        }
+    }
+
+    pub fn selector_label(&self) -> String {
+        if self.selector.is_empty() {
+            return "<none>".to_string();
+        }
+        self.selector
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    pub fn ports_label(&self) -> String {
+        if self.ports.is_empty() {
+            return "<none>".to_string();
+        }
+        self.ports
+            .iter()
+            .map(|port| match port.target_port {
+                Some(ref target) if *target != port.port.to_string() => {
+                    format!("{}→{}/{}", port.port, target, port.protocol)
+                }
+                _ => format!("{}/{}", port.port, port.protocol),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl IngressBackend {
+    pub fn route_label(&self) -> String {
+        format!(
+            "{}{}",
+            self.host.as_deref().unwrap_or("*"),
+            self.path.as_deref().unwrap_or("/")
+        )
+    }
+}
+
+impl BackendPort {
+    pub fn label(&self) -> String {
+        match self {
+            BackendPort::Number(number) => number.to_string(),
+            BackendPort::Name(name) => name.clone(),
+            BackendPort::Unspecified => "-".to_string(),
+        }
+    }
+}
+
+/// A selector matches when every key/value pair is present in the labels. An empty
+/// selector matches nothing, like a Service without a selector.
+pub fn selector_matches(
+    selector: &BTreeMap<String, String>,
+    labels: &BTreeMap<String, String>,
+) -> bool {
+    !selector.is_empty()
+        && selector
+            .iter()
+            .all(|(key, value)| labels.get(key) == Some(value))
+}
+
+/// String map such as `metadata.labels` or `spec.selector`.
+pub fn string_map(value: &Value) -> BTreeMap<String, String> {
+    value
+        .as_object()
+        .map(|map| {
+            map.iter()
+                .filter_map(|(key, value)| Some((key.clone(), value.as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub fn parse_service(service: &Value) -> Option<ServiceInfo> {
+    let ports = service["spec"]["ports"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|port| {
+            Some(ServicePort {
+                name: port["name"].as_str().map(|s| s.to_string()),
+                port: port["port"].as_u64()? as u32,
+                target_port: int_or_string(&port["targetPort"]),
+                protocol: port["protocol"].as_str().unwrap_or("TCP").to_string(),
+            })
+        })
+        .collect();
+
+    Some(ServiceInfo {
+        name: service["metadata"]["name"].as_str()?.to_string(),
+        service_type: service["spec"]["type"]
+            .as_str()
+            .unwrap_or("ClusterIP")
+            .to_string(),
+        cluster_ip: service["spec"]["clusterIP"].as_str().map(|s| s.to_string()),
+        selector: string_map(&service["spec"]["selector"]),
+        ports,
+    })
+}
+
+pub fn parse_endpoints(endpoints: &Value) -> Option<EndpointsInfo> {
+    let mut ready_addresses = Vec::new();
+    let mut not_ready_addresses = Vec::new();
+
+    for subset in endpoints["subsets"].as_array().into_iter().flatten() {
+        ready_addresses.extend(address_names(&subset["addresses"]));
+        not_ready_addresses.extend(address_names(&subset["notReadyAddresses"]));
+    }
+
+    Some(EndpointsInfo {
+        name: endpoints["metadata"]["name"].as_str()?.to_string(),
+        ready_addresses,
+        not_ready_addresses,
+    })
+}
+
+/// Parses both `networking.k8s.io/v1` and the older `extensions/v1beta1` backend format.
+pub fn parse_ingress(ingress: &Value) -> Option<IngressInfo> {
+    let spec = &ingress["spec"];
+    let mut backends = Vec::new();
+
+    for default_backend in [&spec["defaultBackend"], &spec["backend"]] {
+        if let Some(backend) = parse_backend(default_backend, None, None) {
+            backends.push(backend);
+        }
+    }
+
+    for rule in spec["rules"].as_array().into_iter().flatten() {
+        let host = rule["host"].as_str();
+        for path in rule["http"]["paths"].as_array().into_iter().flatten() {
+            if let Some(backend) = parse_backend(&path["backend"], host, path["path"].as_str()) {
+                backends.push(backend);
+            }
+        }
+    }
+
+    Some(IngressInfo {
+        name: ingress["metadata"]["name"].as_str()?.to_string(),
+        class: spec["ingressClassName"]
+            .as_str()
+            .or_else(|| ingress["metadata"]["annotations"]["kubernetes.io/ingress.class"].as_str())
+            .map(|s| s.to_string()),
+        backends,
+    })
+}
+
+fn parse_backend(
+    backend: &Value,
+    host: Option<&str>,
+    path: Option<&str>,
+) -> Option<IngressBackend> {
+    let (service, port) = if let Some(service) = backend["service"]["name"].as_str() {
+        let port = &backend["service"]["port"];
+        let port = match (port["number"].as_u64(), port["name"].as_str()) {
+            (Some(number), _) => BackendPort::Number(number as u32),
+            (None, Some(name)) => BackendPort::Name(name.to_string()),
+            (None, None) => BackendPort::Unspecified,
+        };
+        (service, port)
+    } else {
+        let service = backend["serviceName"].as_str()?;
+        let port = match &backend["servicePort"] {
+            Value::Number(number) => BackendPort::Number(number.as_u64().unwrap_or(0) as u32),
+            Value::String(name) => match name.parse() {
+                Ok(number) => BackendPort::Number(number),
+                Err(_) => BackendPort::Name(name.clone()),
+            },
+            _ => BackendPort::Unspecified,
+        };
+        (service, port)
+    };
+
+    Some(IngressBackend {
+        host: host.map(|s| s.to_string()),
+        path: path.map(|s| s.to_string()),
+        service: service.to_string(),
+        port,
+    })
+}
+
+/// Target pod name of each endpoint address, or its IP when there is no target.
+fn address_names(addresses: &Value) -> Vec<String> {
+    addresses
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|address| {
+            address["targetRef"]["name"]
+                .as_str()
+                .or_else(|| address["ip"].as_str())
+                .map(|s| s.to_string())
+        })
+        .collect()
+}
+
+fn int_or_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Number(number) => Some(number.to_string()),
+        Value::String(name) => Some(name.clone()),
+        _ => None,
+    }
+}
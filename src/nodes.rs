@@ -0,0 +1,182 @@
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::networking::string_map;
+
+/// Conditions that report a problem when their status is `True`.
+const PRESSURE_CONDITIONS: [&str; 4] = [
+    "MemoryPressure",
+    "DiskPressure",
+    "PIDPressure",
+    "NetworkUnavailable",
+];
+
+/// Label prefix of the node roles (`node-role.kubernetes.io/control-plane`).
+const ROLE_LABEL_PREFIX: &str = "node-role.kubernetes.io/";
+
+#[derive(Debug, Clone)]
+pub struct NodeCondition {
+    pub condition_type: String,
+    pub status: String,
+    pub reason: Option<String>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NodeTaint {
+    pub key: String,
+    pub value: Option<String>,
+    pub effect: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct NodeInfo {
+    pub name: String,
+    pub ready: bool,
+    pub unschedulable: bool, // Cordoned with `kubectl cordon`
+    pub roles: Vec<String>,
+    pub kubelet_version: Option<String>,
+    pub os_image: Option<String>,
+    pub container_runtime: Option<String>,
+    pub internal_ip: Option<String>,
+    pub capacity: BTreeMap<String, String>,
+    pub allocatable: BTreeMap<String, String>,
+    pub conditions: Vec<NodeCondition>,
+    pub taints: Vec<NodeTaint>,
+    pub labels: BTreeMap<String, String>,
+}
+
+impl NodeInfo {
+    /// "Ready", "NotReady", "Ready,SchedulingDisabled", like `kubectl get nodes`.
+    pub fn status_label(&self) -> String {
+        let status = if self.ready { "Ready" } else { "NotReady" };
+        if self.unschedulable {
+            format!("{},SchedulingDisabled", status)
+        } else {
+            status.to_string()
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        if !self.ready {
+            "🔴"
+        } else if self.unschedulable || !self.pressure_conditions().is_empty() {
+            "🟡"
+        } else {
+            "🟢"
+        }
+    }
+
+    /// MemoryPressure, DiskPressure, PIDPressure and NetworkUnavailable conditions that
+    /// are currently `True`.
+    pub fn pressure_conditions(&self) -> Vec<&NodeCondition> {
+        self.conditions
+            .iter()
+            .filter(|condition| {
+                condition.status == "True"
+                    && PRESSURE_CONDITIONS.contains(&condition.condition_type.as_str())
+            })
+            .collect()
+    }
+
+    pub fn ready_condition(&self) -> Option<&NodeCondition> {
+        self.conditions
+            .iter()
+            .find(|condition| condition.condition_type == "Ready")
+    }
+
+    pub fn roles_label(&self) -> String {
+        if self.roles.is_empty() {
+            "<none>".to_string()
+        } else {
+            self.roles.join(",")
+        }
+    }
+}
+
+impl NodeTaint {
+    /// `key=value:effect`, like `kubectl describe node`.
+    pub fn label(&self) -> String {
+        match self.value {
+            Some(ref value) => format!("{}={}:{}", self.key, value, self.effect),
+            None => format!("{}:{}", self.key, self.effect),
+        }
+    }
+}
+
+/// Nodes sorted by name.
+pub fn parse_nodes(nodes: &[Value]) -> Vec<NodeInfo> {
+    let mut nodes: Vec<NodeInfo> = nodes.iter().filter_map(parse_node).collect();
+    nodes.sort_by(|a, b| a.name.cmp(&b.name));
+    nodes
+}
+
+pub fn parse_node(node: &Value) -> Option<NodeInfo> {
+    let conditions: Vec<NodeCondition> = node["status"]["conditions"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|condition| {
+            Some(NodeCondition {
+                condition_type: condition["type"].as_str()?.to_string(),
+                status: condition["status"]
+                    .as_str()
+                    .unwrap_or("Unknown")
+                    .to_string(),
+                reason: condition["reason"].as_str().map(|s| s.to_string()),
+                message: condition["message"].as_str().map(|s| s.to_string()),
+            })
+        })
+        .collect();
+    let ready = conditions
+        .iter()
+        .any(|condition| condition.condition_type == "Ready" && condition.status == "True");
+
+    let taints = node["spec"]["taints"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|taint| {
+            Some(NodeTaint {
+                key: taint["key"].as_str()?.to_string(),
+                value: taint["value"].as_str().map(|s| s.to_string()),
+                effect: taint["effect"].as_str().unwrap_or_default().to_string(),
+            })
+        })
+        .collect();
+
+    let labels = string_map(&node["metadata"]["labels"]);
+    let roles = labels
+        .keys()
+        .filter_map(|key| key.strip_prefix(ROLE_LABEL_PREFIX))
+        .filter(|role| !role.is_empty())
+        .map(|role| role.to_string())
+        .collect();
+
+    let internal_ip = node["status"]["addresses"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|address| address["type"] == "InternalIP")
+        .and_then(|address| address["address"].as_str())
+        .map(|s| s.to_string());
+    let node_info = &node["status"]["nodeInfo"];
+
+    Some(NodeInfo {
+        name: node["metadata"]["name"].as_str()?.to_string(),
+        ready,
+        unschedulable: node["spec"]["unschedulable"].as_bool().unwrap_or(false),
+        roles,
+        kubelet_version: node_info["kubeletVersion"].as_str().map(|s| s.to_string()),
+        os_image: node_info["osImage"].as_str().map(|s| s.to_string()),
+        container_runtime: node_info["containerRuntimeVersion"]
+            .as_str()
+            .map(|s| s.to_string()),
+        internal_ip,
+        capacity: string_map(&node["status"]["capacity"]),
+        allocatable: string_map(&node["status"]["allocatable"]),
+        conditions,
+        taints,
+        labels,
+    })
+}
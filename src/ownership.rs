@@ -0,0 +1,349 @@
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::kubernetes::NamespaceObjects;
+use crate::networking::string_map;
+
+/// Longest owner chain followed, so that a cycle in a broken dump cannot loop forever.
+/// Real chains are at most CronJob → Job → Pod or Deployment → ReplicaSet → Pod.
+const MAX_OWNER_DEPTH: usize = 8;
+
+/// Kind and name of an object of a namespace.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ObjectRef {
+    pub kind: String,
+    pub name: String,
+}
+
+impl ObjectRef {
+    pub fn new(kind: &str, name: &str) -> Self {
+        ObjectRef {
+            kind: kind.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    /// "Deployment web"
+    pub fn label(&self) -> String {
+        format!("{} {}", self.kind, self.name)
+    }
+
+    pub fn is(&self, kind: &str, name: &str) -> bool {
+        self.kind == kind && self.name == name
+    }
+}
+
+/// Which object controls which in a namespace: Deployment → ReplicaSet → Pod, CronJob →
+/// Job → Pod, StatefulSet → Pod, ... Edges come from `metadata.ownerReferences`; objects
+/// without any (dumps that strip them) are matched with the label selectors of the
+/// workloads instead.
+#[derive(Debug, Clone, Default)]
+pub struct OwnershipGraph {
+    owners: BTreeMap<ObjectRef, ObjectRef>, // Object → its controller
+}
+
+impl OwnershipGraph {
+    pub fn from_objects(objects: &NamespaceObjects) -> Self {
+        let mut graph = OwnershipGraph::default();
+
+        let owned = [
+            ("Pod", &objects.pods),
+            ("ReplicaSet", &objects.replicasets),
+            ("Job", &objects.jobs),
+            ("StatefulSet", &objects.statefulsets),
+            ("DaemonSet", &objects.daemonsets),
+            ("Deployment", &objects.deployments),
+        ];
+        for (kind, items) in owned {
+            for object in items {
+                let Some(name) = object["metadata"]["name"].as_str() else {
+                    continue;
+                };
+                if let Some(owner) = controller_of(object) {
+                    graph.owners.insert(ObjectRef::new(kind, name), owner);
+                }
+            }
+        }
+
+        // Selector fallback: pods go to the closest controller, ReplicaSets to their Deployment
+        let pod_controllers = [
+            ("ReplicaSet", &objects.replicasets),
+            ("StatefulSet", &objects.statefulsets),
+            ("DaemonSet", &objects.daemonsets),
+            ("Job", &objects.jobs),
+            ("Deployment", &objects.deployments),
+        ];
+        graph.match_selectors("Pod", &objects.pods, &pod_controllers);
+        graph.match_selectors(
+            "ReplicaSet",
+            &objects.replicasets,
+            &[("Deployment", &objects.deployments)],
+        );
+
+        graph
+    }
+
+    /// Give the `kind` objects that have no owner yet the first controller whose selector
+    /// matches their labels.
+    fn match_selectors(
+        &mut self,
+        kind: &str,
+        objects: &[Value],
+        controllers: &[(&str, &Vec<Value>)],
+    ) {
+        for object in objects {
+            let Some(name) = object["metadata"]["name"].as_str() else {
+                continue;
+            };
+            let object_ref = ObjectRef::new(kind, name);
+            if self.owners.contains_key(&object_ref) {
+                continue;
+            }
+            let labels = string_map(&object["metadata"]["labels"]);
+            let controller = controllers.iter().find_map(|(controller_kind, items)| {
+                items
+                    .iter()
+                    .find(|controller| selector_matches(&controller["spec"]["selector"], &labels))
+                    .and_then(|controller| controller["metadata"]["name"].as_str())
+                    .map(|controller_name| ObjectRef::new(controller_kind, controller_name))
+            });
+            if let Some(controller) = controller {
+                self.owners.insert(object_ref, controller);
+            }
+        }
+    }
+
+    pub fn owner(&self, kind: &str, name: &str) -> Option<&ObjectRef> {
+        self.owners.get(&ObjectRef::new(kind, name))
+    }
+
+    /// Owners of an object, closest first: `[ReplicaSet web-7d9f, Deployment web]`.
+    pub fn owner_chain(&self, kind: &str, name: &str) -> Vec<&ObjectRef> {
+        let mut chain: Vec<&ObjectRef> = Vec::new();
+        let mut current = self.owner(kind, name);
+        while let Some(owner) = current {
+            if chain.len() == MAX_OWNER_DEPTH || chain.contains(&owner) {
+                break;
+            }
+            chain.push(owner);
+            current = self.owners.get(owner);
+        }
+        chain
+    }
+
+    /// The top-level workload of an object, if it has an owner.
+    pub fn root_owner(&self, kind: &str, name: &str) -> Option<&ObjectRef> {
+        self.owner_chain(kind, name).last().copied()
+    }
+
+    /// Names of the pods `kind`/`name` controls, directly or through a ReplicaSet or a
+    /// Job, sorted by name.
+    pub fn pods_of(&self, kind: &str, name: &str) -> Vec<&str> {
+        self.owners
+            .keys()
+            .filter(|object| object.kind == "Pod")
+            .filter(|pod| {
+                self.owner_chain(&pod.kind, &pod.name)
+                    .iter()
+                    .any(|owner| owner.is(kind, name))
+            })
+            .map(|pod| pod.name.as_str())
+            .collect()
+    }
+}
+
+/// The owner reference with `controller: true`, or the first one.
+fn controller_of(object: &Value) -> Option<ObjectRef> {
+    let owners = object["metadata"]["ownerReferences"].as_array()?;
+    let owner = owners
+        .iter()
+        .find(|owner| owner["controller"].as_bool() == Some(true))
+        .or_else(|| owners.first())?;
+    Some(ObjectRef::new(
+        owner["kind"].as_str()?,
+        owner["name"].as_str()?,
+    ))
+}
+
+/// `spec.selector` of a workload: `matchLabels` and `matchExpressions` must all match.
+/// An empty selector matches nothing.
+fn selector_matches(selector: &Value, labels: &BTreeMap<String, String>) -> bool {
+    let match_labels = string_map(&selector["matchLabels"]);
+    let expressions = selector["matchExpressions"]
+        .as_array()
+        .map(|expressions| expressions.as_slice())
+        .unwrap_or_default();
+    if match_labels.is_empty() && expressions.is_empty() {
+        return false;
+    }
+
+    match_labels
+        .iter()
+        .all(|(key, value)| labels.get(key) == Some(value))
+        && expressions
+            .iter()
+            .all(|expression| expression_matches(expression, labels))
+}
+
+fn expression_matches(expression: &Value, labels: &BTreeMap<String, String>) -> bool {
+    let Some(key) = expression["key"].as_str() else {
+        return false;
+    };
+    let values: Vec<&str> = expression["values"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|value| value.as_str())
+        .collect();
+    let label = labels.get(key).map(|value| value.as_str());
+
+    match expression["operator"].as_str() {
+        Some("In") => label.is_some_and(|label| values.contains(&label)),
+        Some("NotIn") => label.is_none_or(|label| !values.contains(&label)),
+        Some("Exists") => label.is_some(),
+        Some("DoesNotExist") => label.is_none(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn object(name: &str, labels: Value, owner: Option<(&str, &str)>) -> Value {
+        let owner_references = match owner {
+            Some((kind, owner_name)) => {
+                json!([{ "kind": kind, "name": owner_name, "controller": true }])
+            }
+            None => json!([]),
+        };
+        json!({
+            "metadata": { "name": name, "labels": labels, "ownerReferences": owner_references },
+        })
+    }
+
+    fn workload(name: &str, selector: Value) -> Value {
+        json!({ "metadata": { "name": name }, "spec": { "selector": selector } })
+    }
+
+    #[test]
+    fn follows_owner_references() {
+        let objects = NamespaceObjects {
+            deployments: vec![workload("web", json!({ "matchLabels": { "app": "web" } }))],
+            replicasets: vec![object("web-7d9f", json!({}), Some(("Deployment", "web")))],
+            pods: vec![
+                object(
+                    "web-7d9f-abcde",
+                    json!({}),
+                    Some(("ReplicaSet", "web-7d9f")),
+                ),
+                object(
+                    "web-7d9f-fghij",
+                    json!({}),
+                    Some(("ReplicaSet", "web-7d9f")),
+                ),
+                object("standalone", json!({}), None),
+            ],
+            ..NamespaceObjects::default()
+        };
+        let graph = OwnershipGraph::from_objects(&objects);
+
+        assert_eq!(
+            graph.owner_chain("Pod", "web-7d9f-abcde"),
+            vec![
+                &ObjectRef::new("ReplicaSet", "web-7d9f"),
+                &ObjectRef::new("Deployment", "web")
+            ]
+        );
+        assert_eq!(
+            graph.root_owner("Pod", "web-7d9f-fghij"),
+            Some(&ObjectRef::new("Deployment", "web"))
+        );
+        assert_eq!(graph.root_owner("Pod", "standalone"), None);
+        assert_eq!(
+            graph.pods_of("Deployment", "web"),
+            vec!["web-7d9f-abcde", "web-7d9f-fghij"]
+        );
+        assert_eq!(graph.pods_of("ReplicaSet", "web-7d9f").len(), 2);
+    }
+
+    #[test]
+    fn falls_back_to_label_selectors() {
+        let objects = NamespaceObjects {
+            deployments: vec![workload("web", json!({ "matchLabels": { "app": "web" } }))],
+            statefulsets: vec![workload(
+                "db",
+                json!({ "matchExpressions": [
+                    { "key": "app", "operator": "In", "values": ["db", "database"] },
+                    { "key": "canary", "operator": "DoesNotExist" },
+                ] }),
+            )],
+            pods: vec![
+                object("web-1", json!({ "app": "web" }), None),
+                object("db-0", json!({ "app": "db" }), None),
+                object("db-canary", json!({ "app": "db", "canary": "true" }), None),
+                object("other", json!({ "app": "other" }), None),
+            ],
+            ..NamespaceObjects::default()
+        };
+        let graph = OwnershipGraph::from_objects(&objects);
+
+        assert_eq!(
+            graph.owner("Pod", "web-1"),
+            Some(&ObjectRef::new("Deployment", "web"))
+        );
+        assert_eq!(
+            graph.owner("Pod", "db-0"),
+            Some(&ObjectRef::new("StatefulSet", "db"))
+        );
+        assert_eq!(graph.owner("Pod", "db-canary"), None);
+        assert_eq!(graph.owner("Pod", "other"), None);
+    }
+
+    #[test]
+    fn owner_references_win_over_selectors() {
+        let objects = NamespaceObjects {
+            jobs: vec![workload(
+                "migrate",
+                json!({ "matchLabels": { "app": "web" } }),
+            )],
+            deployments: vec![workload("web", json!({ "matchLabels": { "app": "web" } }))],
+            pods: vec![object(
+                "migrate-x",
+                json!({ "app": "web" }),
+                Some(("Job", "migrate")),
+            )],
+            ..NamespaceObjects::default()
+        };
+        let graph = OwnershipGraph::from_objects(&objects);
+
+        assert_eq!(
+            graph.root_owner("Pod", "migrate-x").unwrap().label(),
+            "Job migrate"
+        );
+        assert!(graph.pods_of("Deployment", "web").is_empty());
+    }
+
+    #[test]
+    fn empty_selectors_match_nothing() {
+        let objects = NamespaceObjects {
+            deployments: vec![workload("web", json!({}))],
+            pods: vec![object("web-1", json!({ "app": "web" }), None)],
+            ..NamespaceObjects::default()
+        };
+        let graph = OwnershipGraph::from_objects(&objects);
+        assert_eq!(graph.owner("Pod", "web-1"), None);
+    }
+
+    #[test]
+    fn owner_cycles_end() {
+        let objects = NamespaceObjects {
+            replicasets: vec![object("a", json!({}), Some(("Deployment", "b")))],
+            deployments: vec![object("b", json!({}), Some(("ReplicaSet", "a")))],
+            ..NamespaceObjects::default()
+        };
+        let graph = OwnershipGraph::from_objects(&objects);
+        assert_eq!(graph.owner_chain("ReplicaSet", "a").len(), 2);
+    }
+}
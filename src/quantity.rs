@@ -0,0 +1,241 @@
+use serde_json::Value;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+
+/// Nano-units in one unit: quantities are stored in nano-units so that `1n` CPU and
+/// `1Ei` of memory both fit without rounding.
+const NANOS: i128 = 1_000_000_000;
+
+/// Binary suffixes and their power of two.
+const BINARY_SUFFIXES: [(&str, u32); 6] = [
+    ("Ki", 10),
+    ("Mi", 20),
+    ("Gi", 30),
+    ("Ti", 40),
+    ("Pi", 50),
+    ("Ei", 60),
+];
+
+/// Decimal SI suffixes and their power of ten.
+const DECIMAL_SUFFIXES: [(&str, i32); 10] = [
+    ("n", -9),
+    ("u", -6),
+    ("m", -3),
+    ("", 0),
+    ("k", 3),
+    ("M", 6),
+    ("G", 9),
+    ("T", 12),
+    ("P", 15),
+    ("E", 18),
+];
+
+/// Longest mantissa accepted, in digits, so that scaling it cannot overflow.
+const MAX_DIGITS: usize = 24;
+
+/// A Kubernetes resource quantity: `500m`, `2`, `1Gi`, `128974848`, `1.5e3`, ...
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Quantity(i128);
+
+impl Quantity {
+    pub const ZERO: Quantity = Quantity(0);
+
+    /// Parse a quantity the way the API server does. Values smaller than the
+    /// precision (`0.1n`) round up to it.
+    pub fn parse(quantity: &str) -> Option<Quantity> {
+        let quantity = quantity.trim();
+        let (negative, quantity) = match quantity.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, quantity.strip_prefix('+').unwrap_or(quantity)),
+        };
+
+        let number_end = quantity
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(quantity.len());
+        let (number, suffix) = quantity.split_at(number_end);
+        let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+        let digits = format!("{}{}", integer, fraction);
+        if digits.is_empty() || digits.len() > MAX_DIGITS || fraction.contains('.') {
+            return None;
+        }
+        let mantissa: i128 = digits.parse().ok()?;
+
+        let (exponent, binary_shift) = parse_suffix(suffix)?;
+        let mut value = mantissa.checked_mul(1i128.checked_shl(binary_shift)?)?;
+        let exponent = exponent + 9 - fraction.len() as i32;
+        if exponent >= 0 {
+            value = value.checked_mul(10i128.checked_pow(exponent as u32)?)?;
+        } else {
+            let divisor = 10i128.checked_pow(exponent.unsigned_abs())?;
+            value = (value + divisor - 1) / divisor;
+        }
+
+        Some(Quantity(if negative { -value } else { value }))
+    }
+
+    /// Quantities may be written as strings (`"500m"`) or bare numbers (`1`).
+    pub fn from_value(value: &Value) -> Option<Quantity> {
+        match value {
+            Value::String(quantity) => Quantity::parse(quantity),
+            Value::Number(quantity) => Quantity::parse(&quantity.to_string()),
+            _ => None,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Thousandths of a unit (millicores for CPU), rounded up.
+    pub fn millis(&self) -> i128 {
+        ceil_div(self.0, NANOS / 1000)
+    }
+
+    /// Whole units (bytes for memory), rounded up.
+    pub fn units(&self) -> i128 {
+        ceil_div(self.0, NANOS)
+    }
+
+    /// CPU as `kubectl describe node` shows it: whole cores (`2`) or millicores (`250m`).
+    pub fn cpu_label(&self) -> String {
+        let millis = self.millis();
+        if millis % 1000 == 0 {
+            (millis / 1000).to_string()
+        } else {
+            format!("{}m", millis)
+        }
+    }
+
+    /// Memory in the largest binary unit it reaches, with one decimal: `512Mi`, `1.5Gi`.
+    pub fn memory_label(&self) -> String {
+        let bytes = self.units();
+        for (suffix, shift) in BINARY_SUFFIXES.iter().rev() {
+            let unit = 1i128 << shift;
+            if bytes.abs() >= unit {
+                let value = (bytes as f64 / unit as f64 * 10.0).round() / 10.0;
+                return if value.fract() == 0.0 {
+                    format!("{:.0}{}", value, suffix)
+                } else {
+                    format!("{:.1}{}", value, suffix)
+                };
+            }
+        }
+        bytes.to_string()
+    }
+
+    /// `self` as a percentage of `total`, or None when `total` is zero.
+    pub fn percent_of(&self, total: Quantity) -> Option<f64> {
+        (total.0 != 0).then(|| self.0 as f64 * 100.0 / total.0 as f64)
+    }
+}
+
+impl Add for Quantity {
+    type Output = Quantity;
+
+    fn add(self, other: Quantity) -> Quantity {
+        Quantity(self.0.saturating_add(other.0))
+    }
+}
+
+impl AddAssign for Quantity {
+    fn add_assign(&mut self, other: Quantity) {
+        *self = *self + other;
+    }
+}
+
+impl Sum for Quantity {
+    fn sum<I: Iterator<Item = Quantity>>(iter: I) -> Quantity {
+        iter.fold(Quantity::ZERO, Add::add)
+    }
+}
+
+/// (power of ten, power of two) of a suffix: `Mi`, `k`, `e3`, `E-2`, ...
+fn parse_suffix(suffix: &str) -> Option<(i32, u32)> {
+    if let Some((_, shift)) = BINARY_SUFFIXES.iter().find(|(name, _)| *name == suffix) {
+        return Some((0, *shift));
+    }
+    if let Some((_, exponent)) = DECIMAL_SUFFIXES.iter().find(|(name, _)| *name == suffix) {
+        return Some((*exponent, 0));
+    }
+    // Decimal exponent; a bare `E` is the exa suffix above
+    let exponent = suffix.strip_prefix(['e', 'E'])?;
+    let exponent: i32 = exponent.parse().ok()?;
+    (-30..=30).contains(&exponent).then_some((exponent, 0))
+}
+
+fn ceil_div(value: i128, divisor: i128) -> i128 {
+    let quotient = value / divisor;
+    if value % divisor > 0 {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn parse(quantity: &str) -> Quantity {
+        Quantity::parse(quantity).unwrap()
+    }
+
+    #[test]
+    fn parses_cpu_quantities() {
+        assert_eq!(parse("500m").millis(), 500);
+        assert_eq!(parse("2").millis(), 2000);
+        assert_eq!(parse("0.25").millis(), 250);
+        assert_eq!(parse("1.5e3").units(), 1500);
+        assert_eq!(parse("100n").millis(), 1);
+        assert_eq!(parse("250m").cpu_label(), "250m");
+        assert_eq!(parse("2000m").cpu_label(), "2");
+    }
+
+    #[test]
+    fn parses_memory_quantities() {
+        assert_eq!(parse("1Gi").units(), 1 << 30);
+        assert_eq!(parse("128974848").units(), 128_974_848);
+        assert_eq!(parse("129M").units(), 129_000_000);
+        assert_eq!(parse("1Ei").units(), 1 << 60);
+        assert_eq!(parse("512Mi").memory_label(), "512Mi");
+        assert_eq!(parse("1536Mi").memory_label(), "1.5Gi");
+        assert_eq!(parse("100").memory_label(), "100");
+    }
+
+    #[test]
+    fn rounds_below_precision_up() {
+        assert_eq!(parse("0.1n"), Quantity(1));
+        assert_eq!(parse("1.5m").millis(), 2);
+    }
+
+    #[test]
+    fn rejects_invalid_quantities() {
+        for quantity in [
+            "",
+            "m",
+            "1.2.3",
+            "12Xi",
+            "1e31",
+            "1x",
+            "1234567890123456789012345",
+        ] {
+            assert_eq!(Quantity::parse(quantity), None, "{}", quantity);
+        }
+    }
+
+    #[test]
+    fn reads_strings_and_numbers() {
+        assert_eq!(Quantity::from_value(&json!("500m")), Some(parse("0.5")));
+        assert_eq!(Quantity::from_value(&json!(2)), Some(parse("2")));
+        assert_eq!(Quantity::from_value(&json!(null)), None);
+    }
+
+    #[test]
+    fn sums_and_percentages() {
+        let total: Quantity = ["250m", "750m", "1"].into_iter().map(parse).sum();
+        assert_eq!(total, parse("2"));
+        assert_eq!(parse("500m").percent_of(total), Some(25.0));
+        assert_eq!(total.percent_of(Quantity::ZERO), None);
+    }
+}
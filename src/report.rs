@@ -0,0 +1,791 @@
+use serde_json::{json, Value};
+
+use crate::diff::{ChangeKind, DumpDiff, DumpTotals, ObjectChange};
+use crate::error::Result;
+use crate::kubernetes::{ClusterAnalysis, ClusterIssue, DeploymentInfo, IssueSeverity, PodInfo};
+use crate::metrics::ResourceUsage;
+use crate::nodes::NodeInfo;
+use crate::ownership::ObjectRef;
+use crate::quantity::Quantity;
+use crate::resources::{CapacityReport, ResourceTotals};
+use crate::workloads::WorkloadKind;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReportFormat {
+    Text,
+    Json,
+    Markdown,
+}
+
+impl ReportFormat {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "text" | "txt" => Ok(ReportFormat::Text),
+            "json" => Ok(ReportFormat::Json),
+            "markdown" | "md" => Ok(ReportFormat::Markdown),
+            _ => Err(format!("Unknown format '{}' (expected text, json or markdown)", s).into()),
+        }
+    }
+}
+
+/// Render the full cluster analysis in the requested format.
+pub fn render_analysis(analysis: &ClusterAnalysis, dump: &str, format: ReportFormat) -> String {
+    match format {
+        ReportFormat::Text => render_text(analysis, dump),
+        ReportFormat::Json => {
+            serde_json::to_string_pretty(&analysis_to_json(analysis, dump)).unwrap_or_default()
+        }
+        ReportFormat::Markdown => render_markdown(analysis, dump),
+    }
+}
+
+/// Render the comparison of two dumps in the requested format.
+pub fn render_diff(diff: &DumpDiff, before: &str, after: &str, format: ReportFormat) -> String {
+    match format {
+        ReportFormat::Text => render_diff_text(diff, before, after),
+        ReportFormat::Json => {
+            serde_json::to_string_pretty(&diff_to_json(diff, before, after)).unwrap_or_default()
+        }
+        ReportFormat::Markdown => render_diff_markdown(diff, before, after),
+    }
+}
+
+fn render_text(analysis: &ClusterAnalysis, dump: &str) -> String {
+    let mut lines = Vec::new();
+
+    lines.push(format!("🔍 Cluster Analysis: {}", dump));
+    lines.push(summary_line(analysis));
+    lines.push(String::new());
+
+    if !analysis.nodes.is_empty() {
+        lines.push(format!("🖥️ Nodes ({})", analysis.nodes.len()));
+        for node in &analysis.nodes {
+            lines.push(format!(
+                "  {} Node: {} ({}, roles: {}, kubelet: {})",
+                node.icon(),
+                node.name,
+                node.status_label(),
+                node.roles_label(),
+                node.kubelet_version.as_deref().unwrap_or("-")
+            ));
+        }
+        lines.push(String::new());
+    }
+
+    lines.push("📊 Capacity (requests / limits)".to_string());
+    for allocation in &analysis.capacity.nodes {
+        lines.push(format!(
+            "  🖥️ {}: {} | allocatable: cpu {}, memory {}{}",
+            allocation.node,
+            totals_label(&allocation.totals),
+            allocation.allocatable_cpu.cpu_label(),
+            allocation.allocatable_memory.memory_label(),
+            allocation
+                .usage
+                .map(|usage| format!(" | usage: {}", usage.label()))
+                .unwrap_or_default()
+        ));
+    }
+    for namespace in &analysis.capacity.namespaces {
+        lines.push(format!(
+            "  📁 {}: {} | {} pods, {} without limits",
+            namespace.namespace,
+            totals_label(&namespace.totals),
+            namespace.pods,
+            namespace.pods_without_limits
+        ));
+    }
+    lines.push(String::new());
+
+    for ns_analysis in &analysis.namespaces {
+        lines.push(format!(
+            "📁 Namespace: {} ({} pods, {} deployments, {} other workloads, {} issues)",
+            ns_analysis.name,
+            ns_analysis.pods.len(),
+            ns_analysis.deployments.len(),
+            ns_analysis.workloads.total(),
+            ns_analysis.issues.len()
+        ));
+        for pod in &ns_analysis.pods {
+            lines.push(format!(
+                "  {} Pod: {} ({}, ready: {}, restarts: {}{})",
+                pod_icon(pod),
+                pod.name,
+                pod.status,
+                if pod.ready { "yes" } else { "no" },
+                pod.restart_count.as_deref().unwrap_or("-"),
+                pod.usage
+                    .map(|usage| format!(", usage: {}", usage.label()))
+                    .unwrap_or_default()
+            ));
+        }
+        for deployment in &ns_analysis.deployments {
+            lines.push(format!(
+                "  {} Deployment: {} ({}/{} replicas)",
+                deployment_icon(deployment),
+                deployment.name,
+                deployment.ready_replicas,
+                deployment.desired_replicas
+            ));
+        }
+        for kind in WorkloadKind::ALL {
+            for summary in ns_analysis.workloads.summaries(kind) {
+                lines.push(format!(
+                    "  {} {}: {} ({})",
+                    summary.health.icon(),
+                    kind.label(),
+                    summary.name,
+                    summary.status
+                ));
+            }
+        }
+        lines.push(String::new());
+    }
+
+    lines.push("🚨 Issues".to_string());
+    if analysis.total_issues == 0 {
+        lines.push("  No issues found".to_string());
+    }
+    for severity in IssueSeverity::ALL {
+        let issues: Vec<&ClusterIssue> = analysis
+            .issues_by_severity()
+            .into_iter()
+            .filter(|issue| issue.severity == severity)
+            .collect();
+        if issues.is_empty() {
+            continue;
+        }
+        lines.push(format!(
+            "  {} {} ({})",
+            severity.icon(),
+            severity.label().to_uppercase(),
+            issues.len()
+        ));
+        for issue in issues {
+            lines.push(format!(
+                "    [{}] {} {}: {}{}",
+                issue_scope(issue),
+                issue.component_type,
+                issue.component,
+                issue.description,
+                owner_suffix(issue)
+            ));
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn render_markdown(analysis: &ClusterAnalysis, dump: &str) -> String {
+    let mut lines = Vec::new();
+
+    lines.push(format!("# Cluster Analysis: `{}`", dump));
+    lines.push(String::new());
+    lines.push(summary_line(analysis));
+    lines.push(String::new());
+
+    lines.push("## Issues".to_string());
+    lines.push(String::new());
+    if analysis.total_issues == 0 {
+        lines.push("No issues found.".to_string());
+    } else {
+        lines.push("| Severity | Namespace | Component | Description |".to_string());
+        lines.push("|---|---|---|---|".to_string());
+        for issue in analysis.issues_by_severity() {
+            lines.push(format!(
+                "| {} {} | {} | {} `{}`{} | {} |",
+                issue.severity.icon(),
+                issue.severity.label(),
+                issue_scope(issue),
+                issue.component_type,
+                issue.component,
+                owner_suffix(issue),
+                escape_markdown_cell(&issue.description)
+            ));
+        }
+    }
+    lines.push(String::new());
+
+    if !analysis.nodes.is_empty() {
+        lines.push("## Nodes".to_string());
+        lines.push(String::new());
+        lines.push("| Name | Status | Roles | Kubelet |".to_string());
+        lines.push("|---|---|---|---|".to_string());
+        for node in &analysis.nodes {
+            lines.push(format!(
+                "| `{}` | {} {} | {} | {} |",
+                node.name,
+                node.icon(),
+                node.status_label(),
+                node.roles_label(),
+                node.kubelet_version.as_deref().unwrap_or("-")
+            ));
+        }
+        lines.push(String::new());
+    }
+
+    lines.push("## Capacity".to_string());
+    lines.push(String::new());
+    lines.push(
+        "| Node / Namespace | CPU requests | CPU limits | Memory requests | Memory limits | Pods |"
+            .to_string(),
+    );
+    lines.push("|---|---|---|---|---|---|".to_string());
+    for allocation in &analysis.capacity.nodes {
+        lines.push(format!(
+            "| 🖥️ `{}` | {} | {} | {} | {} | {} |",
+            allocation.node,
+            usage_label(
+                allocation.totals.cpu_request,
+                allocation.cpu_request_percent(),
+                Quantity::cpu_label
+            ),
+            usage_label(
+                allocation.totals.cpu_limit,
+                allocation.cpu_limit_percent(),
+                Quantity::cpu_label
+            ),
+            usage_label(
+                allocation.totals.memory_request,
+                allocation.memory_request_percent(),
+                Quantity::memory_label
+            ),
+            usage_label(
+                allocation.totals.memory_limit,
+                allocation.memory_limit_percent(),
+                Quantity::memory_label
+            ),
+            allocation.pods
+        ));
+    }
+    for namespace in &analysis.capacity.namespaces {
+        lines.push(format!(
+            "| 📁 `{}` | {} | {} | {} | {} | {} ({} without limits) |",
+            namespace.namespace,
+            namespace.totals.cpu_request.cpu_label(),
+            namespace.totals.cpu_limit.cpu_label(),
+            namespace.totals.memory_request.memory_label(),
+            namespace.totals.memory_limit.memory_label(),
+            namespace.pods,
+            namespace.pods_without_limits
+        ));
+    }
+    lines.push(String::new());
+
+    lines.push("## Namespaces".to_string());
+    for ns_analysis in &analysis.namespaces {
+        lines.push(String::new());
+        lines.push(format!("### {}", ns_analysis.name));
+        lines.push(String::new());
+
+        if ns_analysis.pods.is_empty()
+            && ns_analysis.deployments.is_empty()
+            && ns_analysis.workloads.total() == 0
+        {
+            lines.push("_No workloads._".to_string());
+            continue;
+        }
+
+        lines.push("| Kind | Name | Status | Details |".to_string());
+        lines.push("|---|---|---|---|".to_string());
+        for pod in &ns_analysis.pods {
+            lines.push(format!(
+                "| Pod | `{}` | {} {} | ready: {}, restarts: {}{} |",
+                pod.name,
+                pod_icon(pod),
+                pod.status,
+                if pod.ready { "yes" } else { "no" },
+                pod.restart_count.as_deref().unwrap_or("-"),
+                pod.usage
+                    .map(|usage| format!(", usage: {}", usage.label()))
+                    .unwrap_or_default()
+            ));
+        }
+        for deployment in &ns_analysis.deployments {
+            lines.push(format!(
+                "| Deployment | `{}` | {} {}/{} | strategy: {} |",
+                deployment.name,
+                deployment_icon(deployment),
+                deployment.ready_replicas,
+                deployment.desired_replicas,
+                deployment.strategy.as_deref().unwrap_or("-")
+            ));
+        }
+        for kind in WorkloadKind::ALL {
+            for summary in ns_analysis.workloads.summaries(kind) {
+                lines.push(format!(
+                    "| {} | `{}` | {} | {} |",
+                    kind.label(),
+                    summary.name,
+                    summary.health.icon(),
+                    escape_markdown_cell(&summary.status)
+                ));
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn analysis_to_json(analysis: &ClusterAnalysis, dump: &str) -> Value {
+    let namespaces: Vec<Value> = analysis
+        .namespaces
+        .iter()
+        .map(|ns_analysis| {
+            json!({
+                "name": ns_analysis.name,
+                "pods": ns_analysis.pods.iter().map(|pod| json!({
+                    "name": pod.name,
+                    "status": pod.status,
+                    "ready": pod.ready,
+                    "restart_count": pod.restart_count,
+                    "image": pod.image,
+                    "usage": pod.usage.as_ref().map(usage_to_json),
+                    "owner": ns_analysis.ownership.root_owner("Pod", &pod.name).map(owner_to_json),
+                })).collect::<Vec<_>>(),
+                "deployments": ns_analysis.deployments.iter().map(|deployment| json!({
+                    "name": deployment.name,
+                    "ready_replicas": deployment.ready_replicas,
+                    "desired_replicas": deployment.desired_replicas,
+                    "strategy": deployment.strategy,
+                    "image": deployment.image,
+                    "pods": ns_analysis
+                        .pods_of("Deployment", &deployment.name)
+                        .iter()
+                        .map(|pod| pod.name.as_str())
+                        .collect::<Vec<_>>(),
+                })).collect::<Vec<_>>(),
+                "workloads": WorkloadKind::ALL.iter().flat_map(|kind| {
+                    ns_analysis.workloads.summaries(*kind).into_iter().map(move |summary| json!({
+                        "kind": kind.label(),
+                        "name": summary.name,
+                        "status": summary.status,
+                    }))
+                }).collect::<Vec<_>>(),
+                "issues": ns_analysis.issues.iter().map(issue_to_json).collect::<Vec<_>>(),
+            })
+        })
+        .collect();
+
+    json!({
+        "dump": dump,
+        "summary": {
+            "nodes": analysis.nodes.len(),
+            "namespaces": analysis.namespaces.len(),
+            "pods": analysis.total_pods,
+            "deployments": analysis.total_deployments,
+            "workloads": analysis.total_workloads,
+            "issues": analysis.total_issues,
+            "critical": analysis.issue_count(IssueSeverity::Critical),
+            "warning": analysis.issue_count(IssueSeverity::Warning),
+            "info": analysis.issue_count(IssueSeverity::Info),
+        },
+        "nodes": analysis.nodes.iter().map(node_to_json).collect::<Vec<_>>(),
+        "capacity": capacity_to_json(&analysis.capacity),
+        "namespaces": namespaces,
+        "issues": analysis.issues_by_severity().into_iter().map(issue_to_json).collect::<Vec<_>>(),
+    })
+}
+
+fn render_diff_text(diff: &DumpDiff, before: &str, after: &str) -> String {
+    let mut lines = Vec::new();
+
+    lines.push(format!("🔀 Dump Diff: {} → {}", before, after));
+    lines.push(diff_summary_line(diff));
+    lines.push(String::new());
+
+    if diff.is_empty() {
+        lines.push("No differences found".to_string());
+        return lines.join("\n");
+    }
+
+    for (title, issues) in [
+        ("🚨 New issues", &diff.new_issues),
+        ("✅ Resolved issues", &diff.resolved_issues),
+    ] {
+        lines.push(format!("{} ({})", title, issues.len()));
+        for issue in issues {
+            lines.push(format!(
+                "  {} [{}] {} {}: {}{}",
+                issue.severity.icon(),
+                issue_scope(issue),
+                issue.component_type,
+                issue.component,
+                issue.description,
+                owner_suffix(issue)
+            ));
+        }
+        lines.push(String::new());
+    }
+
+    if !diff.changed_issues.is_empty() {
+        lines.push(format!("✏️ Changed issues ({})", diff.changed_issues.len()));
+        for change in &diff.changed_issues {
+            let issue = &change.after;
+            lines.push(format!(
+                "  {} [{}] {} {}: {}{} ({})",
+                issue.severity.icon(),
+                issue_scope(issue),
+                issue.component_type,
+                issue.component,
+                issue.description,
+                owner_suffix(issue),
+                change.previous_label()
+            ));
+        }
+        lines.push(String::new());
+    }
+
+    for change in ChangeKind::ALL {
+        let objects: Vec<&ObjectChange> = diff.changes_of(change).collect();
+        if objects.is_empty() {
+            continue;
+        }
+        lines.push(format!(
+            "{} {} ({})",
+            change.icon(),
+            capitalize(change.label()),
+            objects.len()
+        ));
+        for object in objects {
+            lines.push(format!("  {} {}", object.kind, object.path()));
+            // Changed objects list what changed, added and removed ones their fields
+            if change == ChangeKind::Changed {
+                for detail in &object.details {
+                    lines.push(format!("      {}", detail));
+                }
+            } else if !object.details.is_empty() {
+                lines.push(format!("      {}", object.details.join(", ")));
+            }
+        }
+        lines.push(String::new());
+    }
+
+    lines.join("\n").trim_end().to_string()
+}
+
+fn render_diff_markdown(diff: &DumpDiff, before: &str, after: &str) -> String {
+    let mut lines = Vec::new();
+
+    lines.push(format!("# Dump Diff: `{}` → `{}`", before, after));
+    lines.push(String::new());
+    lines.push(diff_summary_line(diff));
+    lines.push(String::new());
+
+    for (title, issues) in [
+        ("New issues", &diff.new_issues),
+        ("Resolved issues", &diff.resolved_issues),
+    ] {
+        lines.push(format!("## {}", title));
+        lines.push(String::new());
+        if issues.is_empty() {
+            lines.push("None.".to_string());
+        } else {
+            lines.push("| Severity | Namespace | Component | Description |".to_string());
+            lines.push("|---|---|---|---|".to_string());
+            for issue in issues {
+                lines.push(format!(
+                    "| {} {} | {} | {} `{}`{} | {} |",
+                    issue.severity.icon(),
+                    issue.severity.label(),
+                    issue_scope(issue),
+                    issue.component_type,
+                    issue.component,
+                    owner_suffix(issue),
+                    escape_markdown_cell(&issue.description)
+                ));
+            }
+        }
+        lines.push(String::new());
+    }
+
+    lines.push("## Changed issues".to_string());
+    lines.push(String::new());
+    if diff.changed_issues.is_empty() {
+        lines.push("None.".to_string());
+    } else {
+        lines.push("| Severity | Namespace | Component | Description | Before |".to_string());
+        lines.push("|---|---|---|---|---|".to_string());
+        for change in &diff.changed_issues {
+            let (before, issue) = (&change.before, &change.after);
+            lines.push(format!(
+                "| {} {} | {} | {} `{}`{} | {} | {} {} |",
+                issue.severity.icon(),
+                issue.severity.label(),
+                issue_scope(issue),
+                issue.component_type,
+                issue.component,
+                owner_suffix(issue),
+                escape_markdown_cell(&issue.description),
+                before.severity.icon(),
+                escape_markdown_cell(&before.description)
+            ));
+        }
+    }
+    lines.push(String::new());
+
+    lines.push("## Objects".to_string());
+    lines.push(String::new());
+    if diff.changes.is_empty() {
+        lines.push("No objects were added, removed or changed.".to_string());
+    } else {
+        lines.push("| Change | Kind | Object | Details |".to_string());
+        lines.push("|---|---|---|---|".to_string());
+        for change in ChangeKind::ALL {
+            for object in diff.changes_of(change) {
+                lines.push(format!(
+                    "| {} {} | {} | `{}` | {} |",
+                    change.icon(),
+                    change.label(),
+                    object.kind,
+                    object.path(),
+                    escape_markdown_cell(&object.details.join("; "))
+                ));
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn diff_to_json(diff: &DumpDiff, before: &str, after: &str) -> Value {
+    json!({
+        "before": {
+            "dump": before,
+            "summary": totals_json(&diff.before),
+        },
+        "after": {
+            "dump": after,
+            "summary": totals_json(&diff.after),
+        },
+        "new_issues": diff.new_issues.iter().map(issue_to_json).collect::<Vec<_>>(),
+        "resolved_issues": diff.resolved_issues.iter().map(issue_to_json).collect::<Vec<_>>(),
+        "changed_issues": diff.changed_issues.iter().map(|change| json!({
+            "before": issue_to_json(&change.before),
+            "after": issue_to_json(&change.after),
+        })).collect::<Vec<_>>(),
+        "changes": diff.changes.iter().map(|object| json!({
+            "change": object.change.label(),
+            "kind": object.kind,
+            "namespace": object.namespace,
+            "name": object.name,
+            "details": object.details,
+        })).collect::<Vec<_>>(),
+    })
+}
+
+fn totals_json(totals: &DumpTotals) -> Value {
+    json!({
+        "nodes": totals.nodes,
+        "namespaces": totals.namespaces,
+        "pods": totals.pods,
+        "deployments": totals.deployments,
+        "workloads": totals.workloads,
+        "issues": totals.issues,
+        "critical": totals.critical,
+    })
+}
+
+fn issue_to_json(issue: &ClusterIssue) -> Value {
+    json!({
+        "severity": issue.severity.label(),
+        "namespace": issue.namespace,
+        "component": issue.component,
+        "component_type": issue.component_type,
+        "description": issue.description,
+        "owner": issue.owner.as_ref().map(owner_to_json),
+    })
+}
+
+fn owner_to_json(owner: &ObjectRef) -> Value {
+    json!({
+        "kind": owner.kind,
+        "name": owner.name,
+    })
+}
+
+fn node_to_json(node: &NodeInfo) -> Value {
+    json!({
+        "name": node.name,
+        "status": node.status_label(),
+        "ready": node.ready,
+        "unschedulable": node.unschedulable,
+        "roles": node.roles,
+        "kubelet_version": node.kubelet_version,
+        "internal_ip": node.internal_ip,
+        "capacity": node.capacity,
+        "allocatable": node.allocatable,
+        "conditions": node.conditions.iter().map(|condition| json!({
+            "type": condition.condition_type,
+            "status": condition.status,
+            "reason": condition.reason,
+            "message": condition.message,
+        })).collect::<Vec<_>>(),
+        "taints": node.taints.iter().map(|taint| taint.label()).collect::<Vec<_>>(),
+    })
+}
+
+fn capacity_to_json(capacity: &CapacityReport) -> Value {
+    json!({
+        "nodes": capacity.nodes.iter().map(|allocation| json!({
+            "name": allocation.node,
+            "allocatable": {
+                "cpu": allocation.allocatable_cpu.cpu_label(),
+                "memory": allocation.allocatable_memory.memory_label(),
+                "pods": allocation.allocatable_pods.units() as u64,
+            },
+            "pods": allocation.pods,
+            "resources": totals_to_json(&allocation.totals),
+            "percent": {
+                "cpu_requests": allocation.cpu_request_percent(),
+                "cpu_limits": allocation.cpu_limit_percent(),
+                "memory_requests": allocation.memory_request_percent(),
+                "memory_limits": allocation.memory_limit_percent(),
+            },
+            "usage": allocation.usage.as_ref().map(usage_to_json),
+            "requests_exceed_allocatable": allocation.requests_exceed_allocatable(),
+            "limits_overcommitted": allocation.limits_overcommitted(),
+        })).collect::<Vec<_>>(),
+        "namespaces": capacity.namespaces.iter().map(|namespace| json!({
+            "name": namespace.namespace,
+            "pods": namespace.pods,
+            "pods_without_limits": namespace.pods_without_limits,
+            "usage": namespace.usage.as_ref().map(usage_to_json),
+            "resources": totals_to_json(&namespace.totals),
+        })).collect::<Vec<_>>(),
+    })
+}
+
+/// Millicores and bytes, so that consumers do not have to parse quantities.
+fn totals_to_json(totals: &ResourceTotals) -> Value {
+    json!({
+        "cpu_request_millicores": totals.cpu_request.millis() as i64,
+        "cpu_limit_millicores": totals.cpu_limit.millis() as i64,
+        "memory_request_bytes": totals.memory_request.units() as i64,
+        "memory_limit_bytes": totals.memory_limit.units() as i64,
+    })
+}
+
+fn usage_to_json(usage: &ResourceUsage) -> Value {
+    json!({
+        "cpu_millicores": usage.cpu.millis() as i64,
+        "memory_bytes": usage.memory.units() as i64,
+    })
+}
+
+/// "cpu 500m/2, memory 256Mi/1Gi"
+fn totals_label(totals: &ResourceTotals) -> String {
+    format!(
+        "cpu {}/{}, memory {}/{}",
+        totals.cpu_request.cpu_label(),
+        totals.cpu_limit.cpu_label(),
+        totals.memory_request.memory_label(),
+        totals.memory_limit.memory_label()
+    )
+}
+
+/// "500m (25%)" against the allocatable of the node.
+fn usage_label(quantity: Quantity, percent: Option<f64>, label: fn(&Quantity) -> String) -> String {
+    match percent {
+        Some(percent) => format!("{} ({:.0}%)", label(&quantity), percent),
+        None => label(&quantity),
+    }
+}
+
+/// The namespace of an issue, or `cluster` for node issues.
+fn issue_scope(issue: &ClusterIssue) -> &str {
+    if issue.namespace.is_empty() {
+        "cluster"
+    } else {
+        &issue.namespace
+    }
+}
+
+/// " (owner: Deployment web)" for issues of objects a workload controls.
+fn owner_suffix(issue: &ClusterIssue) -> String {
+    match issue.owner {
+        Some(ref owner) => format!(" (owner: {})", owner.label()),
+        None => String::new(),
+    }
+}
+
+fn summary_line(analysis: &ClusterAnalysis) -> String {
+    format!(
+        "Nodes: {} | Namespaces: {} | Pods: {} | Deployments: {} | Other workloads: {} | Issues: {} ({} critical, {} warning, {} info)",
+        analysis.nodes.len(),
+        analysis.namespaces.len(),
+        analysis.total_pods,
+        analysis.total_deployments,
+        analysis.total_workloads,
+        analysis.total_issues,
+        analysis.issue_count(IssueSeverity::Critical),
+        analysis.issue_count(IssueSeverity::Warning),
+        analysis.issue_count(IssueSeverity::Info)
+    )
+}
+
+/// "Pods: 5 → 6 (+1) | ..." and the number of new and resolved issues.
+fn diff_summary_line(diff: &DumpDiff) -> String {
+    let (before, after) = (&diff.before, &diff.after);
+    let counts = [
+        ("Nodes", before.nodes, after.nodes),
+        ("Namespaces", before.namespaces, after.namespaces),
+        ("Pods", before.pods, after.pods),
+        ("Deployments", before.deployments, after.deployments),
+        ("Other workloads", before.workloads, after.workloads),
+        ("Issues", before.issues, after.issues),
+    ];
+    let mut parts: Vec<String> = counts
+        .iter()
+        .map(|(label, before, after)| format!("{}: {}", label, count_change(*before, *after)))
+        .collect();
+    parts.push(format!(
+        "{} new, {} resolved, {} changed issues",
+        diff.new_issues.len(),
+        diff.resolved_issues.len(),
+        diff.changed_issues.len()
+    ));
+    parts.join(" | ")
+}
+
+/// "5 → 6 (+1)", or just "5" when the count did not change.
+pub fn count_change(before: usize, after: usize) -> String {
+    if before == after {
+        before.to_string()
+    } else {
+        format!(
+            "{} → {} ({:+})",
+            before,
+            after,
+            after as i64 - before as i64
+        )
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn pod_icon(pod: &PodInfo) -> &'static str {
+    if pod.ready && pod.status == "Running" {
+        "🟢"
+    } else {
+        "🔴"
+    }
+}
+
+fn deployment_icon(deployment: &DeploymentInfo) -> &'static str {
+    if deployment.ready_replicas == deployment.desired_replicas {
+        "🟢"
+    } else {
+        "🟡"
+    }
+}
+
+fn escape_markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
@@ -0,0 +1,255 @@
+use std::collections::BTreeMap;
+
+use crate::kubernetes::{ContainerKind, ContainerResources, PodInfo};
+use crate::metrics::ResourceUsage;
+use crate::nodes::NodeInfo;
+use crate::quantity::Quantity;
+
+/// CPU and memory requests and limits, of a container or summed over pods.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ResourceTotals {
+    pub cpu_request: Quantity,
+    pub cpu_limit: Quantity,
+    pub memory_request: Quantity,
+    pub memory_limit: Quantity,
+}
+
+impl ResourceTotals {
+    /// Quantities that cannot be parsed count as zero.
+    pub fn of_container(resources: &ContainerResources) -> Self {
+        let quantity = |value: &Option<String>| {
+            value
+                .as_deref()
+                .and_then(Quantity::parse)
+                .unwrap_or_default()
+        };
+        ResourceTotals {
+            cpu_request: quantity(&resources.cpu_request),
+            cpu_limit: quantity(&resources.cpu_limit),
+            memory_request: quantity(&resources.memory_request),
+            memory_limit: quantity(&resources.memory_limit),
+        }
+    }
+
+    /// Effective requests and limits of a pod, computed like the scheduler does: the sum
+    /// over the app containers, or the largest init container if that is more.
+    pub fn of_pod(pod: &PodInfo) -> Self {
+        let mut app = ResourceTotals::default();
+        let mut init = ResourceTotals::default();
+        for container in &pod.containers {
+            let container_totals = ResourceTotals::of_container(&container.resources);
+            match container.kind {
+                ContainerKind::App => app = app + container_totals,
+                ContainerKind::Init => init = init.max(container_totals),
+                ContainerKind::Ephemeral => {}
+            }
+        }
+        app.max(init)
+    }
+
+    /// Sum over the pods that hold resources: pods that finished (`Succeeded`, `Failed`)
+    /// are left out, as in `kubectl describe node`.
+    pub fn of_pods<'a>(pods: impl IntoIterator<Item = &'a PodInfo>) -> Self {
+        pods.into_iter()
+            .filter(|pod| holds_resources(pod))
+            .map(ResourceTotals::of_pod)
+            .fold(ResourceTotals::default(), |total, pod| total + pod)
+    }
+
+    /// Field by field maximum.
+    fn max(self, other: ResourceTotals) -> Self {
+        ResourceTotals {
+            cpu_request: self.cpu_request.max(other.cpu_request),
+            cpu_limit: self.cpu_limit.max(other.cpu_limit),
+            memory_request: self.memory_request.max(other.memory_request),
+            memory_limit: self.memory_limit.max(other.memory_limit),
+        }
+    }
+}
+
+impl std::ops::Add for ResourceTotals {
+    type Output = ResourceTotals;
+
+    fn add(self, other: ResourceTotals) -> ResourceTotals {
+        ResourceTotals {
+            cpu_request: self.cpu_request + other.cpu_request,
+            cpu_limit: self.cpu_limit + other.cpu_limit,
+            memory_request: self.memory_request + other.memory_request,
+            memory_limit: self.memory_limit + other.memory_limit,
+        }
+    }
+}
+
+/// Pods that are not done yet keep their requests reserved on their node.
+pub fn holds_resources(pod: &PodInfo) -> bool {
+    pod.status != "Succeeded" && pod.status != "Failed"
+}
+
+/// Pods with an app container that has no CPU or no memory limit.
+pub fn lacks_limits(pod: &PodInfo) -> bool {
+    !cpu_limited(pod) || !memory_limited(pod)
+}
+
+/// Every app container has a CPU limit, so the pod as a whole is bounded.
+pub fn cpu_limited(pod: &PodInfo) -> bool {
+    app_containers_set(pod, |resources| &resources.cpu_limit)
+}
+
+/// Every app container has a memory limit, so the pod as a whole is bounded.
+pub fn memory_limited(pod: &PodInfo) -> bool {
+    app_containers_set(pod, |resources| &resources.memory_limit)
+}
+
+fn app_containers_set(pod: &PodInfo, field: fn(&ContainerResources) -> &Option<String>) -> bool {
+    pod.containers
+        .iter()
+        .filter(|container| container.kind == ContainerKind::App)
+        .all(|container| field(&container.resources).is_some())
+}
+
+/// Requests and limits of the pods running on a node, against what the node can
+/// allocate to pods.
+#[derive(Debug, Clone)]
+pub struct NodeAllocation {
+    pub node: String,
+    pub allocatable_cpu: Quantity,
+    pub allocatable_memory: Quantity,
+    pub allocatable_pods: Quantity,
+    pub totals: ResourceTotals,
+    pub pods: usize,
+    pub usage: Option<ResourceUsage>, // From the node metrics, when the dump has them
+}
+
+impl NodeAllocation {
+    pub fn new<'a>(node: &NodeInfo, pods: impl IntoIterator<Item = &'a PodInfo>) -> Self {
+        let allocatable = |resource: &str| {
+            node.allocatable
+                .get(resource)
+                .or_else(|| node.capacity.get(resource))
+                .and_then(|quantity| Quantity::parse(quantity))
+                .unwrap_or_default()
+        };
+        let pods: Vec<&PodInfo> = pods
+            .into_iter()
+            .filter(|pod| holds_resources(pod))
+            .collect();
+
+        NodeAllocation {
+            node: node.name.clone(),
+            allocatable_cpu: allocatable("cpu"),
+            allocatable_memory: allocatable("memory"),
+            allocatable_pods: allocatable("pods"),
+            totals: ResourceTotals::of_pods(pods.iter().copied()),
+            pods: pods.len(),
+            usage: None,
+        }
+    }
+
+    pub fn cpu_request_percent(&self) -> Option<f64> {
+        self.totals.cpu_request.percent_of(self.allocatable_cpu)
+    }
+
+    pub fn cpu_limit_percent(&self) -> Option<f64> {
+        self.totals.cpu_limit.percent_of(self.allocatable_cpu)
+    }
+
+    pub fn memory_request_percent(&self) -> Option<f64> {
+        self.totals
+            .memory_request
+            .percent_of(self.allocatable_memory)
+    }
+
+    pub fn memory_limit_percent(&self) -> Option<f64> {
+        self.totals.memory_limit.percent_of(self.allocatable_memory)
+    }
+
+    /// Requests above allocatable: the scheduler should never allow this, so pods were
+    /// bound directly or the node shrank.
+    pub fn requests_exceed_allocatable(&self) -> bool {
+        exceeds(self.cpu_request_percent()) || exceeds(self.memory_request_percent())
+    }
+
+    /// Limits above allocatable: the pods cannot all use their limits at once.
+    pub fn limits_overcommitted(&self) -> bool {
+        exceeds(self.cpu_limit_percent()) || exceeds(self.memory_limit_percent())
+    }
+}
+
+/// Requests and limits of the pods of a namespace.
+#[derive(Debug, Clone, Default)]
+pub struct NamespaceResources {
+    pub namespace: String,
+    pub totals: ResourceTotals,
+    pub pods: usize,
+    pub pods_without_limits: usize,
+    pub usage: Option<ResourceUsage>, // Sum over the pods that have metrics
+}
+
+/// Requests and limits per node and per namespace, for the Capacity screen and the
+/// report.
+#[derive(Debug, Clone, Default)]
+pub struct CapacityReport {
+    pub nodes: Vec<NodeAllocation>,
+    pub namespaces: Vec<NamespaceResources>,
+}
+
+impl CapacityReport {
+    /// `pods` are (namespace, pod) pairs of the whole cluster.
+    pub fn new<'a>(
+        nodes: &[NodeInfo],
+        pods: impl IntoIterator<Item = (&'a str, &'a PodInfo)>,
+    ) -> Self {
+        let pods: Vec<(&str, &PodInfo)> = pods.into_iter().collect();
+
+        let node_allocations = nodes
+            .iter()
+            .map(|node| {
+                let node_pods = pods
+                    .iter()
+                    .filter(|(_, pod)| pod.node_name.as_deref() == Some(node.name.as_str()))
+                    .map(|(_, pod)| *pod);
+                NodeAllocation::new(node, node_pods)
+            })
+            .collect();
+
+        let mut namespaces: BTreeMap<&str, NamespaceResources> = BTreeMap::new();
+        for (namespace, pod) in pods.iter().filter(|(_, pod)| holds_resources(pod)) {
+            let resources = namespaces
+                .entry(namespace)
+                .or_insert_with(|| NamespaceResources {
+                    namespace: namespace.to_string(),
+                    ..NamespaceResources::default()
+                });
+            resources.totals = resources.totals + ResourceTotals::of_pod(pod);
+            resources.pods += 1;
+            if lacks_limits(pod) {
+                resources.pods_without_limits += 1;
+            }
+            if let Some(usage) = pod.usage {
+                resources.usage = Some(resources.usage.unwrap_or_default() + usage);
+            }
+        }
+
+        CapacityReport {
+            nodes: node_allocations,
+            namespaces: namespaces.into_values().collect(),
+        }
+    }
+
+    /// Requests and limits of the whole cluster against the allocatable of every node.
+    pub fn cluster_totals(&self) -> (ResourceTotals, Quantity, Quantity) {
+        let totals = self
+            .namespaces
+            .iter()
+            .fold(ResourceTotals::default(), |total, namespace| {
+                total + namespace.totals
+            });
+        let cpu = self.nodes.iter().map(|node| node.allocatable_cpu).sum();
+        let memory = self.nodes.iter().map(|node| node.allocatable_memory).sum();
+        (totals, cpu, memory)
+    }
+}
+
+fn exceeds(percent: Option<f64>) -> bool {
+    percent.is_some_and(|percent| percent > 100.0)
+}
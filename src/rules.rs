@@ -0,0 +1,1452 @@
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::error::Result;
+use crate::events::{parse_events, EventInfo};
+use crate::kubernetes::{
+    parse_pod, ClusterIssue, ClusterObjects, IssueSeverity, NamespaceObjects, PodInfo,
+};
+use crate::metrics::attach_usage;
+use crate::networking::{parse_ingress, parse_service};
+use crate::nodes::parse_nodes;
+use crate::quantity::Quantity;
+use crate::resources::{memory_limited, CapacityReport, NodeAllocation, ResourceTotals};
+
+/// A health check run against the raw objects of one namespace, or of the whole
+/// cluster for checks that involve nodes.
+pub trait Rule {
+    /// Stable identifier used in the config file, e.g. `crash-loop-backoff`.
+    fn id(&self) -> &'static str;
+    fn description(&self) -> &'static str;
+    fn default_severity(&self) -> IssueSeverity;
+
+    fn check(&self, _objects: &NamespaceObjects) -> Vec<ClusterIssue> {
+        Vec::new()
+    }
+
+    /// Run once per analysis. Issues about cluster-scoped objects have an empty
+    /// namespace.
+    fn check_cluster(&self, _cluster: &ClusterObjects) -> Vec<ClusterIssue> {
+        Vec::new()
+    }
+}
+
+/// Per-rule overrides from the `rules` section of the config file. `severity` replaces
+/// the severity of every issue of the rule, including the ones the rule escalates:
+///
+/// ```json
+/// { "rules": { "latest-tag": { "enabled": false }, "high-restarts": { "severity": "critical" } } }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RuleConfig {
+    pub enabled: Option<bool>,
+    pub severity: Option<IssueSeverity>,
+}
+
+impl RuleConfig {
+    pub fn from_json(rule_id: &str, json: &Value) -> Result<RuleConfig> {
+        let severity = match json["severity"].as_str() {
+            Some(severity) => Some(IssueSeverity::parse(severity).ok_or_else(|| {
+                format!("Invalid severity '{}' for rule '{}'", severity, rule_id)
+            })?),
+            None => None,
+        };
+
+        Ok(RuleConfig {
+            enabled: json["enabled"].as_bool(),
+            severity,
+        })
+    }
+}
+
+struct RegisteredRule {
+    rule: Box<dyn Rule>,
+    enabled: bool,
+    severity: Option<IssueSeverity>,
+}
+
+/// The set of rules `analyze_cluster` runs, with config overrides applied.
+pub struct RuleRegistry {
+    rules: Vec<RegisteredRule>,
+}
+
+impl RuleRegistry {
+    pub fn builtin() -> Self {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(PodNotReady),
+            Box::new(DeploymentReplicas),
+            Box::new(WorkloadReplicas),
+            Box::new(JobFailed),
+            Box::new(CrashLoopBackOff),
+            Box::new(OomKilled),
+            Box::new(ImagePullBackOff),
+            Box::new(HighRestartCount),
+            Box::new(MissingResourceLimits),
+            Box::new(RequestAboveLimit),
+            Box::new(LatestImageTag),
+            Box::new(SingleReplicaDeployment),
+            Box::new(ServiceWithoutReadyPods),
+            Box::new(IngressBackendMissing),
+            Box::new(WarningEvents),
+            Box::new(NodeNotReady),
+            Box::new(NodePressure),
+            Box::new(PodOnCordonedNode),
+            Box::new(NodeRequestsExceedAllocatable),
+            Box::new(NodeLimitsOvercommitted),
+            Box::new(MemoryNearLimit),
+            Box::new(OverRequested),
+        ];
+
+        RuleRegistry {
+            rules: rules
+                .into_iter()
+                .map(|rule| RegisteredRule {
+                    rule,
+                    enabled: true,
+                    severity: None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Built-in rules with the config file overrides applied. Unknown rule ids are an error
+    /// so typos in the config file do not silently do nothing.
+    pub fn from_config(overrides: &HashMap<String, RuleConfig>) -> Result<Self> {
+        let mut registry = RuleRegistry::builtin();
+
+        for (rule_id, rule_config) in overrides {
+            let registered = registry
+                .rules
+                .iter_mut()
+                .find(|registered| registered.rule.id() == rule_id)
+                .ok_or_else(|| format!("Unknown rule '{}' in config file", rule_id))?;
+
+            if let Some(enabled) = rule_config.enabled {
+                registered.enabled = enabled;
+            }
+            if rule_config.severity.is_some() {
+                registered.severity = rule_config.severity;
+            }
+        }
+
+        Ok(registry)
+    }
+
+    pub fn check_namespace(&self, objects: &NamespaceObjects) -> Vec<ClusterIssue> {
+        self.run(|rule| rule.check(objects))
+    }
+
+    pub fn check_cluster(&self, cluster: &ClusterObjects) -> Vec<ClusterIssue> {
+        self.run(|rule| rule.check_cluster(cluster))
+    }
+
+    fn run<F>(&self, check: F) -> Vec<ClusterIssue>
+    where
+        F: Fn(&dyn Rule) -> Vec<ClusterIssue>,
+    {
+        let mut issues = Vec::new();
+
+        for registered in self.rules.iter().filter(|registered| registered.enabled) {
+            for mut issue in check(registered.rule.as_ref()) {
+                issue.rule = registered.rule.id();
+                if let Some(severity) = registered.severity {
+                    issue.severity = severity;
+                }
+                issues.push(issue);
+            }
+        }
+
+        issues
+    }
+
+    /// (id, description, effective severity, enabled) for every registered rule.
+    pub fn summary(&self) -> Vec<(&'static str, &'static str, IssueSeverity, bool)> {
+        self.rules
+            .iter()
+            .map(|registered| {
+                (
+                    registered.rule.id(),
+                    registered.rule.description(),
+                    registered
+                        .severity
+                        .unwrap_or_else(|| registered.rule.default_severity()),
+                    registered.enabled,
+                )
+            })
+            .collect()
+    }
+}
+
+// Built-in rules
+
+struct PodNotReady;
+
+impl Rule for PodNotReady {
+    fn id(&self) -> &'static str {
+        "pod-not-ready"
+    }
+
+    fn description(&self) -> &'static str {
+        "Pod is not ready or not in the Running phase"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    fn check(&self, objects: &NamespaceObjects) -> Vec<ClusterIssue> {
+        let mut issues = Vec::new();
+
+        for pod in &objects.pods {
+            let phase = pod["status"]["phase"].as_str().unwrap_or("Unknown");
+            // Completed pods (e.g. finished Jobs) are expected to be not ready
+            if phase == "Succeeded" {
+                continue;
+            }
+
+            let ready = pod["status"]["conditions"]
+                .as_array()
+                .and_then(|conditions| conditions.iter().find(|c| c["type"] == "Ready"))
+                .map(|condition| condition["status"] == "True")
+                .unwrap_or(false);
+
+            if !ready || phase != "Running" {
+                let name = object_name(pod);
+                issues.push(issue(
+                    self.default_severity(),
+                    name,
+                    "Pod",
+                    objects,
+                    format!(
+                        "Pod {} is not ready or not running (status: {})",
+                        name, phase
+                    ),
+                ));
+            }
+        }
+
+        issues
+    }
+}
+
+struct DeploymentReplicas;
+
+impl Rule for DeploymentReplicas {
+    fn id(&self) -> &'static str {
+        "deployment-replicas"
+    }
+
+    fn description(&self) -> &'static str {
+        "Deployment has fewer ready replicas than desired (critical when none are ready)"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    fn check(&self, objects: &NamespaceObjects) -> Vec<ClusterIssue> {
+        let mut issues = Vec::new();
+
+        for deployment in &objects.deployments {
+            let name = object_name(deployment);
+            let ready_replicas = deployment["status"]["readyReplicas"].as_u64().unwrap_or(0);
+            // An omitted `spec.replicas` means 1, as the API server defaults it
+            let desired_replicas = deployment["spec"]["replicas"].as_u64().unwrap_or(1);
+
+            if ready_replicas != desired_replicas {
+                let severity = if ready_replicas == 0 {
+                    IssueSeverity::Critical
+                } else {
+                    self.default_severity()
+                };
+
+                issues.push(issue(
+                    severity,
+                    name,
+                    "Deployment",
+                    objects,
+                    format!(
+                        "Deployment {} has {}/{} replicas ready",
+                        name, ready_replicas, desired_replicas
+                    ),
+                ));
+            }
+        }
+
+        issues
+    }
+}
+
+struct WorkloadReplicas;
+
+impl Rule for WorkloadReplicas {
+    fn id(&self) -> &'static str {
+        "workload-replicas"
+    }
+
+    fn description(&self) -> &'static str {
+        "StatefulSet, DaemonSet or standalone ReplicaSet has pods that are not ready"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    fn check(&self, objects: &NamespaceObjects) -> Vec<ClusterIssue> {
+        let mut issues = Vec::new();
+
+        // (kind, object, ready, desired, unit)
+        let mut workloads: Vec<(&str, &Value, u64, u64, &str)> = Vec::new();
+        for statefulset in &objects.statefulsets {
+            workloads.push((
+                "StatefulSet",
+                statefulset,
+                statefulset["status"]["readyReplicas"].as_u64().unwrap_or(0),
+                statefulset["spec"]["replicas"].as_u64().unwrap_or(1),
+                "replicas",
+            ));
+        }
+        for daemonset in &objects.daemonsets {
+            workloads.push((
+                "DaemonSet",
+                daemonset,
+                daemonset["status"]["numberReady"].as_u64().unwrap_or(0),
+                daemonset["status"]["desiredNumberScheduled"]
+                    .as_u64()
+                    .unwrap_or(0),
+                "nodes",
+            ));
+        }
+        // ReplicaSets owned by a Deployment are already covered by `deployment-replicas`
+        for replicaset in objects.replicasets.iter().filter(|rs| !is_owned(rs)) {
+            workloads.push((
+                "ReplicaSet",
+                replicaset,
+                replicaset["status"]["readyReplicas"].as_u64().unwrap_or(0),
+                replicaset["spec"]["replicas"].as_u64().unwrap_or(1),
+                "replicas",
+            ));
+        }
+
+        for (kind, object, ready, desired, unit) in workloads {
+            if ready < desired {
+                let name = object_name(object);
+                let severity = if ready == 0 {
+                    IssueSeverity::Critical
+                } else {
+                    self.default_severity()
+                };
+
+                issues.push(issue(
+                    severity,
+                    name,
+                    kind,
+                    objects,
+                    format!("{} {} has {}/{} {} ready", kind, name, ready, desired, unit),
+                ));
+            }
+        }
+
+        issues
+    }
+}
+
+struct JobFailed;
+
+impl Rule for JobFailed {
+    fn id(&self) -> &'static str {
+        "job-failed"
+    }
+
+    fn description(&self) -> &'static str {
+        "Job has failed (e.g. exceeded its backoff limit or deadline)"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    fn check(&self, objects: &NamespaceObjects) -> Vec<ClusterIssue> {
+        objects
+            .jobs
+            .iter()
+            .filter_map(|job| {
+                let failed = job["status"]["conditions"]
+                    .as_array()?
+                    .iter()
+                    .find(|c| c["type"] == "Failed" && c["status"] == "True")?;
+                let name = object_name(job);
+                Some(issue(
+                    self.default_severity(),
+                    name,
+                    "Job",
+                    objects,
+                    format!(
+                        "Job {} failed: {}",
+                        name,
+                        failed["message"]
+                            .as_str()
+                            .or_else(|| failed["reason"].as_str())
+                            .unwrap_or("unknown reason")
+                    ),
+                ))
+            })
+            .collect()
+    }
+}
+
+struct CrashLoopBackOff;
+
+impl Rule for CrashLoopBackOff {
+    fn id(&self) -> &'static str {
+        "crash-loop-backoff"
+    }
+
+    fn description(&self) -> &'static str {
+        "A container is in CrashLoopBackOff"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Critical
+    }
+
+    fn check(&self, objects: &NamespaceObjects) -> Vec<ClusterIssue> {
+        container_status_issues(self, objects, |status| {
+            (waiting_reason(status) == Some("CrashLoopBackOff")).then(|| {
+                format!(
+                    "Container {} is in CrashLoopBackOff ({} restarts)",
+                    status["name"].as_str().unwrap_or("?"),
+                    status["restartCount"].as_u64().unwrap_or(0)
+                )
+            })
+        })
+    }
+}
+
+struct OomKilled;
+
+impl Rule for OomKilled {
+    fn id(&self) -> &'static str {
+        "oom-killed"
+    }
+
+    fn description(&self) -> &'static str {
+        "A container was killed for running out of memory"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    fn check(&self, objects: &NamespaceObjects) -> Vec<ClusterIssue> {
+        container_status_issues(self, objects, |status| {
+            let oom_killed = ["state", "lastState"]
+                .iter()
+                .any(|state| status[*state]["terminated"]["reason"] == "OOMKilled");
+            oom_killed.then(|| {
+                format!(
+                    "Container {} was OOMKilled",
+                    status["name"].as_str().unwrap_or("?")
+                )
+            })
+        })
+    }
+}
+
+struct ImagePullBackOff;
+
+impl Rule for ImagePullBackOff {
+    fn id(&self) -> &'static str {
+        "image-pull-backoff"
+    }
+
+    fn description(&self) -> &'static str {
+        "A container image cannot be pulled"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Critical
+    }
+
+    fn check(&self, objects: &NamespaceObjects) -> Vec<ClusterIssue> {
+        container_status_issues(self, objects, |status| match waiting_reason(status) {
+            Some(reason @ ("ImagePullBackOff" | "ErrImagePull" | "InvalidImageName")) => {
+                Some(format!(
+                    "Container {} cannot pull its image ({})",
+                    status["name"].as_str().unwrap_or("?"),
+                    reason
+                ))
+            }
+            _ => None,
+        })
+    }
+}
+
+/// Restart count at which `high-restarts` fires.
+const HIGH_RESTART_THRESHOLD: u64 = 5;
+
+struct HighRestartCount;
+
+impl Rule for HighRestartCount {
+    fn id(&self) -> &'static str {
+        "high-restarts"
+    }
+
+    fn description(&self) -> &'static str {
+        "A container restarted 5 times or more"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    fn check(&self, objects: &NamespaceObjects) -> Vec<ClusterIssue> {
+        container_status_issues(self, objects, |status| {
+            let restarts = status["restartCount"].as_u64().unwrap_or(0);
+            (restarts >= HIGH_RESTART_THRESHOLD).then(|| {
+                format!(
+                    "Container {} restarted {} times",
+                    status["name"].as_str().unwrap_or("?"),
+                    restarts
+                )
+            })
+        })
+    }
+}
+
+struct MissingResourceLimits;
+
+impl Rule for MissingResourceLimits {
+    fn id(&self) -> &'static str {
+        "missing-resource-limits"
+    }
+
+    fn description(&self) -> &'static str {
+        "A container has no CPU or memory limit"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Info
+    }
+
+    fn check(&self, objects: &NamespaceObjects) -> Vec<ClusterIssue> {
+        pod_template_issues(self, objects, |container| {
+            let limits = &container["resources"]["limits"];
+            let missing: Vec<&str> = ["cpu", "memory"]
+                .into_iter()
+                .filter(|resource| limits[*resource].is_null())
+                .collect();
+            (!missing.is_empty()).then(|| {
+                format!(
+                    "Container {} has no {} limit",
+                    container["name"].as_str().unwrap_or("?"),
+                    missing.join("/")
+                )
+            })
+        })
+    }
+}
+
+struct RequestAboveLimit;
+
+impl Rule for RequestAboveLimit {
+    fn id(&self) -> &'static str {
+        "request-above-limit"
+    }
+
+    fn description(&self) -> &'static str {
+        "A container requests more CPU or memory than its limit"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    fn check(&self, objects: &NamespaceObjects) -> Vec<ClusterIssue> {
+        pod_template_issues(self, objects, |container| {
+            let resources = &container["resources"];
+            let above: Vec<String> = ["cpu", "memory"]
+                .into_iter()
+                .filter_map(|resource| {
+                    let request = Quantity::from_value(&resources["requests"][resource])?;
+                    let limit = Quantity::from_value(&resources["limits"][resource])?;
+                    (request > limit).then(|| {
+                        let label = if resource == "cpu" {
+                            Quantity::cpu_label
+                        } else {
+                            Quantity::memory_label
+                        };
+                        format!(
+                            "{} request {} > limit {}",
+                            resource,
+                            label(&request),
+                            label(&limit)
+                        )
+                    })
+                })
+                .collect();
+            (!above.is_empty()).then(|| {
+                format!(
+                    "Container {} has {}",
+                    container["name"].as_str().unwrap_or("?"),
+                    above.join(", ")
+                )
+            })
+        })
+    }
+}
+
+struct LatestImageTag;
+
+impl Rule for LatestImageTag {
+    fn id(&self) -> &'static str {
+        "latest-tag"
+    }
+
+    fn description(&self) -> &'static str {
+        "A container image uses the :latest tag or no tag at all"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Info
+    }
+
+    fn check(&self, objects: &NamespaceObjects) -> Vec<ClusterIssue> {
+        pod_template_issues(self, objects, |container| {
+            let image = container["image"].as_str()?;
+            is_latest_image(image).then(|| {
+                format!(
+                    "Container {} uses unpinned image {}",
+                    container["name"].as_str().unwrap_or("?"),
+                    image
+                )
+            })
+        })
+    }
+}
+
+struct SingleReplicaDeployment;
+
+impl Rule for SingleReplicaDeployment {
+    fn id(&self) -> &'static str {
+        "single-replica"
+    }
+
+    fn description(&self) -> &'static str {
+        "Deployment runs a single replica and has no redundancy"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Info
+    }
+
+    fn check(&self, objects: &NamespaceObjects) -> Vec<ClusterIssue> {
+        objects
+            .deployments
+            .iter()
+            .filter(|deployment| deployment["spec"]["replicas"].as_u64().unwrap_or(1) == 1)
+            .map(|deployment| {
+                let name = object_name(deployment);
+                issue(
+                    self.default_severity(),
+                    name,
+                    "Deployment",
+                    objects,
+                    format!("Deployment {} runs a single replica", name),
+                )
+            })
+            .collect()
+    }
+}
+
+struct ServiceWithoutReadyPods;
+
+impl Rule for ServiceWithoutReadyPods {
+    fn id(&self) -> &'static str {
+        "service-no-ready-pods"
+    }
+
+    fn description(&self) -> &'static str {
+        "Service selector matches no pods (critical when pods match but none is ready)"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    fn check(&self, objects: &NamespaceObjects) -> Vec<ClusterIssue> {
+        let pods: Vec<PodInfo> = objects.pods.iter().filter_map(parse_pod).collect();
+        let mut issues = Vec::new();
+
+        // Services without a selector (e.g. ExternalName) have no pods to check
+        for service in objects.services.iter().filter_map(parse_service) {
+            if !service.has_selector() {
+                continue;
+            }
+
+            let matching = service.matching_pods(&pods);
+            let ready = matching
+                .iter()
+                .filter(|pod| pod.ready && pod.status == "Running")
+                .count();
+
+            if matching.is_empty() {
+                issues.push(issue(
+                    self.default_severity(),
+                    &service.name,
+                    "Service",
+                    objects,
+                    format!(
+                        "Service {} selector {} matches no pods",
+                        service.name,
+                        service.selector_label()
+                    ),
+                ));
+            } else if ready == 0 {
+                issues.push(issue(
+                    IssueSeverity::Critical,
+                    &service.name,
+                    "Service",
+                    objects,
+                    format!(
+                        "Service {} has no ready pods (0/{} matching pods ready)",
+                        service.name,
+                        matching.len()
+                    ),
+                ));
+            }
+        }
+
+        issues
+    }
+}
+
+struct IngressBackendMissing;
+
+impl Rule for IngressBackendMissing {
+    fn id(&self) -> &'static str {
+        "ingress-backend"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ingress routes to a Service or port that does not exist (critical for missing services)"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    fn check(&self, objects: &NamespaceObjects) -> Vec<ClusterIssue> {
+        let services: Vec<_> = objects.services.iter().filter_map(parse_service).collect();
+        let mut issues = Vec::new();
+
+        for ingress in objects.ingresses.iter().filter_map(parse_ingress) {
+            for backend in &ingress.backends {
+                match services
+                    .iter()
+                    .find(|service| service.name == backend.service)
+                {
+                    None => issues.push(issue(
+                        IssueSeverity::Critical,
+                        &ingress.name,
+                        "Ingress",
+                        objects,
+                        format!(
+                            "Ingress {} routes {} to missing service {}",
+                            ingress.name,
+                            backend.route_label(),
+                            backend.service
+                        ),
+                    )),
+                    Some(service) if !service.exposes(&backend.port) => issues.push(issue(
+                        self.default_severity(),
+                        &ingress.name,
+                        "Ingress",
+                        objects,
+                        format!(
+                            "Ingress {} routes {} to port {} not exposed by service {} ({})",
+                            ingress.name,
+                            backend.route_label(),
+                            backend.port.label(),
+                            service.name,
+                            service.ports_label()
+                        ),
+                    )),
+                    Some(_) => {}
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+struct WarningEvents;
+
+impl Rule for WarningEvents {
+    fn id(&self) -> &'static str {
+        "warning-events"
+    }
+
+    fn description(&self) -> &'static str {
+        "Warning events such as FailedScheduling, BackOff or Unhealthy"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    fn check(&self, objects: &NamespaceObjects) -> Vec<ClusterIssue> {
+        // One issue per object and reason: repeated events only add to the count
+        let mut grouped: Vec<EventInfo> = Vec::new();
+        for event in parse_events(&objects.events) {
+            if !event.is_warning() {
+                continue;
+            }
+            let existing = grouped.iter_mut().find(|seen| {
+                seen.involves(&event.involved_kind, &event.involved_name)
+                    && seen.reason == event.reason
+            });
+            match existing {
+                Some(seen) => {
+                    // Events are sorted oldest first, so the latest message wins
+                    seen.count += event.count;
+                    seen.message = event.message;
+                }
+                None => grouped.push(event),
+            }
+        }
+
+        grouped
+            .into_iter()
+            .map(|event| {
+                issue(
+                    self.default_severity(),
+                    &event.involved_name,
+                    &event.involved_kind,
+                    objects,
+                    format!("Event {}", event.summary().trim_start_matches("Warning ")),
+                )
+            })
+            .collect()
+    }
+}
+
+struct NodeNotReady;
+
+impl Rule for NodeNotReady {
+    fn id(&self) -> &'static str {
+        "node-not-ready"
+    }
+
+    fn description(&self) -> &'static str {
+        "Node is NotReady or its kubelet stopped reporting"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Critical
+    }
+
+    fn check_cluster(&self, cluster: &ClusterObjects) -> Vec<ClusterIssue> {
+        parse_nodes(&cluster.nodes)
+            .into_iter()
+            .filter(|node| !node.ready)
+            .map(|node| {
+                let reason = node
+                    .ready_condition()
+                    .and_then(|condition| condition.reason.as_deref())
+                    .unwrap_or("no Ready condition");
+                node_issue(
+                    self.default_severity(),
+                    &node.name,
+                    format!("Node {} is NotReady ({})", node.name, reason),
+                )
+            })
+            .collect()
+    }
+}
+
+struct NodePressure;
+
+impl Rule for NodePressure {
+    fn id(&self) -> &'static str {
+        "node-pressure"
+    }
+
+    fn description(&self) -> &'static str {
+        "Node reports MemoryPressure, DiskPressure, PIDPressure or NetworkUnavailable"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    fn check_cluster(&self, cluster: &ClusterObjects) -> Vec<ClusterIssue> {
+        let mut issues = Vec::new();
+
+        for node in parse_nodes(&cluster.nodes) {
+            for condition in node.pressure_conditions() {
+                issues.push(node_issue(
+                    self.default_severity(),
+                    &node.name,
+                    format!(
+                        "Node {} has {}{}",
+                        node.name,
+                        condition.condition_type,
+                        condition
+                            .message
+                            .as_ref()
+                            .map(|message| format!(": {}", message))
+                            .unwrap_or_default()
+                    ),
+                ));
+            }
+        }
+
+        issues
+    }
+}
+
+struct PodOnCordonedNode;
+
+impl Rule for PodOnCordonedNode {
+    fn id(&self) -> &'static str {
+        "pod-on-cordoned-node"
+    }
+
+    fn description(&self) -> &'static str {
+        "Pod is not ready and runs on a cordoned or NotReady node"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    fn check_cluster(&self, cluster: &ClusterObjects) -> Vec<ClusterIssue> {
+        let nodes = parse_nodes(&cluster.nodes);
+        let mut issues = Vec::new();
+
+        for objects in &cluster.namespaces {
+            for pod in objects.pods.iter().filter_map(parse_pod) {
+                // Finished pods do not need to be rescheduled
+                if pod.ready || pod.status == "Succeeded" || pod.status == "Failed" {
+                    continue;
+                }
+                let Some(node) = pod
+                    .node_name
+                    .as_ref()
+                    .and_then(|name| nodes.iter().find(|node| node.name == *name))
+                else {
+                    continue;
+                };
+                if node.ready && !node.unschedulable {
+                    continue;
+                }
+                issues.push(issue(
+                    self.default_severity(),
+                    &pod.name,
+                    "Pod",
+                    objects,
+                    format!(
+                        "Pod {} is stuck ({}, not ready) on node {} ({})",
+                        pod.name,
+                        pod.status,
+                        node.name,
+                        node.status_label()
+                    ),
+                ));
+            }
+        }
+
+        issues
+    }
+}
+
+struct NodeRequestsExceedAllocatable;
+
+impl Rule for NodeRequestsExceedAllocatable {
+    fn id(&self) -> &'static str {
+        "node-requests-exceed-allocatable"
+    }
+
+    fn description(&self) -> &'static str {
+        "Pod requests on a node add up to more than its allocatable CPU or memory"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    fn check_cluster(&self, cluster: &ClusterObjects) -> Vec<ClusterIssue> {
+        node_allocations(cluster)
+            .into_iter()
+            .filter(NodeAllocation::requests_exceed_allocatable)
+            .map(|allocation| {
+                node_issue(
+                    self.default_severity(),
+                    &allocation.node,
+                    format!(
+                        "Node {} requests exceed allocatable (cpu {}, memory {})",
+                        allocation.node,
+                        percent_label(allocation.cpu_request_percent()),
+                        percent_label(allocation.memory_request_percent())
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+struct NodeLimitsOvercommitted;
+
+impl Rule for NodeLimitsOvercommitted {
+    fn id(&self) -> &'static str {
+        "node-limits-overcommitted"
+    }
+
+    fn description(&self) -> &'static str {
+        "Pod limits on a node add up to more than its allocatable CPU or memory"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Info
+    }
+
+    fn check_cluster(&self, cluster: &ClusterObjects) -> Vec<ClusterIssue> {
+        node_allocations(cluster)
+            .into_iter()
+            .filter(NodeAllocation::limits_overcommitted)
+            .map(|allocation| {
+                node_issue(
+                    self.default_severity(),
+                    &allocation.node,
+                    format!(
+                        "Node {} limits are overcommitted (cpu {}, memory {})",
+                        allocation.node,
+                        percent_label(allocation.cpu_limit_percent()),
+                        percent_label(allocation.memory_limit_percent())
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Share of its memory limit from which a container is about to be OOM killed.
+const MEMORY_NEAR_LIMIT_PERCENT: f64 = 90.0;
+
+struct MemoryNearLimit;
+
+impl Rule for MemoryNearLimit {
+    fn id(&self) -> &'static str {
+        "memory-near-limit"
+    }
+
+    fn description(&self) -> &'static str {
+        "A container uses 90% or more of its memory limit (from the metrics of the dump)"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    fn check_cluster(&self, cluster: &ClusterObjects) -> Vec<ClusterIssue> {
+        let mut issues = Vec::new();
+
+        for (objects, pod) in pods_with_usage(cluster) {
+            let Some(pod_usage) = pod.usage else {
+                continue;
+            };
+            // Per-container metrics when available, else the pod against its total limit
+            let mut usages: Vec<(String, Quantity, Quantity)> = pod
+                .containers
+                .iter()
+                .filter_map(|container| {
+                    let usage = container.usage?;
+                    let limit = ResourceTotals::of_container(&container.resources).memory_limit;
+                    Some((format!("Container {}", container.name), usage.memory, limit))
+                })
+                .collect();
+            if usages.is_empty() && memory_limited(&pod) {
+                let limit = ResourceTotals::of_pod(&pod).memory_limit;
+                usages.push((format!("Pod {}", pod.name), pod_usage.memory, limit));
+            }
+
+            for (subject, used, limit) in usages {
+                let Some(percent) = used.percent_of(limit) else {
+                    continue;
+                };
+                if percent >= MEMORY_NEAR_LIMIT_PERCENT {
+                    issues.push(issue(
+                        self.default_severity(),
+                        &pod.name,
+                        "Pod",
+                        objects,
+                        format!(
+                            "{} uses {} of its {} memory limit ({:.0}%)",
+                            subject,
+                            used.memory_label(),
+                            limit.memory_label(),
+                            percent
+                        ),
+                    ));
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+/// Share of its request under which a pod is over-requested.
+const OVER_REQUESTED_PERCENT: f64 = 10.0;
+/// Requests smaller than these are not worth reporting.
+const OVER_REQUESTED_MIN_CPU_MILLIS: i128 = 250;
+const OVER_REQUESTED_MIN_MEMORY_BYTES: i128 = 256 * 1024 * 1024;
+
+struct OverRequested;
+
+impl Rule for OverRequested {
+    fn id(&self) -> &'static str {
+        "over-requested"
+    }
+
+    fn description(&self) -> &'static str {
+        "A running pod uses less than 10% of the CPU or memory it requests"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Info
+    }
+
+    fn check_cluster(&self, cluster: &ClusterObjects) -> Vec<ClusterIssue> {
+        let mut issues = Vec::new();
+
+        for (objects, pod) in pods_with_usage(cluster) {
+            let Some(usage) = pod.usage.filter(|_| pod.status == "Running") else {
+                continue;
+            };
+            let totals = ResourceTotals::of_pod(&pod);
+            let mut wasted = Vec::new();
+            if totals.cpu_request.millis() >= OVER_REQUESTED_MIN_CPU_MILLIS {
+                if let Some(percent) = usage.cpu.percent_of(totals.cpu_request) {
+                    if percent < OVER_REQUESTED_PERCENT {
+                        wasted.push(format!(
+                            "cpu {} of {} requested ({:.0}%)",
+                            usage.cpu.cpu_label(),
+                            totals.cpu_request.cpu_label(),
+                            percent
+                        ));
+                    }
+                }
+            }
+            if totals.memory_request.units() >= OVER_REQUESTED_MIN_MEMORY_BYTES {
+                if let Some(percent) = usage.memory.percent_of(totals.memory_request) {
+                    if percent < OVER_REQUESTED_PERCENT {
+                        wasted.push(format!(
+                            "memory {} of {} requested ({:.0}%)",
+                            usage.memory.memory_label(),
+                            totals.memory_request.memory_label(),
+                            percent
+                        ));
+                    }
+                }
+            }
+
+            if !wasted.is_empty() {
+                issues.push(issue(
+                    self.default_severity(),
+                    &pod.name,
+                    "Pod",
+                    objects,
+                    format!("Pod {} only uses {}", pod.name, wasted.join(", ")),
+                ));
+            }
+        }
+
+        issues
+    }
+}
+
+// Helpers shared by the rules
+
+/// Pods of every namespace with their usage from the metrics of the dump.
+fn pods_with_usage(cluster: &ClusterObjects) -> Vec<(&NamespaceObjects, PodInfo)> {
+    let mut pods = Vec::new();
+    for objects in &cluster.namespaces {
+        let mut namespace_pods: Vec<PodInfo> = objects.pods.iter().filter_map(parse_pod).collect();
+        attach_usage(&mut namespace_pods, &objects.namespace, &cluster.metrics);
+        pods.extend(namespace_pods.into_iter().map(|pod| (objects, pod)));
+    }
+    pods
+}
+
+/// Requests and limits of the pods of every node.
+fn node_allocations(cluster: &ClusterObjects) -> Vec<NodeAllocation> {
+    let pods: Vec<(&str, PodInfo)> = cluster
+        .namespaces
+        .iter()
+        .flat_map(|objects| {
+            objects
+                .pods
+                .iter()
+                .filter_map(parse_pod)
+                .map(|pod| (objects.namespace.as_str(), pod))
+        })
+        .collect();
+    let nodes = parse_nodes(&cluster.nodes);
+    CapacityReport::new(
+        &nodes,
+        pods.iter().map(|(namespace, pod)| (*namespace, pod)),
+    )
+    .nodes
+}
+
+/// "120% of allocatable", or "-" when the node reports no allocatable.
+fn percent_label(percent: Option<f64>) -> String {
+    match percent {
+        Some(percent) => format!("{:.0}% of allocatable", percent),
+        None => "-".to_string(),
+    }
+}
+
+fn node_issue(severity: IssueSeverity, node: &str, description: String) -> ClusterIssue {
+    ClusterIssue {
+        severity,
+        component: node.to_string(),
+        component_type: "Node".to_string(),
+        namespace: String::new(),
+        description,
+        owner: None,
+        rule: "", // Set by `RuleRegistry::run`
+    }
+}
+
+fn issue(
+    severity: IssueSeverity,
+    component: &str,
+    component_type: &str,
+    objects: &NamespaceObjects,
+    description: String,
+) -> ClusterIssue {
+    ClusterIssue {
+        severity,
+        component: component.to_string(),
+        component_type: component_type.to_string(),
+        namespace: objects.namespace.clone(),
+        description,
+        owner: None,
+        rule: "", // Set by `RuleRegistry::run`
+    }
+}
+
+fn object_name(object: &Value) -> &str {
+    object["metadata"]["name"].as_str().unwrap_or("unknown")
+}
+
+fn is_owned(object: &Value) -> bool {
+    object["metadata"]["ownerReferences"]
+        .as_array()
+        .map(|owners| !owners.is_empty())
+        .unwrap_or(false)
+}
+
+fn waiting_reason(status: &Value) -> Option<&str> {
+    status["state"]["waiting"]["reason"].as_str()
+}
+
+/// Run `check` on every container and init container status of every pod.
+fn container_status_issues<F>(
+    rule: &dyn Rule,
+    objects: &NamespaceObjects,
+    check: F,
+) -> Vec<ClusterIssue>
+where
+    F: Fn(&Value) -> Option<String>,
+{
+    let mut issues = Vec::new();
+
+    for pod in &objects.pods {
+        let statuses = ["initContainerStatuses", "containerStatuses"]
+            .iter()
+            .filter_map(|key| pod["status"][*key].as_array())
+            .flatten();
+
+        for status in statuses {
+            if let Some(description) = check(status) {
+                issues.push(issue(
+                    rule.default_severity(),
+                    object_name(pod),
+                    "Pod",
+                    objects,
+                    description,
+                ));
+            }
+        }
+    }
+
+    issues
+}
+
+/// Run `check` on every container of every pod template: the templates of the namespace's
+/// workloads, plus pods that are not owned by a workload. Checking templates rather than
+/// pods reports a misconfigured Deployment once instead of once per replica.
+fn pod_template_issues<F>(
+    rule: &dyn Rule,
+    objects: &NamespaceObjects,
+    check: F,
+) -> Vec<ClusterIssue>
+where
+    F: Fn(&Value) -> Option<String>,
+{
+    let mut templates: Vec<(&str, &str, &Value)> = Vec::new();
+
+    let workloads = [
+        ("Deployment", &objects.deployments),
+        ("StatefulSet", &objects.statefulsets),
+        ("DaemonSet", &objects.daemonsets),
+    ];
+    for (kind, items) in workloads {
+        for workload in items {
+            templates.push((
+                object_name(workload),
+                kind,
+                &workload["spec"]["template"]["spec"],
+            ));
+        }
+    }
+    // Owned ReplicaSets and Jobs come from a Deployment or CronJob template checked elsewhere
+    let standalone = [("ReplicaSet", &objects.replicasets), ("Job", &objects.jobs)];
+    for (kind, items) in standalone {
+        for workload in items.iter().filter(|workload| !is_owned(workload)) {
+            templates.push((
+                object_name(workload),
+                kind,
+                &workload["spec"]["template"]["spec"],
+            ));
+        }
+    }
+    for cronjob in &objects.cronjobs {
+        templates.push((
+            object_name(cronjob),
+            "CronJob",
+            &cronjob["spec"]["jobTemplate"]["spec"]["template"]["spec"],
+        ));
+    }
+    for pod in objects.pods.iter().filter(|pod| !is_owned(pod)) {
+        templates.push((object_name(pod), "Pod", &pod["spec"]));
+    }
+
+    let mut issues = Vec::new();
+    for (name, component_type, pod_spec) in templates {
+        let containers = pod_spec["containers"].as_array().into_iter().flatten();
+        for container in containers {
+            if let Some(description) = check(container) {
+                issues.push(issue(
+                    rule.default_severity(),
+                    name,
+                    component_type,
+                    objects,
+                    description,
+                ));
+            }
+        }
+    }
+
+    issues
+}
+
+fn is_latest_image(image: &str) -> bool {
+    // Digests pin the image regardless of the tag
+    if image.contains('@') {
+        return false;
+    }
+
+    // The tag is after the last ':' unless that ':' belongs to a registry port
+    let last_segment = image.rsplit('/').next().unwrap_or(image);
+    match last_segment.rsplit_once(':') {
+        Some((_, tag)) => tag == "latest",
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn deployments(ready: &[u64]) -> NamespaceObjects {
+        NamespaceObjects {
+            namespace: "default".to_string(),
+            deployments: ready
+                .iter()
+                .enumerate()
+                .map(|(i, ready)| {
+                    json!({
+                        "metadata": {"name": format!("web-{}", i)},
+                        "spec": {"replicas": 2},
+                        "status": {"readyReplicas": ready}
+                    })
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn severities(registry: &RuleRegistry, objects: &NamespaceObjects) -> Vec<IssueSeverity> {
+        registry
+            .check_namespace(objects)
+            .into_iter()
+            .filter(|issue| issue.rule == "deployment-replicas")
+            .map(|issue| issue.severity)
+            .collect()
+    }
+
+    fn with_severity(severity: &str) -> RuleRegistry {
+        let config =
+            RuleConfig::from_json("deployment-replicas", &json!({ "severity": severity })).unwrap();
+        RuleRegistry::from_config(&HashMap::from([(
+            "deployment-replicas".to_string(),
+            config,
+        )]))
+        .unwrap()
+    }
+
+    #[test]
+    fn rules_escalate_without_overrides() {
+        let objects = deployments(&[1, 0]);
+        assert_eq!(
+            severities(&RuleRegistry::builtin(), &objects),
+            [IssueSeverity::Warning, IssueSeverity::Critical]
+        );
+    }
+
+    #[test]
+    fn configured_severity_applies_to_escalated_issues() {
+        let objects = deployments(&[1, 0]);
+        assert_eq!(
+            severities(&with_severity("info"), &objects),
+            [IssueSeverity::Info, IssueSeverity::Info]
+        );
+        assert_eq!(
+            severities(&with_severity("critical"), &objects),
+            [IssueSeverity::Critical, IssueSeverity::Critical]
+        );
+    }
+
+    #[test]
+    fn disabled_rules_and_unknown_ids() {
+        let config =
+            RuleConfig::from_json("deployment-replicas", &json!({ "enabled": false })).unwrap();
+        let registry = RuleRegistry::from_config(&HashMap::from([(
+            "deployment-replicas".to_string(),
+            config.clone(),
+        )]))
+        .unwrap();
+        assert!(severities(&registry, &deployments(&[0])).is_empty());
+
+        let unknown = HashMap::from([("no-such-rule".to_string(), config)]);
+        assert!(RuleRegistry::from_config(&unknown).is_err());
+        assert!(RuleConfig::from_json("x", &json!({ "severity": "urgent" })).is_err());
+    }
+}
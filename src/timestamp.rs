@@ -0,0 +1,430 @@
+/// A point in time parsed from a log line, in nanoseconds since the Unix epoch (UTC).
+///
+/// klog and syslog timestamps carry no year; they are placed in `YEARLESS_YEAR` and only
+/// sort correctly against other timestamps without a year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timestamp {
+    unix_nanos: i64,
+    has_year: bool,
+}
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+/// Year of the timestamps that carry none: a leap year, so that Feb 29 is a valid date.
+const YEARLESS_YEAR: i64 = 2000;
+const SECONDS_PER_DAY: i64 = 86_400;
+/// (hour, minute, second, nanoseconds)
+type ClockTime = (u32, u32, u32, u32);
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+impl Timestamp {
+    /// None when the instant does not fit the nanosecond range (after the year 2262).
+    fn from_parts(date: (i64, u32, u32), time: ClockTime, has_year: bool) -> Option<Self> {
+        let (year, month, day) = date;
+        let (hour, minute, second, nanos) = time;
+        let seconds = days_from_civil(year, month, day)
+            .checked_mul(SECONDS_PER_DAY)?
+            .checked_add(hour as i64 * 3600 + minute as i64 * 60 + second as i64)?;
+        Some(Timestamp {
+            unix_nanos: seconds
+                .checked_mul(NANOS_PER_SECOND)?
+                .checked_add(nanos as i64)?,
+            has_year,
+        })
+    }
+
+    fn from_unix_nanos(unix_nanos: i64) -> Self {
+        Timestamp {
+            unix_nanos,
+            has_year: true,
+        }
+    }
+
+    /// "2024-01-02 15:04:05.123" (milliseconds only when present), without the year
+    /// for formats that do not carry one.
+    pub fn display(&self) -> String {
+        let seconds = self.unix_nanos.div_euclid(NANOS_PER_SECOND);
+        let nanos = self.unix_nanos.rem_euclid(NANOS_PER_SECOND);
+        let (year, month, day) = civil_from_days(seconds.div_euclid(SECONDS_PER_DAY));
+        let second_of_day = seconds.rem_euclid(SECONDS_PER_DAY);
+
+        let date = if self.has_year {
+            format!("{:04}-{:02}-{:02}", year, month, day)
+        } else {
+            format!("{:02}-{:02}", month, day)
+        };
+        let millis = if nanos > 0 {
+            format!(".{:03}", nanos / 1_000_000)
+        } else {
+            String::new()
+        };
+
+        format!(
+            "{} {:02}:{:02}:{:02}{}",
+            date,
+            second_of_day / 3600,
+            second_of_day % 3600 / 60,
+            second_of_day % 60,
+            millis
+        )
+    }
+}
+
+/// Parse a timestamp in one of the formats found in container logs:
+///
+/// - RFC 3339 / ISO 8601 with optional nanoseconds and offset: `2024-01-02T15:04:05.123456789Z`
+/// - the same with a space separator and optional `,123` milliseconds: `2024-01-02 15:04:05,123`
+/// - bracketed: `[2024-01-02 15:04:05]`
+/// - klog, with or without the severity letter: `I0102 15:04:05.000000`
+/// - syslog: `Jan  2 15:04:05`
+/// - epoch seconds, milliseconds, microseconds or nanoseconds: `1704207845123`, `1704207845.123`
+pub fn parse_timestamp(text: &str) -> Option<Timestamp> {
+    let text = text.trim();
+    let text = text
+        .strip_prefix('[')
+        .and_then(|inner| inner.strip_suffix(']'))
+        .unwrap_or(text)
+        .trim();
+
+    parse_rfc3339(text)
+        .or_else(|| parse_klog(text))
+        .or_else(|| parse_syslog(text))
+        .or_else(|| parse_epoch(text))
+}
+
+/// Timestamp at the start of a log line, made of its first one to three whitespace
+/// separated tokens (bracketed or not). Returns the rest of the line as well.
+pub fn leading_timestamp(line: &str) -> Option<(Timestamp, &str)> {
+    let trimmed = line.trim_start();
+
+    if let Some(inner) = trimmed.strip_prefix('[') {
+        let end = inner.find(']')?;
+        let timestamp = parse_timestamp(&inner[..end])?;
+        return Some((timestamp, inner[end + 1..].trim_start()));
+    }
+
+    // Byte offsets of the end of the first three tokens
+    let mut token_ends = Vec::new();
+    let mut in_token = false;
+    for (i, c) in trimmed.char_indices() {
+        if c.is_whitespace() {
+            if in_token {
+                token_ends.push(i);
+                if token_ends.len() == 3 {
+                    break;
+                }
+            }
+            in_token = false;
+        } else {
+            in_token = true;
+        }
+    }
+    if in_token && token_ends.len() < 3 {
+        token_ends.push(trimmed.len());
+    }
+
+    // Longest prefix first, so "2024-01-02 15:04:05" wins over "2024-01-02"
+    token_ends.iter().rev().find_map(|&end| {
+        parse_timestamp(&trimmed[..end]).map(|timestamp| (timestamp, trimmed[end..].trim_start()))
+    })
+}
+
+fn parse_rfc3339(text: &str) -> Option<Timestamp> {
+    let bytes = text.as_bytes();
+    if bytes.len() < 19 || !matches!(bytes[10], b'T' | b't' | b' ') {
+        return None;
+    }
+
+    let date = parse_date(text.get(..10)?)?;
+    let (time, rest) = parse_clock(text.get(11..)?)?;
+
+    let offset_seconds = match rest {
+        "" | "Z" | "z" => 0,
+        _ => parse_offset(rest)?,
+    };
+
+    let timestamp = Timestamp::from_parts(date, time, true)?;
+    Some(Timestamp::from_unix_nanos(
+        timestamp
+            .unix_nanos
+            .checked_sub(offset_seconds.checked_mul(NANOS_PER_SECOND)?)?,
+    ))
+}
+
+fn parse_klog(text: &str) -> Option<Timestamp> {
+    // I0102 15:04:05.000000 (the severity letter is optional)
+    let text = match text.chars().next()? {
+        'I' | 'W' | 'E' | 'F' => &text[1..],
+        _ => text,
+    };
+    let (date, clock) = text.split_once(' ')?;
+    if date.len() != 4 || !date.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let month = date[..2].parse().ok()?;
+    let day = date[2..].parse().ok()?;
+    let (time, rest) = parse_clock(clock.trim_start())?;
+    if !rest.is_empty() || !valid_date(YEARLESS_YEAR, month, day) {
+        return None;
+    }
+    Timestamp::from_parts((YEARLESS_YEAR, month, day), time, false)
+}
+
+fn parse_syslog(text: &str) -> Option<Timestamp> {
+    // Jan  2 15:04:05
+    let mut parts = text.split_whitespace();
+    let month_name = parts.next()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+    let (time, rest) = parse_clock(parts.next()?)?;
+    if !rest.is_empty() || parts.next().is_some() {
+        return None;
+    }
+
+    let month = MONTHS.iter().position(|m| *m == month_name)? as u32 + 1;
+    if !valid_date(YEARLESS_YEAR, month, day) {
+        return None;
+    }
+    Timestamp::from_parts((YEARLESS_YEAR, month, day), time, false)
+}
+
+fn parse_epoch(text: &str) -> Option<Timestamp> {
+    let (whole, fraction) = match text.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (text, None),
+    };
+    if whole.is_empty() || !whole.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let value: i64 = whole.parse().ok()?;
+    let nanos = match (whole.len(), fraction) {
+        (10, None) => value.checked_mul(NANOS_PER_SECOND)?,
+        (10, Some(fraction)) => value
+            .checked_mul(NANOS_PER_SECOND)?
+            .checked_add(parse_fraction_nanos(fraction)? as i64)?,
+        (13, None) => value.checked_mul(1_000_000)?,
+        (16, None) => value.checked_mul(1_000)?,
+        (19, None) => value,
+        _ => return None,
+    };
+    Some(Timestamp::from_unix_nanos(nanos))
+}
+
+/// `YYYY-MM-DD`
+fn parse_date(text: &str) -> Option<(i64, u32, u32)> {
+    let bytes = text.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    let year = parse_digits(text.get(..4)?)? as i64;
+    let month = parse_digits(text.get(5..7)?)?;
+    let day = parse_digits(text.get(8..10)?)?;
+    valid_date(year, month, day).then_some((year, month, day))
+}
+
+/// `HH:MM:SS` with an optional `.fraction` or `,fraction`. Returns the time and what follows.
+fn parse_clock(text: &str) -> Option<(ClockTime, &str)> {
+    let bytes = text.as_bytes();
+    if bytes.len() < 8 || bytes[2] != b':' || bytes[5] != b':' {
+        return None;
+    }
+    let hour = parse_digits(text.get(..2)?)?;
+    let minute = parse_digits(text.get(3..5)?)?;
+    let second = parse_digits(text.get(6..8)?)?;
+    // 60 is a leap second
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    let mut rest = &text[8..];
+    let mut nanos = 0;
+    if let Some(fraction) = rest.strip_prefix('.').or_else(|| rest.strip_prefix(',')) {
+        let digits = fraction.bytes().take_while(|b| b.is_ascii_digit()).count();
+        nanos = parse_fraction_nanos(&fraction[..digits])?;
+        rest = &fraction[digits..];
+    }
+
+    Some(((hour, minute, second, nanos), rest))
+}
+
+/// `+02:00`, `-0700`
+fn parse_offset(text: &str) -> Option<i64> {
+    let sign = match text.as_bytes().first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let digits = text[1..].replace(':', "");
+    if digits.len() != 4 {
+        return None;
+    }
+    let hours = parse_digits(digits.get(..2)?)? as i64;
+    let minutes = parse_digits(digits.get(2..)?)? as i64;
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Up to nine fractional digits as nanoseconds; extra digits are ignored.
+fn parse_fraction_nanos(digits: &str) -> Option<u32> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let significant = &digits[..digits.len().min(9)];
+    let value: u32 = significant.parse().ok()?;
+    Some(value * 10u32.pow(9 - significant.len() as u32))
+}
+
+fn parse_digits(text: &str) -> Option<u32> {
+    if text.bytes().all(|b| b.is_ascii_digit()) {
+        text.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn valid_date(year: i64, month: u32, day: u32) -> bool {
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => return false,
+    };
+    day >= 1 && day <= days_in_month
+}
+
+// Howard Hinnant's days_from_civil / civil_from_days
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn display(text: &str) -> String {
+        parse_timestamp(text).unwrap().display()
+    }
+
+    #[test]
+    fn parses_rfc3339_variants() {
+        assert_eq!(display("2024-01-02T15:04:05Z"), "2024-01-02 15:04:05");
+        assert_eq!(
+            display("2024-01-02T15:04:05.123456789Z"),
+            "2024-01-02 15:04:05.123"
+        );
+        assert_eq!(
+            display("2024-01-02 15:04:05,250"),
+            "2024-01-02 15:04:05.250"
+        );
+        assert_eq!(display("2024-01-02T17:04:05+02:00"), "2024-01-02 15:04:05");
+        assert_eq!(display("2024-01-02T08:04:05-0700"), "2024-01-02 15:04:05");
+        assert_eq!(display("[2024-01-02 15:04:05]"), "2024-01-02 15:04:05");
+    }
+
+    #[test]
+    fn rejects_invalid_dates() {
+        for text in [
+            "2024-13-02T15:04:05Z",
+            "2023-02-29T15:04:05Z",
+            "2024-01-02T24:00:00Z",
+            "2024-01-02",
+        ] {
+            assert!(parse_timestamp(text).is_none(), "{}", text);
+        }
+        assert!(parse_timestamp("2024-02-29T15:04:05Z").is_some());
+    }
+
+    #[test]
+    fn parses_yearless_formats() {
+        assert_eq!(display("I0102 15:04:05.000000"), "01-02 15:04:05");
+        assert_eq!(display("0102 15:04:05"), "01-02 15:04:05");
+        assert_eq!(display("Jan  2 15:04:05"), "01-02 15:04:05");
+        assert!(parse_timestamp("Foo 2 15:04:05").is_none());
+        assert!(parse_timestamp("I0230 15:04:05").is_none());
+    }
+
+    #[test]
+    fn accepts_feb_29_without_a_year() {
+        assert_eq!(display("E0229 23:59:59.5"), "02-29 23:59:59.500");
+        assert_eq!(display("Feb 29 00:00:00"), "02-29 00:00:00");
+        assert!(parse_timestamp("Feb 29 00:00:00") < parse_timestamp("Mar  1 00:00:00"));
+    }
+
+    #[test]
+    fn parses_epoch_timestamps() {
+        let seconds = parse_timestamp("1704207845").unwrap();
+        assert_eq!(seconds.display(), "2024-01-02 15:04:05");
+        assert_eq!(parse_timestamp("1704207845000"), Some(seconds));
+        assert_eq!(parse_timestamp("1704207845000000"), Some(seconds));
+        assert_eq!(parse_timestamp("1704207845000000000"), Some(seconds));
+        assert_eq!(display("1704207845.123"), "2024-01-02 15:04:05.123");
+        assert!(parse_timestamp("17042078450").is_none());
+    }
+
+    #[test]
+    fn epoch_overflow_is_not_a_timestamp() {
+        assert!(parse_timestamp("9999999999999").is_none());
+        assert!(parse_timestamp("9999999999999999").is_none());
+        assert!(parse_timestamp("9999999999999999999").is_none());
+        assert!(leading_timestamp("9999999999999 hello").is_none());
+    }
+
+    #[test]
+    fn dates_past_the_nanosecond_range_are_not_timestamps() {
+        assert!(parse_timestamp("9999-12-31T23:59:59Z").is_none());
+        assert!(parse_timestamp("9999-12-31T23:59:59.999999999+14:00").is_none());
+        assert!(leading_timestamp("9999-12-31 23:59:59 INFO far future").is_none());
+        assert_eq!(display("2262-04-11T23:47:16Z"), "2262-04-11 23:47:16");
+    }
+
+    #[test]
+    fn offsets_near_the_limit_do_not_overflow() {
+        assert!(parse_timestamp("2262-04-11T23:47:16-01:00").is_none());
+        assert_eq!(display("2262-04-11T22:47:16-01:00"), "2262-04-11 23:47:16");
+        assert_eq!(display("1677-09-21T00:12:44Z"), "1677-09-21 00:12:44");
+        assert!(parse_timestamp("1677-09-21T00:12:44+01:00").is_none());
+    }
+
+    #[test]
+    fn finds_the_longest_leading_timestamp() {
+        let (timestamp, rest) = leading_timestamp("2024-01-02 15:04:05 INFO started").unwrap();
+        assert_eq!(timestamp.display(), "2024-01-02 15:04:05");
+        assert_eq!(rest, "INFO started");
+
+        let (timestamp, rest) = leading_timestamp("Jan  2 15:04:05 host sshd: ok").unwrap();
+        assert_eq!(timestamp.display(), "01-02 15:04:05");
+        assert_eq!(rest, "host sshd: ok");
+
+        let (_, rest) = leading_timestamp("[2024-01-02T15:04:05Z] boom").unwrap();
+        assert_eq!(rest, "boom");
+        assert!(leading_timestamp("no timestamp here").is_none());
+    }
+}
@@ -0,0 +1,255 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::quantity::Quantity;
+use crate::resources::NodeAllocation;
+
+/// Requested share of allocatable from which a node is shown in yellow.
+const HIGH_REQUEST_PERCENT: f64 = 80.0;
+
+pub fn draw_capacity(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Length(3), // Cluster totals
+            Constraint::Min(0),    // Nodes and namespaces
+            Constraint::Length(3), // Instructions
+        ])
+        .split(f.size());
+
+    let title = Paragraph::new("📊 Capacity (requests and limits vs allocatable)")
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL))
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+    f.render_widget(title, chunks[0]);
+
+    let Some(ref analysis) = app.cluster_analysis else {
+        let loading = Paragraph::new("Loading cluster analysis...")
+            .block(Block::default().borders(Borders::ALL).title("Capacity"))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::Yellow));
+        f.render_widget(loading, chunks[2]);
+        return;
+    };
+    let capacity = &analysis.capacity;
+
+    // Cluster totals
+    let (totals, cpu, memory) = capacity.cluster_totals();
+    let summary = if capacity.nodes.is_empty() {
+        format!(
+            "CPU: {} requested, {} limit | Memory: {} requested, {} limit | No nodes in this dump",
+            totals.cpu_request.cpu_label(),
+            totals.cpu_limit.cpu_label(),
+            totals.memory_request.memory_label(),
+            totals.memory_limit.memory_label()
+        )
+    } else {
+        format!(
+            "CPU: requests {} | limits {}   Memory: requests {} | limits {}",
+            usage_cell(totals.cpu_request, cpu, Quantity::cpu_label),
+            usage_cell(totals.cpu_limit, cpu, Quantity::cpu_label),
+            usage_cell(totals.memory_request, memory, Quantity::memory_label),
+            usage_cell(totals.memory_limit, memory, Quantity::memory_label)
+        )
+    };
+    let summary_widget = Paragraph::new(summary)
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).title("Cluster"));
+    f.render_widget(summary_widget, chunks[1]);
+
+    // Nodes, then namespaces; only their rows are selectable
+    let selected_index = app.list_state.selected().unwrap_or(0);
+    let header_style = Style::default()
+        .fg(Color::Gray)
+        .add_modifier(Modifier::ITALIC);
+    let mut list_items = Vec::new();
+    let mut selected_display_index = None;
+    let mut row_index = 0;
+    let mut push_row = |list_items: &mut Vec<ListItem>, text: String, color: Color| {
+        let style = if row_index == selected_index {
+            selected_display_index = Some(list_items.len());
+            Style::default()
+                .bg(Color::Blue)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(color)
+        };
+        list_items.push(ListItem::new(text).style(style));
+        row_index += 1;
+    };
+
+    if !capacity.nodes.is_empty() {
+        list_items.push(ListItem::new("🖥️ Nodes").style(header_style));
+        list_items.push(
+            ListItem::new(row(
+                "NODE",
+                [
+                    "CPU REQUESTS",
+                    "CPU LIMITS",
+                    "MEMORY REQUESTS",
+                    "MEMORY LIMITS",
+                    "PODS",
+                    "USAGE (CPU/MEMORY)",
+                ],
+            ))
+            .style(header_style),
+        );
+        for node in &capacity.nodes {
+            let text = row(
+                &node.node,
+                [
+                    usage_cell(
+                        node.totals.cpu_request,
+                        node.allocatable_cpu,
+                        Quantity::cpu_label,
+                    ),
+                    usage_cell(
+                        node.totals.cpu_limit,
+                        node.allocatable_cpu,
+                        Quantity::cpu_label,
+                    ),
+                    usage_cell(
+                        node.totals.memory_request,
+                        node.allocatable_memory,
+                        Quantity::memory_label,
+                    ),
+                    usage_cell(
+                        node.totals.memory_limit,
+                        node.allocatable_memory,
+                        Quantity::memory_label,
+                    ),
+                    format!("{}/{}", node.pods, node.allocatable_pods.units()),
+                    node.usage
+                        .map(|usage| {
+                            format!(
+                                "{}/{}",
+                                percent(usage.cpu.percent_of(node.allocatable_cpu)),
+                                percent(usage.memory.percent_of(node.allocatable_memory))
+                            )
+                        })
+                        .unwrap_or_else(|| "-".to_string()),
+                ],
+            );
+            push_row(&mut list_items, text, node_color(node));
+        }
+        list_items.push(ListItem::new(""));
+    }
+
+    list_items.push(ListItem::new("📁 Namespaces").style(header_style));
+    list_items.push(
+        ListItem::new(row(
+            "NAMESPACE",
+            [
+                "CPU REQUESTS",
+                "CPU LIMITS",
+                "MEMORY REQUESTS",
+                "MEMORY LIMITS",
+                "PODS",
+                "USAGE (CPU/MEMORY)",
+            ],
+        ))
+        .style(header_style),
+    );
+    for namespace in &capacity.namespaces {
+        let pods = if namespace.pods_without_limits > 0 {
+            format!("{} ({}⚠️)", namespace.pods, namespace.pods_without_limits)
+        } else {
+            namespace.pods.to_string()
+        };
+        let text = row(
+            &namespace.namespace,
+            [
+                namespace.totals.cpu_request.cpu_label(),
+                namespace.totals.cpu_limit.cpu_label(),
+                namespace.totals.memory_request.memory_label(),
+                namespace.totals.memory_limit.memory_label(),
+                pods,
+                namespace
+                    .usage
+                    .map(|usage| {
+                        format!("{}/{}", usage.cpu.cpu_label(), usage.memory.memory_label())
+                    })
+                    .unwrap_or_else(|| "-".to_string()),
+            ],
+        );
+        let color = if namespace.pods_without_limits > 0 {
+            Color::Yellow
+        } else {
+            Color::White
+        };
+        push_row(&mut list_items, text, color);
+    }
+
+    // Track the selected row so long lists scroll with the selection
+    let mut scroll_state = ListState::default();
+    scroll_state.select(selected_display_index);
+    let list = List::new(list_items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Allocation (⚠️ pods without limits | Select a node to view it)"),
+    );
+    f.render_stateful_widget(list, chunks[2], &mut scroll_state);
+
+    let instructions = Paragraph::new("↑↓ Navigate | Enter: View Node | ESC: Back | q: Quit")
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL))
+        .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[3]);
+}
+
+fn row<S: AsRef<str>>(name: &str, cells: [S; 6]) -> String {
+    let [cpu_request, cpu_limit, memory_request, memory_limit, pods, usage] = cells;
+    format!(
+        "  {:<20} {:<18} {:<18} {:<20} {:<20} {:<10} {}",
+        name,
+        cpu_request.as_ref(),
+        cpu_limit.as_ref(),
+        memory_request.as_ref(),
+        memory_limit.as_ref(),
+        pods.as_ref(),
+        usage.as_ref()
+    )
+}
+
+fn percent(percent: Option<f64>) -> String {
+    match percent {
+        Some(percent) => format!("{:.0}%", percent),
+        None => "-".to_string(),
+    }
+}
+
+/// `2500m/3920m (64%)`
+fn usage_cell(used: Quantity, total: Quantity, label: fn(&Quantity) -> String) -> String {
+    match used.percent_of(total) {
+        Some(percent) => format!("{}/{} ({:.0}%)", label(&used), label(&total), percent),
+        None => label(&used),
+    }
+}
+
+/// Red when requests exceed allocatable, yellow when limits are overcommitted or the
+/// node is nearly full.
+fn node_color(node: &NodeAllocation) -> Color {
+    let nearly_full = [node.cpu_request_percent(), node.memory_request_percent()]
+        .into_iter()
+        .flatten()
+        .any(|percent| percent >= HIGH_REQUEST_PERCENT);
+    if node.requests_exceed_allocatable() {
+        Color::Red
+    } else if node.limits_overcommitted() || nearly_full {
+        Color::Yellow
+    } else {
+        Color::Green
+    }
+}
@@ -6,6 +6,8 @@ use ratatui::{
 };
 
 use crate::app::App;
+use crate::kubernetes::NamespaceAnalysis;
+use crate::workloads::WorkloadKind;
 
 pub fn draw_cluster_analysis(f: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
@@ -19,14 +21,17 @@ pub fn draw_cluster_analysis(f: &mut Frame, app: &mut App) {
         .split(f.size());
 
     // Title
-    let title_widget = Paragraph::new("🔍 Cluster Analysis")
-        .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL))
-        .style(
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        );
+    let title_widget = Paragraph::new(format!(
+        "🔍 Cluster Analysis ({})",
+        app.dump.layout().label()
+    ))
+    .alignment(Alignment::Center)
+    .block(Block::default().borders(Borders::ALL))
+    .style(
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    );
     f.render_widget(title_widget, chunks[0]);
 
     // Analysis content
@@ -35,15 +40,34 @@ pub fn draw_cluster_analysis(f: &mut Frame, app: &mut App) {
             let mut display_items = Vec::new();
             let mut selectable_items = Vec::new();
 
+            // Node summary (not selectable)
+            if !analysis.nodes.is_empty() {
+                display_items.push((
+                    format!(
+                        "🖥️ Nodes: {} ({} NotReady, {} cordoned)",
+                        analysis.nodes.len(),
+                        analysis.nodes.iter().filter(|node| !node.ready).count(),
+                        analysis
+                            .nodes
+                            .iter()
+                            .filter(|node| node.unschedulable)
+                            .count()
+                    ),
+                    false,
+                ));
+                display_items.push(("".to_string(), false));
+            }
+
             // Create display items and track selectable ones
             for ns_analysis in &analysis.namespaces {
                 // Namespace header (not selectable)
                 display_items.push((
                     format!(
-                        "📁 Namespace: {} ({} pods, {} deployments)",
+                        "📁 Namespace: {} ({} pods, {} deployments, {} other workloads)",
                         ns_analysis.name,
                         ns_analysis.pods.len(),
-                        ns_analysis.deployments.len()
+                        ns_analysis.deployments.len(),
+                        ns_analysis.workloads.total()
                     ),
                     false, // not selectable
                 ));
@@ -71,19 +95,41 @@ pub fn draw_cluster_analysis(f: &mut Frame, app: &mut App) {
                     };
                     display_items.push((
                         format!(
-                            "  {} Deployment: {} ({}/{})",
+                            "  {} Deployment: {} ({}/{}){}",
                             status_icon,
                             deployment.name,
                             deployment.ready_replicas,
-                            deployment.desired_replicas
+                            deployment.desired_replicas,
+                            issue_count_suffix(ns_analysis, "Deployment", &deployment.name)
                         ),
                         true, // selectable
                     ));
                     selectable_items.push((deployment.name.clone(), "Deployment".to_string()));
                 }
 
+                // Other workload entries (selectable)
+                for kind in WorkloadKind::ALL {
+                    for summary in ns_analysis.workloads.summaries(kind) {
+                        display_items.push((
+                            format!(
+                                "  {} {}: {} ({}){}",
+                                summary.health.icon(),
+                                kind.label(),
+                                summary.name,
+                                summary.status,
+                                issue_count_suffix(ns_analysis, kind.label(), &summary.name)
+                            ),
+                            true, // selectable
+                        ));
+                        selectable_items.push((summary.name, kind.label().to_string()));
+                    }
+                }
+
                 // Add empty line between namespaces (not selectable)
-                if !ns_analysis.pods.is_empty() || !ns_analysis.deployments.is_empty() {
+                if !ns_analysis.pods.is_empty()
+                    || !ns_analysis.deployments.is_empty()
+                    || ns_analysis.workloads.total() > 0
+                {
                     display_items.push(("".to_string(), false));
                 }
             }
@@ -175,3 +221,12 @@ fn calculate_selectable_index(
     }
     0
 }
+
+/// " 🚨 3 issues" for a workload with issues of its own or of the pods it owns.
+fn issue_count_suffix(ns_analysis: &NamespaceAnalysis, kind: &str, name: &str) -> String {
+    match ns_analysis.workload_issues(kind, name).len() {
+        0 => String::new(),
+        1 => " 🚨 1 issue".to_string(),
+        count => format!(" 🚨 {} issues", count),
+    }
+}
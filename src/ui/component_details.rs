@@ -1,13 +1,19 @@
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
-    text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, Paragraph},
     Frame,
 };
 
 use crate::app::App;
-use crate::kubernetes::{DeploymentInfo, PodInfo};
+use crate::events::{events_for, EventInfo};
+use crate::kubernetes::{ClusterIssue, DeploymentInfo, PodInfo};
+use crate::metrics::usage_against;
+use crate::networking::{IngressInfo, NamespaceNetworking, ServiceInfo};
+use crate::ownership::OwnershipGraph;
+use crate::quantity::Quantity;
+use crate::resources::{cpu_limited, lacks_limits, memory_limited, ResourceTotals};
+use crate::workloads::{NamespaceWorkloads, WorkloadHealth, WorkloadKind, WorkloadSummary};
 
 pub fn draw_component_details(f: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
@@ -37,13 +43,19 @@ pub fn draw_component_details(f: &mut Frame, app: &mut App) {
         );
     f.render_widget(title_widget, chunks[0]);
 
-    // Details content
+    // Details content, scrolled with ↑↓
     if let Some((ref name, ref comp_type)) = app.selected_component {
-        match comp_type.as_str() {
-            "Pod" => draw_pod_details(f, chunks[1], app, name),
-            "Deployment" => draw_deployment_details(f, chunks[1], app, name),
-            _ => draw_generic_details(f, chunks[1], name, comp_type),
-        }
+        let details = match comp_type.as_str() {
+            "Pod" => pod_details(app, name),
+            "Deployment" => deployment_details(app, name),
+            "Service" | "Ingress" => networking_details(app, comp_type, name),
+            _ => match WorkloadKind::from_label(comp_type) {
+                Some(kind) => workload_details(app, kind, name),
+                None => generic_details(app, name, comp_type),
+            },
+        };
+        app.details_max_scroll =
+            draw_scrollable_details(f, chunks[1], details, &mut app.details_scroll);
     } else {
         let empty = Paragraph::new("No component selected")
             .block(Block::default().borders(Borders::ALL))
@@ -59,24 +71,24 @@ pub fn draw_component_details(f: &mut Frame, app: &mut App) {
     f.render_widget(instructions, chunks[2]);
 }
 
-fn draw_pod_details(f: &mut Frame, area: ratatui::layout::Rect, app: &App, pod_name: &str) {
+fn pod_details(app: &App, pod_name: &str) -> Vec<String> {
     // First try to find in the loaded pods (from namespace browsing)
     if let Some(pod) = app.pods.iter().find(|p| p.name == pod_name) {
         let namespace = app.selected_namespace.as_deref().unwrap_or("unknown");
-        let details = create_pod_detail_lines(pod, namespace);
-        let mut scroll_state = ratatui::widgets::ListState::default();
-        draw_scrollable_details(f, area, details, &mut scroll_state);
-        return;
+        let details = create_pod_detail_lines(pod, namespace, &app.ownership);
+        return details;
     }
 
     // Fallback: try to find in cluster analysis data
     if let Some(analysis) = &app.cluster_analysis {
         for namespace_analysis in &analysis.namespaces {
             if let Some(pod) = namespace_analysis.pods.iter().find(|p| p.name == pod_name) {
-                let details = create_pod_detail_lines(pod, &namespace_analysis.name);
-                let mut scroll_state = ratatui::widgets::ListState::default();
-                draw_scrollable_details(f, area, details, &mut scroll_state);
-                return;
+                let details = create_pod_detail_lines(
+                    pod,
+                    &namespace_analysis.name,
+                    &namespace_analysis.ownership,
+                );
+                return details;
             }
         }
     }
@@ -89,23 +101,17 @@ fn draw_pod_details(f: &mut Frame, area: ratatui::layout::Rect, app: &App, pod_n
         format!("Current namespace: {:?}", app.selected_namespace),
         format!("Loaded pods count: {}", app.pods.len()),
     ];
-    let mut scroll_state = ratatui::widgets::ListState::default();
-    draw_scrollable_details(f, area, details, &mut scroll_state);
+    details
 }
 
-fn draw_deployment_details(
-    f: &mut Frame,
-    area: ratatui::layout::Rect,
-    app: &App,
-    deployment_name: &str,
-) {
+fn deployment_details(app: &App, deployment_name: &str) -> Vec<String> {
     // First try to find in the loaded deployments (from namespace browsing)
     if let Some(deployment) = app.deployments.iter().find(|d| d.name == deployment_name) {
         let namespace = app.selected_namespace.as_deref().unwrap_or("unknown");
-        let details = create_deployment_detail_lines(deployment, namespace);
-        let mut scroll_state = ratatui::widgets::ListState::default();
-        draw_scrollable_details(f, area, details, &mut scroll_state);
-        return;
+        let pods = owned_pods(&app.ownership, &app.pods, "Deployment", deployment_name);
+        let issues = rolled_up_issues(app, namespace, "Deployment", deployment_name);
+        let details = create_deployment_detail_lines(deployment, namespace, &pods, &issues);
+        return details;
     }
 
     // Fallback: try to find in cluster analysis data
@@ -116,10 +122,15 @@ fn draw_deployment_details(
                 .iter()
                 .find(|d| d.name == deployment_name)
             {
-                let details = create_deployment_detail_lines(deployment, &namespace_analysis.name);
-                let mut scroll_state = ratatui::widgets::ListState::default();
-                draw_scrollable_details(f, area, details, &mut scroll_state);
-                return;
+                let pods = namespace_analysis.pods_of("Deployment", deployment_name);
+                let issues = namespace_analysis.workload_issues("Deployment", deployment_name);
+                let details = create_deployment_detail_lines(
+                    deployment,
+                    &namespace_analysis.name,
+                    &pods,
+                    &issues,
+                );
+                return details;
             }
         }
     }
@@ -132,27 +143,135 @@ fn draw_deployment_details(
         format!("Current namespace: {:?}", app.selected_namespace),
         format!("Loaded deployments count: {}", app.deployments.len()),
     ];
-    let mut scroll_state = ratatui::widgets::ListState::default();
-    draw_scrollable_details(f, area, details, &mut scroll_state);
+    details
 }
 
-fn draw_generic_details(f: &mut Frame, area: ratatui::layout::Rect, name: &str, comp_type: &str) {
-    let details = vec![
+fn workload_details(app: &App, kind: WorkloadKind, workload_name: &str) -> Vec<String> {
+    // First try the loaded namespace, then the cluster analysis data
+    let namespace = app.selected_namespace.as_deref().unwrap_or("unknown");
+    let create_lines = |workloads: &NamespaceWorkloads,
+                        ownership: &OwnershipGraph,
+                        pods: &[PodInfo],
+                        events: &[EventInfo],
+                        namespace: &str| {
+        create_workload_detail_lines(workloads, kind, workload_name, namespace).map(|mut lines| {
+            let pods = owned_pods(ownership, pods, kind.label(), workload_name);
+            lines.extend(owned_pod_lines(&pods));
+            lines.extend(issue_lines(&rolled_up_issues(
+                app,
+                namespace,
+                kind.label(),
+                workload_name,
+            )));
+            lines.extend(event_lines(&events_for(
+                events,
+                kind.label(),
+                workload_name,
+            )));
+            lines
+        })
+    };
+    let mut details = create_lines(
+        &app.workloads,
+        &app.ownership,
+        &app.pods,
+        &app.events,
+        namespace,
+    );
+
+    if details.is_none() {
+        if let Some(analysis) = &app.cluster_analysis {
+            details = analysis.namespaces.iter().find_map(|namespace_analysis| {
+                create_lines(
+                    &namespace_analysis.workloads,
+                    &namespace_analysis.ownership,
+                    &namespace_analysis.pods,
+                    &namespace_analysis.events,
+                    &namespace_analysis.name,
+                )
+            });
+        }
+    }
+
+    // Final fallback
+    let details = details.unwrap_or_else(|| {
+        vec![
+            format!("{} not found in loaded data", kind.label()),
+            "".to_string(),
+            format!("Searched for {}: {}", kind.label(), workload_name),
+            format!("Current namespace: {:?}", app.selected_namespace),
+        ]
+    });
+    details
+}
+
+fn networking_details(app: &App, comp_type: &str, name: &str) -> Vec<String> {
+    let create_lines = |networking: &NamespaceNetworking,
+                        pods: &[PodInfo],
+                        events: &[EventInfo],
+                        namespace: &str| {
+        let lines = if comp_type == "Service" {
+            networking
+                .service(name)
+                .map(|service| create_service_detail_lines(service, networking, pods, namespace))
+        } else {
+            networking
+                .ingresses
+                .iter()
+                .find(|ingress| ingress.name == name)
+                .map(|ingress| create_ingress_detail_lines(ingress, networking, namespace))
+        };
+        lines.map(|mut lines| {
+            lines.extend(event_lines(&events_for(events, comp_type, name)));
+            lines
+        })
+    };
+
+    // First try the loaded namespace, then the cluster analysis data
+    let namespace = app.selected_namespace.as_deref().unwrap_or("unknown");
+    let mut details = create_lines(&app.networking, &app.pods, &app.events, namespace);
+
+    if details.is_none() {
+        if let Some(analysis) = &app.cluster_analysis {
+            details = analysis.namespaces.iter().find_map(|namespace_analysis| {
+                create_lines(
+                    &namespace_analysis.networking,
+                    &namespace_analysis.pods,
+                    &namespace_analysis.events,
+                    &namespace_analysis.name,
+                )
+            });
+        }
+    }
+
+    // Final fallback
+    let details = details.unwrap_or_else(|| {
+        vec![
+            format!("{} not found in loaded data", comp_type),
+            "".to_string(),
+            format!("Searched for {}: {}", comp_type, name),
+            format!("Current namespace: {:?}", app.selected_namespace),
+        ]
+    });
+    details
+}
+
+fn generic_details(app: &App, name: &str, comp_type: &str) -> Vec<String> {
+    let mut details = vec![
         format!("Component Type: {}", comp_type),
         format!("Name: {}", name),
         "".to_string(),
         "No detailed information available for this component type.".to_string(),
     ];
-
-    draw_scrollable_details(
-        f,
-        area,
-        details,
-        &mut ratatui::widgets::ListState::default(),
-    );
+    details.extend(event_lines(&events_for(&app.events, comp_type, name)));
+    details
 }
 
-fn create_pod_detail_lines(pod: &PodInfo, namespace: &str) -> Vec<String> {
+fn create_pod_detail_lines(
+    pod: &PodInfo,
+    namespace: &str,
+    ownership: &OwnershipGraph,
+) -> Vec<String> {
     let mut lines = Vec::new();
 
     // Header
@@ -162,6 +281,14 @@ fn create_pod_detail_lines(pod: &PodInfo, namespace: &str) -> Vec<String> {
     // Basic info
     lines.push(format!("Name: {}", pod.name));
     lines.push(format!("Namespace: {}", namespace));
+    lines.push(format!(
+        "Node: {}",
+        pod.node_name.as_deref().unwrap_or("Not scheduled")
+    ));
+    lines.push(format!(
+        "Owner: {}",
+        owner_label(ownership, "Pod", &pod.name)
+    ));
 
     // Status
     let status_icon = if pod.status == "Running" && pod.ready {
@@ -180,16 +307,57 @@ fn create_pod_detail_lines(pod: &PodInfo, namespace: &str) -> Vec<String> {
     lines.push("".to_string());
     lines.push("🔧 RESOURCE INFORMATION".to_string());
 
-    if let Some(ref cpu) = pod.cpu_usage {
-        lines.push(format!("CPU Usage: {}", cpu));
-    } else {
-        lines.push("CPU Usage: Not available".to_string());
+    let totals = ResourceTotals::of_pod(pod);
+    lines.push(format!(
+        "CPU: {} requested | {} limit",
+        optional_quantity(totals.cpu_request, Quantity::cpu_label),
+        optional_quantity(totals.cpu_limit, Quantity::cpu_label)
+    ));
+    lines.push(format!(
+        "Memory: {} requested | {} limit",
+        optional_quantity(totals.memory_request, Quantity::memory_label),
+        optional_quantity(totals.memory_limit, Quantity::memory_label)
+    ));
+    if lacks_limits(pod) {
+        lines.push("⚠️ Some containers have no CPU or memory limit".to_string());
     }
 
-    if let Some(ref memory) = pod.memory_usage {
-        lines.push(format!("Memory Usage: {}", memory));
-    } else {
-        lines.push("Memory Usage: Not available".to_string());
+    // Limits only count when every container sets one
+    match pod.usage {
+        Some(usage) => {
+            let cpu_limit = if cpu_limited(pod) {
+                totals.cpu_limit
+            } else {
+                Quantity::ZERO
+            };
+            let memory_limit = if memory_limited(pod) {
+                totals.memory_limit
+            } else {
+                Quantity::ZERO
+            };
+            lines.push(format!(
+                "CPU Usage: {}",
+                usage_against(
+                    usage.cpu,
+                    totals.cpu_request,
+                    cpu_limit,
+                    Quantity::cpu_label
+                )
+            ));
+            lines.push(format!(
+                "Memory Usage: {}",
+                usage_against(
+                    usage.memory,
+                    totals.memory_request,
+                    memory_limit,
+                    Quantity::memory_label
+                )
+            ));
+        }
+        None => {
+            lines.push("CPU Usage: Not available".to_string());
+            lines.push("Memory Usage: Not available".to_string());
+        }
     }
 
     if let Some(ref restart_count) = pod.restart_count {
@@ -202,12 +370,60 @@ fn create_pod_detail_lines(pod: &PodInfo, namespace: &str) -> Vec<String> {
 
     // Container info
     lines.push("".to_string());
-    lines.push("📦 CONTAINER INFORMATION".to_string());
+    lines.push(format!("📦 CONTAINERS ({})", pod.containers.len()));
 
-    if let Some(ref image) = pod.image {
-        lines.push(format!("Image: {}", image));
+    if pod.containers.is_empty() {
+        lines.push("No container information available".to_string());
     } else {
-        lines.push("Image: Not available".to_string());
+        lines.push(format!(
+            "  {:<10} {:<24} {:<32} {:<6} {:<9} {}",
+            "KIND", "NAME", "STATE", "READY", "RESTARTS", "LAST TERMINATION"
+        ));
+        for container in &pod.containers {
+            let last_termination = match (&container.last_termination_reason, container.exit_code) {
+                (Some(reason), Some(code)) => format!("{} (exit {})", reason, code),
+                (Some(reason), None) => reason.clone(),
+                (None, Some(code)) => format!("exit {}", code),
+                (None, None) => "-".to_string(),
+            };
+            lines.push(format!(
+                "  {:<10} {:<24} {:<32} {:<6} {:<9} {}",
+                container.kind.label(),
+                container.name,
+                container.state,
+                if container.ready { "yes" } else { "no" },
+                container.restart_count,
+                last_termination
+            ));
+
+            let resources = &container.resources;
+            lines.push(format!(
+                "    image: {} | cpu: {}/{} | memory: {}/{} (request/limit)",
+                container.image.as_deref().unwrap_or("-"),
+                resources.cpu_request.as_deref().unwrap_or("-"),
+                resources.cpu_limit.as_deref().unwrap_or("-"),
+                resources.memory_request.as_deref().unwrap_or("-"),
+                resources.memory_limit.as_deref().unwrap_or("-")
+            ));
+            if let Some(usage) = container.usage {
+                let totals = ResourceTotals::of_container(resources);
+                lines.push(format!(
+                    "    usage: cpu {} | memory {}",
+                    usage_against(
+                        usage.cpu,
+                        totals.cpu_request,
+                        totals.cpu_limit,
+                        Quantity::cpu_label
+                    ),
+                    usage_against(
+                        usage.memory,
+                        totals.memory_request,
+                        totals.memory_limit,
+                        Quantity::memory_label
+                    )
+                ));
+            }
+        }
     }
 
     // Health info
@@ -230,6 +446,8 @@ fn create_pod_detail_lines(pod: &PodInfo, namespace: &str) -> Vec<String> {
         }
     }
 
+    lines.extend(event_lines(&pod.events));
+
     // Additional debug info
     lines.push("".to_string());
     lines.push("🔍 DEBUG INFORMATION".to_string());
@@ -238,7 +456,12 @@ fn create_pod_detail_lines(pod: &PodInfo, namespace: &str) -> Vec<String> {
     lines
 }
 
-fn create_deployment_detail_lines(deployment: &DeploymentInfo, namespace: &str) -> Vec<String> {
+fn create_deployment_detail_lines(
+    deployment: &DeploymentInfo,
+    namespace: &str,
+    pods: &[&PodInfo],
+    issues: &[&ClusterIssue],
+) -> Vec<String> {
     let mut lines = Vec::new();
 
     // Header
@@ -326,6 +549,10 @@ fn create_deployment_detail_lines(deployment: &DeploymentInfo, namespace: &str)
         }
     }
 
+    lines.extend(owned_pod_lines(pods));
+    lines.extend(issue_lines(issues));
+    lines.extend(event_lines(&deployment.events));
+
     // Additional debug info
     lines.push("".to_string());
     lines.push("🔍 DEBUG INFORMATION".to_string());
@@ -337,17 +564,424 @@ fn create_deployment_detail_lines(deployment: &DeploymentInfo, namespace: &str)
     lines
 }
 
+fn create_service_detail_lines(
+    service: &ServiceInfo,
+    networking: &NamespaceNetworking,
+    pods: &[PodInfo],
+    namespace: &str,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    // Header
+    lines.push("🌐 SERVICE INFORMATION".to_string());
+    lines.push("".to_string());
+
+    // Basic info
+    lines.push(format!("Name: {}", service.name));
+    lines.push(format!("Namespace: {}", namespace));
+    lines.push(format!("Type: {}", service.service_type));
+    lines.push(format!(
+        "Cluster IP: {}",
+        service.cluster_ip.as_deref().unwrap_or("Not available")
+    ));
+
+    // Ports
+    lines.push("".to_string());
+    lines.push("🔌 PORTS".to_string());
+    if service.ports.is_empty() {
+        lines.push("No ports defined".to_string());
+    }
+    for port in &service.ports {
+        lines.push(format!(
+            "  {} {}/{} → {}",
+            port.name.as_deref().unwrap_or("-"),
+            port.port,
+            port.protocol,
+            port.target_port.as_deref().unwrap_or("same port")
+        ));
+    }
+
+    // Selector and matching pods
+    lines.push("".to_string());
+    lines.push("🎯 SELECTOR".to_string());
+    lines.push(format!("Selector: {}", service.selector_label()));
+    if service.has_selector() {
+        let matching = service.matching_pods(pods);
+        if matching.is_empty() {
+            lines.push("Matching Pods: 🟡 none".to_string());
+        }
+        for pod in matching {
+            let status_icon = if pod.ready && pod.status == "Running" {
+                "🟢"
+            } else {
+                "🔴"
+            };
+            lines.push(format!(
+                "  {} Pod: {} ({})",
+                status_icon, pod.name, pod.status
+            ));
+        }
+    }
+
+    // Endpoints
+    lines.push("".to_string());
+    lines.push("📍 ENDPOINTS".to_string());
+    match networking.endpoints_for(&service.name) {
+        Some(endpoints) => {
+            lines.push(format!(
+                "Ready: {} | Not Ready: {}",
+                endpoints.ready_addresses.len(),
+                endpoints.not_ready_addresses.len()
+            ));
+            for address in &endpoints.ready_addresses {
+                lines.push(format!("  🟢 {}", address));
+            }
+            for address in &endpoints.not_ready_addresses {
+                lines.push(format!("  🔴 {}", address));
+            }
+        }
+        None => lines.push("Endpoints: Not available".to_string()),
+    }
+
+    // Ingress routes
+    let routes = networking.ingress_routes_to(&service.name);
+    if !routes.is_empty() {
+        lines.push("".to_string());
+        lines.push("🚪 INGRESS ROUTES".to_string());
+        for (ingress_name, backend) in routes {
+            let port_icon = if service.exposes(&backend.port) {
+                "🟢"
+            } else {
+                "🔴"
+            };
+            lines.push(format!(
+                "  {} {} → port {} (Ingress: {})",
+                port_icon,
+                backend.route_label(),
+                backend.port.label(),
+                ingress_name
+            ));
+        }
+    }
+
+    lines
+}
+
+fn create_ingress_detail_lines(
+    ingress: &IngressInfo,
+    networking: &NamespaceNetworking,
+    namespace: &str,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    // Header
+    lines.push("🚪 INGRESS INFORMATION".to_string());
+    lines.push("".to_string());
+
+    // Basic info
+    lines.push(format!("Name: {}", ingress.name));
+    lines.push(format!("Namespace: {}", namespace));
+    lines.push(format!(
+        "Class: {}",
+        ingress.class.as_deref().unwrap_or("Not available")
+    ));
+
+    // Backends
+    lines.push("".to_string());
+    lines.push("🔀 ROUTES".to_string());
+    if ingress.backends.is_empty() {
+        lines.push("No backends defined".to_string());
+    }
+    for backend in &ingress.backends {
+        let status = match networking.service(&backend.service) {
+            None => "🔴 service not found".to_string(),
+            Some(service) if !service.exposes(&backend.port) => {
+                format!("🟡 port not exposed ({})", service.ports_label())
+            }
+            Some(_) => "🟢 ok".to_string(),
+        };
+        lines.push(format!(
+            "  {} → {}:{} | {}",
+            backend.route_label(),
+            backend.service,
+            backend.port.label(),
+            status
+        ));
+    }
+
+    lines
+}
+
+fn create_workload_detail_lines(
+    workloads: &NamespaceWorkloads,
+    kind: WorkloadKind,
+    name: &str,
+    namespace: &str,
+) -> Option<Vec<String>> {
+    let summary = workloads
+        .summaries(kind)
+        .into_iter()
+        .find(|summary| summary.name == name)?;
+
+    let mut lines = Vec::new();
+
+    // Header
+    lines.push(format!(
+        "{} {} INFORMATION",
+        kind.icon(),
+        kind.label().to_uppercase()
+    ));
+    lines.push("".to_string());
+
+    // Basic info
+    lines.push(format!("Name: {}", name));
+    lines.push(format!("Namespace: {}", namespace));
+    lines.push(format!(
+        "Status: {} {}",
+        summary.health.icon(),
+        summary.status
+    ));
+
+    // Kind specific info
+    lines.push("".to_string());
+    lines.push(format!("📋 {} SPEC", kind.label().to_uppercase()));
+
+    let image = match kind {
+        WorkloadKind::StatefulSet => {
+            let statefulset = workloads.statefulsets.iter().find(|s| s.name == name)?;
+            lines.push(format!(
+                "Replicas: {}/{}",
+                statefulset.ready_replicas, statefulset.desired_replicas
+            ));
+            lines.push(format!(
+                "Service Name: {}",
+                statefulset
+                    .service_name
+                    .as_deref()
+                    .unwrap_or("Not available")
+            ));
+            lines.push(format!(
+                "Update Strategy: {}",
+                statefulset
+                    .update_strategy
+                    .as_deref()
+                    .unwrap_or("Not available")
+            ));
+            statefulset.image.clone()
+        }
+        WorkloadKind::DaemonSet => {
+            let daemonset = workloads.daemonsets.iter().find(|d| d.name == name)?;
+            lines.push(format!("Desired Nodes: {}", daemonset.desired_scheduled));
+            lines.push(format!("Scheduled Nodes: {}", daemonset.current_scheduled));
+            lines.push(format!("Ready Nodes: {}", daemonset.ready));
+            lines.push(format!("Misscheduled: {}", daemonset.misscheduled));
+            daemonset.image.clone()
+        }
+        WorkloadKind::ReplicaSet => {
+            let replicaset = workloads.replicasets.iter().find(|r| r.name == name)?;
+            lines.push(format!(
+                "Replicas: {}/{}",
+                replicaset.ready_replicas, replicaset.desired_replicas
+            ));
+            lines.push(format!(
+                "Owner: {}",
+                replicaset.owner.as_deref().unwrap_or("None (standalone)")
+            ));
+            replicaset.image.clone()
+        }
+        WorkloadKind::Job => {
+            let job = workloads.jobs.iter().find(|j| j.name == name)?;
+            lines.push(format!(
+                "Completions: {}/{}",
+                job.succeeded, job.completions
+            ));
+            lines.push(format!("Active Pods: {}", job.active));
+            lines.push(format!("Failed Pods: {}", job.failed));
+            lines.push(format!(
+                "Owner: {}",
+                job.owner.as_deref().unwrap_or("None (standalone)")
+            ));
+            if let Some(ref reason) = job.failure_reason {
+                lines.push(format!("Failure Reason: {}", reason));
+            }
+            job.image.clone()
+        }
+        WorkloadKind::CronJob => {
+            let cronjob = workloads.cronjobs.iter().find(|c| c.name == name)?;
+            lines.push(format!("Schedule: {}", cronjob.schedule));
+            lines.push(format!(
+                "Suspended: {}",
+                if cronjob.suspended {
+                    "⏸️ Yes"
+                } else {
+                    "No"
+                }
+            ));
+            lines.push(format!("Active Jobs: {}", cronjob.active_jobs));
+            lines.push(format!(
+                "Last Schedule: {}",
+                cronjob.last_schedule_time.as_deref().unwrap_or("never")
+            ));
+            lines.push(format!(
+                "Last Success: {}",
+                cronjob.last_successful_time.as_deref().unwrap_or("never")
+            ));
+            cronjob.image.clone()
+        }
+    };
+
+    // Image info
+    lines.push("".to_string());
+    lines.push("📦 CONTAINER IMAGE".to_string());
+    lines.push(format!(
+        "Image: {}",
+        image.as_deref().unwrap_or("Not available")
+    ));
+
+    // Health status
+    lines.push("".to_string());
+    lines.push("❤️ HEALTH STATUS".to_string());
+    lines.push(workload_health_line(&summary));
+
+    Some(lines)
+}
+
+fn workload_health_line(summary: &WorkloadSummary) -> String {
+    match summary.health {
+        WorkloadHealth::Healthy => "Overall Health: 🟢 Healthy".to_string(),
+        WorkloadHealth::Degraded => "Overall Health: 🟡 Degraded".to_string(),
+        WorkloadHealth::Down => "Overall Health: 🔴 Unhealthy".to_string(),
+        WorkloadHealth::Idle => "Overall Health: ⚪ Idle".to_string(),
+    }
+}
+
+/// "Deployment web (via ReplicaSet web-7d9f)": the top-level owner and the objects in between.
+fn owner_label(ownership: &OwnershipGraph, kind: &str, name: &str) -> String {
+    let chain = ownership.owner_chain(kind, name);
+    let Some((root, via)) = chain.split_last() else {
+        return "None (standalone)".to_string();
+    };
+    if via.is_empty() {
+        root.label()
+    } else {
+        let via: Vec<String> = via.iter().map(|owner| owner.label()).collect();
+        format!("{} (via {})", root.label(), via.join(" → "))
+    }
+}
+
+/// Pods a workload controls, directly or through a ReplicaSet or a Job.
+fn owned_pods<'a>(
+    ownership: &OwnershipGraph,
+    pods: &'a [PodInfo],
+    kind: &str,
+    name: &str,
+) -> Vec<&'a PodInfo> {
+    let names = ownership.pods_of(kind, name);
+    pods.iter()
+        .filter(|pod| names.contains(&pod.name.as_str()))
+        .collect()
+}
+
+/// Issues of a workload and of the objects it owns, when the cluster was analyzed.
+fn rolled_up_issues<'a>(
+    app: &'a App,
+    namespace: &str,
+    kind: &str,
+    name: &str,
+) -> Vec<&'a ClusterIssue> {
+    app.cluster_analysis
+        .iter()
+        .flat_map(|analysis| analysis.namespaces.iter())
+        .find(|namespace_analysis| namespace_analysis.name == namespace)
+        .map(|namespace_analysis| namespace_analysis.workload_issues(kind, name))
+        .unwrap_or_default()
+}
+
+/// "PODS" section of a workload.
+fn owned_pod_lines(pods: &[&PodInfo]) -> Vec<String> {
+    let mut lines = vec!["".to_string(), format!("📦 PODS ({})", pods.len())];
+    if pods.is_empty() {
+        lines.push("  No pods found for this workload".to_string());
+    }
+    for pod in pods {
+        let icon = if pod.ready && pod.status == "Running" {
+            "🟢"
+        } else {
+            "🔴"
+        };
+        lines.push(format!(
+            "  {} {} ({}, ready: {}, restarts: {})",
+            icon,
+            pod.name,
+            pod.status,
+            if pod.ready { "yes" } else { "no" },
+            pod.restart_count.as_deref().unwrap_or("-")
+        ));
+    }
+    lines
+}
+
+/// "ISSUES" section, most severe first. Empty when there are none.
+fn issue_lines(issues: &[&ClusterIssue]) -> Vec<String> {
+    if issues.is_empty() {
+        return Vec::new();
+    }
+
+    let mut lines = vec!["".to_string(), format!("🚨 ISSUES ({})", issues.len())];
+    for issue in issues {
+        lines.push(format!(
+            "  {} {} {}: {}",
+            issue.severity.icon(),
+            issue.component_type,
+            issue.component,
+            issue.description
+        ));
+    }
+    lines
+}
+
+/// "EVENTS" section, oldest first. Empty when the object has no events.
+fn event_lines(events: &[EventInfo]) -> Vec<String> {
+    if events.is_empty() {
+        return Vec::new();
+    }
+
+    let mut lines = vec!["".to_string(), format!("📅 EVENTS ({})", events.len())];
+    for event in events {
+        lines.push(format!(
+            "  {} {} {}",
+            event.icon(),
+            event.time(),
+            event.summary()
+        ));
+    }
+    lines
+}
+
+/// The details lines with the first `scroll` ones scrolled out. Scrolling stops when the
+/// last line reaches the bottom of the area; the largest scroll is returned so the app
+/// can stop there too.
 fn draw_scrollable_details(
     f: &mut Frame,
     area: ratatui::layout::Rect,
     lines: Vec<String>,
-    scroll_state: &mut ratatui::widgets::ListState,
-) {
-    let items: Vec<ListItem> = lines.into_iter().map(|line| ListItem::new(line)).collect();
-
-    let list = List::new(items)
-        .block(Block::default().borders(Borders::ALL).title("Details"))
-        .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+    scroll: &mut usize,
+) -> usize {
+    let height = area.height.saturating_sub(2) as usize; // Inside the borders
+    let max_scroll = lines.len().saturating_sub(height);
+    *scroll = (*scroll).min(max_scroll);
+
+    let items: Vec<ListItem> = lines.into_iter().skip(*scroll).map(ListItem::new).collect();
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title("Details"));
+    f.render_widget(list, area);
+    max_scroll
+}
 
-    f.render_stateful_widget(list, area, scroll_state);
+/// A summed request or limit, or "-" when no container sets it.
+fn optional_quantity(quantity: Quantity, label: fn(&Quantity) -> String) -> String {
+    if quantity.is_zero() {
+        "-".to_string()
+    } else {
+        label(&quantity)
+    }
 }
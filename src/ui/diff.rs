@@ -0,0 +1,217 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::App;
+use crate::diff::{ChangeKind, DiffRow};
+use crate::kubernetes::{ClusterIssue, IssueSeverity};
+use crate::report::count_change;
+
+pub fn draw_diff(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Length(4), // Totals before and after
+            Constraint::Min(0),    // Issues and objects
+            Constraint::Length(3), // Instructions
+        ])
+        .split(f.size());
+
+    let title = match app.baseline {
+        Some(ref baseline) => format!(
+            "🔀 Diff: {} → {}",
+            baseline.path().display(),
+            app.dump.path().display()
+        ),
+        None => "🔀 Diff".to_string(),
+    };
+    let title_widget = Paragraph::new(title)
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL))
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+    f.render_widget(title_widget, chunks[0]);
+
+    let Some(ref diff) = app.dump_diff else {
+        let message = Paragraph::new(
+            "No baseline dump to compare with.\n\nStart the analyzer with --baseline <DUMP> to compare the dump with an earlier one,\nor run 'k8s-analyzer diff <BEFORE> <AFTER>'.",
+        )
+        .block(Block::default().borders(Borders::ALL).title("Diff"))
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(Color::Yellow));
+        f.render_widget(message, chunks[1].union(chunks[2]));
+        draw_instructions(f, chunks[3]);
+        return;
+    };
+
+    // Totals of both dumps
+    let (before, after) = (&diff.before, &diff.after);
+    let summary = format!(
+        "Nodes: {} | Namespaces: {} | Pods: {} | Deployments: {} | Other workloads: {} | Issues: {}\nIssues: 🚨 {} new ({} critical), ✅ {} resolved, ✏️ {} changed | Objects: ➕ {} added, ➖ {} removed, ✏️ {} changed",
+        count_change(before.nodes, after.nodes),
+        count_change(before.namespaces, after.namespaces),
+        count_change(before.pods, after.pods),
+        count_change(before.deployments, after.deployments),
+        count_change(before.workloads, after.workloads),
+        count_change(before.issues, after.issues),
+        diff.new_issues.len(),
+        diff.new_issue_count(IssueSeverity::Critical),
+        diff.resolved_issues.len(),
+        diff.changed_issues.len(),
+        diff.count(ChangeKind::Added),
+        diff.count(ChangeKind::Removed),
+        diff.count(ChangeKind::Changed)
+    );
+    let summary_widget = Paragraph::new(summary)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Before → After"),
+        );
+    f.render_widget(summary_widget, chunks[1]);
+
+    if diff.is_empty() {
+        let same = Paragraph::new("🟢 No differences between the two dumps")
+            .block(Block::default().borders(Borders::ALL).title("Changes"))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::Green));
+        f.render_widget(same, chunks[2]);
+        draw_instructions(f, chunks[3]);
+        return;
+    }
+
+    // Rows grouped by section; section headers are not selectable
+    let selected_index = app.list_state.selected().unwrap_or(0);
+    let header_style = Style::default()
+        .fg(Color::Gray)
+        .add_modifier(Modifier::ITALIC);
+    let mut list_items = Vec::new();
+    let mut selected_display_index = None;
+    let mut current_section = None;
+
+    for (row_index, row) in diff.rows().into_iter().enumerate() {
+        let section = section_title(&row);
+        if current_section != Some(section) {
+            if current_section.is_some() {
+                list_items.push(ListItem::new(""));
+            }
+            list_items.push(ListItem::new(section).style(header_style));
+            current_section = Some(section);
+        }
+
+        let (text, color) = match row {
+            DiffRow::NewIssue(issue) => (issue_text(issue), severity_color(issue.severity)),
+            DiffRow::ResolvedIssue(issue) => (issue_text(issue), Color::Green),
+            DiffRow::ChangedIssue(change) => (
+                format!(
+                    "{} ({})",
+                    issue_text(&change.after),
+                    change.previous_label()
+                ),
+                severity_color(change.after.severity),
+            ),
+            DiffRow::Change(object) => {
+                // Changed objects show what changed, the others are self-explanatory
+                let details = if object.change == ChangeKind::Changed {
+                    format!(" ({})", object.details.join("; "))
+                } else {
+                    String::new()
+                };
+                (
+                    format!(
+                        "  {} {} {}{}",
+                        object.change.icon(),
+                        object.kind,
+                        object.path(),
+                        details
+                    ),
+                    change_color(object.change),
+                )
+            }
+        };
+
+        let style = if row_index == selected_index {
+            selected_display_index = Some(list_items.len());
+            Style::default()
+                .bg(Color::Blue)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(color)
+        };
+        list_items.push(ListItem::new(text).style(style));
+    }
+
+    // Track the selected row so long lists scroll with the selection
+    let mut scroll_state = ListState::default();
+    scroll_state.select(selected_display_index);
+    let list = List::new(list_items).block(Block::default().borders(Borders::ALL).title(
+        "Changes (Select a new or changed issue or an object of the current dump to view it)",
+    ));
+    f.render_stateful_widget(list, chunks[2], &mut scroll_state);
+
+    draw_instructions(f, chunks[3]);
+}
+
+fn draw_instructions(f: &mut Frame, area: ratatui::layout::Rect) {
+    let instructions = Paragraph::new("↑↓ Navigate | Enter: View Component | ESC: Back | q: Quit")
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL))
+        .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, area);
+}
+
+/// "  🔴 [default] Pod web-1: ...", the namespace being "cluster" for nodes.
+fn issue_text(issue: &ClusterIssue) -> String {
+    format!(
+        "  {} [{}] {} {}: {}",
+        issue.severity.icon(),
+        if issue.namespace.is_empty() {
+            "cluster"
+        } else {
+            &issue.namespace
+        },
+        issue.component_type,
+        issue.component,
+        issue.description
+    )
+}
+
+fn section_title(row: &DiffRow) -> &'static str {
+    match row {
+        DiffRow::NewIssue(_) => "🚨 New issues",
+        DiffRow::ResolvedIssue(_) => "✅ Resolved issues",
+        DiffRow::ChangedIssue(_) => "✏️ Changed issues",
+        DiffRow::Change(object) => match object.change {
+            ChangeKind::Added => "➕ Added",
+            ChangeKind::Removed => "➖ Removed",
+            ChangeKind::Changed => "✏️ Changed",
+        },
+    }
+}
+
+fn change_color(change: ChangeKind) -> Color {
+    match change {
+        ChangeKind::Added => Color::Green,
+        ChangeKind::Removed => Color::Red,
+        ChangeKind::Changed => Color::Yellow,
+    }
+}
+
+fn severity_color(severity: IssueSeverity) -> Color {
+    match severity {
+        IssueSeverity::Critical => Color::Red,
+        IssueSeverity::Warning => Color::Yellow,
+        IssueSeverity::Info => Color::Blue,
+    }
+}
@@ -0,0 +1,103 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+
+pub fn draw_events_list(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .split(f.size());
+
+    // Title with namespace info
+    let title = if let Some(ref ns) = app.selected_namespace {
+        format!("📅 Events in Namespace: {}", ns)
+    } else {
+        "📅 Events Timeline".to_string()
+    };
+
+    let title_widget = Paragraph::new(title)
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL))
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+    f.render_widget(title_widget, chunks[0]);
+
+    // Events timeline, oldest first
+    if app.events.is_empty() {
+        let empty_message = Paragraph::new("No events found in this namespace")
+            .block(Block::default().borders(Borders::ALL).title("Events"))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::Yellow));
+        f.render_widget(empty_message, chunks[1]);
+    } else {
+        let warnings = app.events.iter().filter(|e| e.is_warning()).count();
+
+        let items: Vec<ListItem> = app
+            .events
+            .iter()
+            .enumerate()
+            .map(|(i, event)| {
+                let style = if Some(i) == app.list_state.selected() {
+                    Style::default()
+                        .bg(Color::Blue)
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD)
+                } else if event.is_warning() {
+                    Style::default().fg(Color::Yellow)
+                } else {
+                    Style::default().fg(Color::White)
+                };
+
+                let source = event
+                    .source
+                    .as_ref()
+                    .map(|source| format!(" [{}]", source))
+                    .unwrap_or_default();
+
+                ListItem::new(format!(
+                    "{} {:<20} {}/{} | {}{}",
+                    event.icon(),
+                    event.time(),
+                    event.involved_kind,
+                    event.involved_name,
+                    event.summary(),
+                    source
+                ))
+                .style(style)
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(format!(
+                "{} events, {} warnings (Select to view the object)",
+                app.events.len(),
+                warnings
+            )))
+            .highlight_style(
+                Style::default()
+                    .bg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            );
+        f.render_stateful_widget(list, chunks[1], &mut app.list_state);
+    }
+
+    // Instructions
+    let instructions = Paragraph::new("↑↓ Navigate | Enter: View Object | ESC: Back | q: Quit")
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL))
+        .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[2]);
+}
@@ -0,0 +1,145 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use super::logs_viewer::{highlight_matches, level_color, search_flags};
+use crate::app::App;
+use crate::global_search::MAX_HITS;
+
+pub fn draw_global_search(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .split(f.size());
+
+    let title = Paragraph::new("🔎 Search All Logs")
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(title, chunks[0]);
+
+    draw_query(f, chunks[1], app);
+    draw_hits(f, chunks[2], app);
+
+    let instructions = if app.global_search_editing {
+        "Enter: Search | ESC: Stop Editing | Alt+C: Case | Alt+R: Regex"
+    } else {
+        "↑/↓: Navigate | Enter: Open in Logs | /: Edit Query | e/w/i/d: Level | a: All Levels | C: Case | R: Regex | ESC: Back"
+    };
+    let instructions = Paragraph::new(instructions)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(instructions, chunks[3]);
+}
+
+fn draw_query(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let query = &app.global_query;
+    let mut spans = vec![
+        Span::styled("/", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            query.query.clone(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ];
+    if app.global_search_editing {
+        spans.push(Span::styled("▏", Style::default().fg(Color::Yellow)));
+    }
+    if let Some(ref error) = query.error {
+        spans.push(Span::styled(
+            format!("  ❌ {}", error.lines().last().unwrap_or("invalid regex")),
+            Style::default().fg(Color::Red),
+        ));
+    }
+    spans.push(Span::styled(
+        format!(
+            "  {}| Level: {}",
+            search_flags(query),
+            app.global_level_filter.as_deref().unwrap_or("all")
+        ),
+        Style::default().fg(Color::Gray),
+    ));
+
+    let border_color = if app.global_search_editing {
+        Color::Yellow
+    } else {
+        Color::Gray
+    };
+    let prompt = Paragraph::new(Line::from(spans)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color))
+            .title("Query"),
+    );
+    f.render_widget(prompt, area);
+}
+
+fn draw_hits(f: &mut Frame, area: ratatui::layout::Rect, app: &mut App) {
+    let Some(ref search) = app.global_search else {
+        let hint = Paragraph::new(
+            "Type a query and press Enter to search every namespace and component of the dump",
+        )
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).title("Results"));
+        f.render_widget(hint, area);
+        return;
+    };
+
+    let status = if search.done { "done" } else { "searching…" };
+    let mut title = format!(
+        "Results: {} hits | Logs scanned: {}/{} | {}",
+        search.hits.len(),
+        search.scanned_logs,
+        search.total_logs,
+        status
+    );
+    if search.limit_reached() {
+        title.push_str(&format!(" | ⚠️ stopped at {} hits", MAX_HITS));
+    }
+
+    let items: Vec<ListItem> = search
+        .hits
+        .iter()
+        .map(|hit| {
+            let mut spans = vec![
+                Span::raw(format!("{} ", hit.level.color_code())),
+                Span::styled(
+                    hit.location(),
+                    Style::default()
+                        .fg(level_color(&hit.level))
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw("  "),
+            ];
+            spans.extend(highlight_matches(&hit.message, &app.global_query));
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("▶ ");
+    f.render_stateful_widget(list, area, &mut app.list_state);
+}
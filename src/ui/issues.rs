@@ -0,0 +1,154 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::kubernetes::IssueSeverity;
+
+pub fn draw_issues(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Length(3), // Summary
+            Constraint::Min(0),    // Issues
+            Constraint::Length(3), // Instructions
+        ])
+        .split(f.size());
+
+    // Title
+    let title_widget = Paragraph::new("🚨 Issues Dashboard")
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL))
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+    f.render_widget(title_widget, chunks[0]);
+
+    let analysis = match &app.cluster_analysis {
+        Some(analysis) => analysis,
+        None => {
+            let loading = Paragraph::new("Loading cluster analysis...")
+                .block(Block::default().borders(Borders::ALL).title("Issues"))
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(Color::Yellow));
+            f.render_widget(loading, chunks[2]);
+            return;
+        }
+    };
+
+    // Summary header with counts per severity
+    let mut summary = vec![Span::styled(
+        format!("Total: {}  ", analysis.total_issues),
+        Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    )];
+    for severity in IssueSeverity::ALL {
+        summary.push(Span::styled(
+            format!(
+                "{} {}: {}  ",
+                severity.icon(),
+                severity.label().to_uppercase(),
+                analysis.issue_count(severity)
+            ),
+            Style::default().fg(severity_color(severity)),
+        ));
+    }
+    let summary_widget = Paragraph::new(Line::from(summary))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).title("Summary"));
+    f.render_widget(summary_widget, chunks[1]);
+
+    // Issues grouped by namespace
+    let selected_index = app.list_state.selected().unwrap_or(0);
+    let mut list_items = Vec::new();
+    let mut selected_display_index = None;
+    let mut issue_index = 0;
+
+    for (namespace, issues) in analysis.issues_by_namespace() {
+        // Namespace header (not selectable)
+        // Cluster-scoped issues (nodes) are grouped under the "" namespace
+        let group = if namespace.is_empty() {
+            "🖥️ Cluster".to_string()
+        } else {
+            format!("📁 {}", namespace)
+        };
+        list_items.push(
+            ListItem::new(format!("{} ({} issues)", group, issues.len())).style(
+                Style::default()
+                    .fg(Color::Gray)
+                    .add_modifier(Modifier::ITALIC),
+            ),
+        );
+
+        for issue in issues {
+            let style = if issue_index == selected_index {
+                selected_display_index = Some(list_items.len());
+                Style::default()
+                    .bg(Color::Blue)
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(severity_color(issue.severity))
+            };
+            let owner = match issue.owner {
+                Some(ref owner) => format!(" (→ {})", owner.label()),
+                None => String::new(),
+            };
+            list_items.push(
+                ListItem::new(format!(
+                    "  {} {} {}: {}{}",
+                    issue.severity.icon(),
+                    issue.component_type,
+                    issue.component,
+                    issue.description,
+                    owner
+                ))
+                .style(style),
+            );
+            issue_index += 1;
+        }
+    }
+
+    if list_items.is_empty() {
+        let healthy = Paragraph::new("🟢 No issues found in this cluster")
+            .block(Block::default().borders(Borders::ALL).title("Issues"))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::Green));
+        f.render_widget(healthy, chunks[2]);
+    } else {
+        // Track the selected row so long issue lists scroll with the selection
+        let mut scroll_state = ListState::default();
+        scroll_state.select(selected_display_index);
+
+        let list = List::new(list_items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Issues by Namespace (Select to view component)"),
+        );
+        f.render_stateful_widget(list, chunks[2], &mut scroll_state);
+    }
+
+    // Instructions
+    let instructions = Paragraph::new("↑↓ Navigate | Enter: View Component | ESC: Back | q: Quit")
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL))
+        .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[3]);
+}
+
+fn severity_color(severity: IssueSeverity) -> Color {
+    match severity {
+        IssueSeverity::Critical => Color::Red,
+        IssueSeverity::Warning => Color::Yellow,
+        IssueSeverity::Info => Color::Blue,
+    }
+}
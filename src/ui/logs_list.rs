@@ -4,9 +4,9 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, Paragraph},
     Frame,
 };
-use std::fs;
 
 use crate::app::App;
+use crate::logs::list_log_components;
 
 pub fn draw_logs_list(f: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
@@ -35,49 +35,29 @@ pub fn draw_logs_list(f: &mut Frame, app: &mut App) {
     let mut log_sources = Vec::new();
 
     if let Some(ref namespace) = app.selected_namespace {
-        let namespace_dir = format!("output/{}", namespace);
+        let namespace_dir = app.dump.namespace_dir(namespace);
 
         // Scan for directories with logs
-        if let Ok(entries) = fs::read_dir(&namespace_dir) {
-            for entry in entries.flatten() {
-                if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
-                    let component_name = entry.file_name().to_string_lossy().to_string();
-                    let component_path = entry.path();
-
-                    // Check if this directory has logs
-                    let has_logs = [
-                        component_path.join("logs.txt"),
-                        component_path.join("log.txt"),
-                        component_path.join("logs.json"),
-                    ]
-                    .iter()
-                    .any(|path| path.exists());
-
-                    if has_logs {
-                        // Determine component type based on name patterns
-                        let component_type = determine_component_type(&component_name);
-                        let status_icon = "📄"; // Default log icon
-
-                        log_sources.push(format!(
-                            "{} {}: {}",
-                            status_icon, component_type, component_name
-                        ));
-                    }
-                }
-            }
+        for component_name in list_log_components(&app.dump, namespace) {
+            // Determine component type based on name patterns
+            let component_type = determine_component_type(&component_name);
+            let status_icon = "📄"; // Default log icon
+
+            log_sources.push(format!(
+                "{} {}: {}",
+                status_icon, component_type, component_name
+            ));
         }
 
         // If no log directories found, check for direct log files
         if log_sources.is_empty() {
-            if let Ok(entries) = fs::read_dir(&namespace_dir) {
-                for entry in entries.flatten() {
-                    let path = entry.path();
-                    if let Some(extension) = path.extension() {
-                        if extension == "txt" || extension == "log" || extension == "json" {
-                            if let Some(stem) = path.file_stem() {
-                                let file_name = stem.to_string_lossy().to_string();
-                                log_sources.push(format!("📄 Log File: {}", file_name));
-                            }
+            for entry in app.dump.read_dir(&namespace_dir) {
+                let path = namespace_dir.join(&entry.name);
+                if let Some(extension) = path.extension() {
+                    if extension == "txt" || extension == "log" || extension == "json" {
+                        if let Some(stem) = path.file_stem() {
+                            let file_name = stem.to_string_lossy().to_string();
+                            log_sources.push(format!("📄 Log File: {}", file_name));
                         }
                     }
                 }
@@ -90,11 +70,14 @@ pub fn draw_logs_list(f: &mut Frame, app: &mut App) {
         log_sources.push("No log sources found in this namespace".to_string());
         log_sources.push("".to_string());
         log_sources.push("Expected log structure:".to_string());
-        log_sources.push("  output/{namespace}/{component}/logs.txt".to_string());
-        log_sources.push("  output/{namespace}/{component}/log.txt".to_string());
+        log_sources.push("  {dump}/{namespace}/{component}/logs.txt".to_string());
+        log_sources.push("  {dump}/{namespace}/{component}/log.txt".to_string());
         log_sources.push("".to_string());
         if let Some(ref ns) = app.selected_namespace {
-            log_sources.push(format!("Checked directory: output/{}/", ns));
+            log_sources.push(format!(
+                "Checked directory: {}/",
+                app.dump.namespace_dir(ns).display()
+            ));
         }
     }
 
@@ -127,8 +110,14 @@ pub fn draw_logs_list(f: &mut Frame, app: &mut App) {
         );
     f.render_stateful_widget(list, chunks[1], &mut app.list_state);
 
-    // Instructions
-    let instructions = Paragraph::new("↑↓ Navigate | Enter: View Logs | ESC: Back | q: Quit")
+    // Instructions, or why the last selected source could not be opened
+    let instructions = match app.logs_error {
+        Some(ref error) => {
+            Paragraph::new(format!("❌ {}", error)).style(Style::default().fg(Color::Red))
+        }
+        None => Paragraph::new("↑↓ Navigate | Enter: View Logs | ESC: Back | q: Quit"),
+    };
+    let instructions = instructions
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::ALL));
     f.render_widget(instructions, chunks[2]);
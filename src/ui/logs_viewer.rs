@@ -2,21 +2,38 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Gauge, List, ListItem, Paragraph},
+    widgets::{Block, Borders, Gauge, List, ListItem, Paragraph, Wrap},
     Frame,
 };
+use serde_json::Value;
 
-use crate::app::App;
-use crate::logs::{ComponentLogs, LogLevel};
+use crate::app::{App, LogView};
+use crate::log_store::WINDOW_ENTRIES;
+use crate::logs::{entry_pod, search_logs, ComponentLogs, LogLevel, LogSearch};
+
+/// Colours of the pods of merged workload logs, by pod number.
+const POD_COLORS: [Color; 6] = [
+    Color::Cyan,
+    Color::Magenta,
+    Color::Green,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightRed,
+];
 
 pub fn draw_logs_viewer(f: &mut Frame, app: &mut App) {
+    // One more header line to warn about truncated pods of merged logs
+    let truncated = app
+        .current_logs
+        .as_ref()
+        .is_some_and(|logs| logs.pods.iter().any(|pod| pod.truncated));
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
         .constraints([
-            Constraint::Length(5), // Header with stats
-            Constraint::Min(0),    // Log entries
-            Constraint::Length(3), // Controls
+            Constraint::Length(if truncated { 6 } else { 5 }), // Header with stats
+            Constraint::Min(0),                                // Log entries
+            Constraint::Length(4),                             // Controls
         ])
         .split(f.size());
 
@@ -38,7 +55,7 @@ fn draw_log_header(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
 
     // Component info (left side)
     let component_info = if let Some(ref logs) = app.current_logs {
-        vec![
+        let mut lines = vec![
             Line::from(vec![
                 Span::styled("Component: ", Style::default().fg(Color::Cyan)),
                 Span::styled(
@@ -53,15 +70,42 @@ fn draw_log_header(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
                 Span::styled(&logs.component_type, Style::default().fg(Color::Yellow)),
                 Span::styled(" | Namespace: ", Style::default().fg(Color::Cyan)),
                 Span::styled(&logs.namespace, Style::default().fg(Color::Green)),
+                Span::styled(" | Container: ", Style::default().fg(Color::Cyan)),
+                Span::styled(
+                    logs.container.as_deref().unwrap_or("all"),
+                    Style::default().fg(Color::Magenta),
+                ),
             ]),
             Line::from(vec![
                 Span::styled("Total Entries: ", Style::default().fg(Color::Cyan)),
+                Span::styled(total_entries_label(logs), Style::default().fg(Color::White)),
+                Span::styled(" | Order: ", Style::default().fg(Color::Cyan)),
+                Span::styled(order_label(logs), Style::default().fg(Color::White)),
+                Span::styled(" | Format: ", Style::default().fg(Color::Cyan)),
+                Span::styled(logs.format, Style::default().fg(Color::White)),
+            ]),
+        ];
+        let truncated: Vec<&str> = logs
+            .pods
+            .iter()
+            .filter(|pod| pod.truncated)
+            .map(|pod| pod.name.as_str())
+            .collect();
+        if !truncated.is_empty() {
+            let warning = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+            lines.push(Line::from(vec![
+                Span::styled("TRUNCATED: ", warning),
                 Span::styled(
-                    logs.total_entries.to_string(),
-                    Style::default().fg(Color::White),
+                    format!(
+                        "first {} entries of {} only",
+                        WINDOW_ENTRIES,
+                        truncated.join(", ")
+                    ),
+                    Style::default().fg(Color::Red),
                 ),
-            ]),
-        ]
+            ]));
+        }
+        lines
     } else {
         vec![Line::from("No logs loaded")]
     };
@@ -76,6 +120,43 @@ fn draw_log_header(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     }
 }
 
+/// Display order. Large files are not sorted by timestamp, only read forward or
+/// backward.
+fn order_label(logs: &ComponentLogs) -> &'static str {
+    match (logs.newest_first, logs.store.is_some()) {
+        (true, false) => "newest first",
+        (false, false) => "oldest first",
+        (true, true) => "reverse file order",
+        (false, true) => "file order",
+    }
+}
+
+/// The part of a large file that search, filters and patterns see, for the titles.
+fn window_label(logs: &ComponentLogs) -> Option<String> {
+    if logs.store.is_none() || logs.entries.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "entries {}-{} of {}",
+        logs.window_start + 1,
+        logs.window_start + logs.entries.len(),
+        logs.total_entries
+    ))
+}
+
+/// Entry count, with the indexing progress of large files.
+fn total_entries_label(logs: &ComponentLogs) -> String {
+    match logs.index_progress() {
+        Some(progress) if progress.error.is_some() => {
+            format!("{} (indexing failed)", logs.total_entries)
+        }
+        Some(progress) if !progress.done => {
+            format!("{}+ (indexing {}%)", logs.total_entries, progress.percent())
+        }
+        _ => logs.total_entries.to_string(),
+    }
+}
+
 fn draw_log_stats(f: &mut Frame, area: ratatui::layout::Rect, logs: &ComponentLogs) {
     let stats_chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -83,6 +164,7 @@ fn draw_log_stats(f: &mut Frame, area: ratatui::layout::Rect, logs: &ComponentLo
             Constraint::Length(1),
             Constraint::Length(1),
             Constraint::Length(1),
+            Constraint::Min(0), // Entries per pod of merged workload logs
         ])
         .split(area);
 
@@ -126,50 +208,78 @@ fn draw_log_stats(f: &mut Frame, area: ratatui::layout::Rect, logs: &ComponentLo
         .gauge_style(Style::default().fg(Color::Blue))
         .ratio(info_ratio);
     f.render_widget(info_gauge, stats_chunks[2]);
+
+    if logs.pods.is_empty() {
+        return;
+    }
+    let mut spans = vec![Span::styled("📦 Pods:", Style::default().fg(Color::Cyan))];
+    for (i, pod) in logs.pods.iter().enumerate() {
+        let style = if logs.hidden_pods.contains(&pod.name) {
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::CROSSED_OUT)
+        } else {
+            Style::default().fg(pod_color(i))
+        };
+        spans.push(Span::raw(" "));
+        // Large log files only contribute their first window
+        let count = if pod.truncated {
+            format!("first {}", pod.entries)
+        } else {
+            pod.entries.to_string()
+        };
+        spans.push(Span::styled(
+            format!("{} {}: {}", i + 1, pod.name, count),
+            style,
+        ));
+    }
+    let pods_widget = Paragraph::new(Line::from(spans)).wrap(Wrap { trim: true });
+    f.render_widget(pods_widget, stats_chunks[3]);
+}
+
+/// Colour of the `index`th pod (from 0) of merged workload logs.
+fn pod_color(index: usize) -> Color {
+    POD_COLORS[index % POD_COLORS.len()]
 }
 
 fn draw_log_entries(f: &mut Frame, area: ratatui::layout::Rect, app: &mut App) {
+    // The detail pane of the selected entry takes the lower half when open
+    let area = if app.log_detail_open && app.current_logs.is_some() {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+            .split(area);
+        draw_log_detail(f, chunks[1], app);
+        chunks[0]
+    } else {
+        area
+    };
+
+    if app.log_view == LogView::Patterns {
+        draw_log_patterns(f, area, app);
+        return;
+    }
+
+    // Entries passing the level and pattern filters, and the search hits among them
+    let visible = app.visible_log_indices();
     if let Some(ref logs) = app.current_logs {
-        // Get filtered logs based on current filter
-        let displayed_logs: Vec<&crate::logs::LogEntry> = match &app.log_filter {
-            Some(filter) => match filter.as_str() {
-                "error" => logs
-                    .entries
-                    .iter()
-                    .filter(|e| matches!(e.level, LogLevel::Error))
-                    .collect(),
-                "warning" => logs
-                    .entries
-                    .iter()
-                    .filter(|e| matches!(e.level, LogLevel::Warning))
-                    .collect(),
-                "info" => logs
-                    .entries
-                    .iter()
-                    .filter(|e| matches!(e.level, LogLevel::Info))
-                    .collect(),
-                "debug" => logs
-                    .entries
-                    .iter()
-                    .filter(|e| matches!(e.level, LogLevel::Debug))
-                    .collect(),
-                _ => logs.entries.iter().collect(),
-            },
-            None => logs.entries.iter().collect(),
-        };
+        let hits = search_logs(logs, &visible, &app.log_search);
+        let selected = app.logs_scroll_state.selected();
+        let pod_width = logs
+            .pods
+            .iter()
+            .map(|pod| pod.name.len())
+            .max()
+            .unwrap_or(0);
 
-        let items: Vec<ListItem> = displayed_logs
+        let items: Vec<ListItem> = visible
             .iter()
             .enumerate()
-            .map(|(i, entry)| {
-                let level_color = match entry.level {
-                    LogLevel::Error => Color::Red,
-                    LogLevel::Warning => Color::Yellow,
-                    LogLevel::Info => Color::Blue,
-                    LogLevel::Debug => Color::Gray,
-                };
+            .map(|(i, &index)| {
+                let entry = &logs.entries[index];
+                let level_color = level_color(&entry.level);
 
-                let style = if Some(i) == app.logs_scroll_state.selected() {
+                let style = if Some(i) == selected {
                     Style::default()
                         .bg(Color::DarkGray)
                         .add_modifier(Modifier::BOLD)
@@ -177,19 +287,97 @@ fn draw_log_entries(f: &mut Frame, area: ratatui::layout::Rect, app: &mut App) {
                     Style::default()
                 };
 
-                let log_line = format!(
-                    "{} [{}] [{}] {}",
+                let mut spans = vec![Span::raw(format!(
+                    "{} [{}] ",
                     entry.level.color_code(),
-                    truncate_timestamp(&entry.timestamp),
-                    entry.source,
-                    truncate_message(&entry.message, 100)
-                );
+                    entry
+                        .time
+                        .map(|time| time.display())
+                        .unwrap_or_else(|| truncate_timestamp(&entry.timestamp)),
+                ))];
+                // Pod column of merged workload logs, one colour per pod
+                if let Some(pod) = entry_pod(entry) {
+                    let color = logs.pod_index(pod).map(pod_color).unwrap_or(Color::White);
+                    spans.extend(highlight_matches_styled(
+                        &format!("{:<width$}", pod, width = pod_width),
+                        &app.log_search,
+                        Style::default().fg(color),
+                    ));
+                    spans.push(Span::raw(" "));
+                }
+                spans.push(Span::raw("["));
+                spans.extend(highlight_matches(&entry.source, &app.log_search));
+                spans.push(Span::raw("] "));
+                spans.extend(highlight_matches(
+                    &scrolled_message(&entry.message, app.log_horizontal_scroll),
+                    &app.log_search,
+                ));
+                if !entry.fields.is_empty() {
+                    spans.push(Span::raw(" "));
+                    spans.extend(highlight_matches_styled(
+                        &entry.fields_label(),
+                        &app.log_search,
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+
+                // Folded stack trace lines: a marker, or the lines themselves when expanded
+                let expanded = app.expanded_log_entries.contains(&entry.line_number);
+                if !entry.continuation.is_empty() && !expanded {
+                    spans.push(Span::styled(
+                        format!(" [+{} lines, x: expand]", entry.continuation.len()),
+                        Style::default().fg(Color::Cyan),
+                    ));
+                }
 
-                ListItem::new(log_line).style(style.fg(level_color))
+                let mut lines = vec![Line::from(spans)];
+                if expanded {
+                    lines.extend(entry.continuation.iter().map(|line| {
+                        let mut spans = vec![Span::raw("    ")];
+                        spans.extend(highlight_matches(
+                            &line.replace('\t', "    "),
+                            &app.log_search,
+                        ));
+                        Line::from(spans)
+                    }));
+                }
+
+                ListItem::new(lines).style(style.fg(level_color))
             })
             .collect();
 
-        let title = format!("Logs ({} entries)", displayed_logs.len());
+        let mut title = format!("Logs ({} entries)", visible.len());
+        if let Some(window) = window_label(logs) {
+            // Search, patterns and filters only cover the loaded window
+            title.push_str(&format!(
+                " | Window: {} (search, filters and patterns see this window only)",
+                window
+            ));
+        }
+        if let Some(ref pattern) = app.log_pattern_filter {
+            title.push_str(&format!(
+                " | 🧩 Pattern: {} (ESC: Patterns)",
+                pattern.template
+            ));
+        }
+        if app.log_search.is_active() {
+            let current = selected
+                .and_then(|selected| hits.iter().position(|&hit| hit == selected))
+                .map(|position| format!("{}/", position + 1))
+                .unwrap_or_default();
+            title.push_str(&format!(
+                " | 🔍 \"{}\": {}{} matches{}",
+                app.log_search.query,
+                current,
+                hits.len(),
+                if logs.store.is_some() {
+                    " in the window"
+                } else {
+                    ""
+                }
+            ));
+        }
+
         let list = List::new(items)
             .block(Block::default().borders(Borders::ALL).title(title))
             .highlight_style(
@@ -207,42 +395,316 @@ fn draw_log_entries(f: &mut Frame, area: ratatui::layout::Rect, app: &mut App) {
     }
 }
 
+/// Split `text` into spans, with the search matches highlighted.
+pub fn highlight_matches(text: &str, search: &LogSearch) -> Vec<Span<'static>> {
+    highlight_matches_styled(text, search, Style::default())
+}
+
+fn highlight_matches_styled(text: &str, search: &LogSearch, style: Style) -> Vec<Span<'static>> {
+    let highlight = Style::default()
+        .fg(Color::Black)
+        .bg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+
+    let mut spans = Vec::new();
+    let mut last = 0;
+    for (start, end) in search.match_ranges(text) {
+        if start > last {
+            spans.push(Span::styled(text[last..start].to_string(), style));
+        }
+        spans.push(Span::styled(text[start..end].to_string(), highlight));
+        last = end;
+    }
+    if last < text.len() {
+        spans.push(Span::styled(text[last..].to_string(), style));
+    }
+    spans
+}
+
+fn draw_log_patterns(f: &mut Frame, area: ratatui::layout::Rect, app: &mut App) {
+    let items: Vec<ListItem> = app
+        .log_patterns
+        .iter()
+        .map(|pattern| {
+            let seen = match (pattern.first_seen, pattern.last_seen) {
+                (Some(first), Some(last)) => format!("{} → {}", first.display(), last.display()),
+                _ => "-".to_string(),
+            };
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{} ", pattern.level.color_code())),
+                Span::styled(
+                    format!("{:>6}x ", pattern.count),
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(format!("[{}] ", seen), Style::default().fg(Color::Gray)),
+                Span::styled(
+                    pattern.template.clone(),
+                    Style::default().fg(level_color(&pattern.level)),
+                ),
+            ]))
+        })
+        .collect();
+
+    let mut title = format!(
+        "Patterns ({} patterns, most frequent first)",
+        app.log_patterns.len()
+    );
+    if let Some(window) = app.current_logs.as_ref().and_then(window_label) {
+        title.push_str(&format!(" | Only {} (the loaded window)", window));
+    }
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    f.render_stateful_widget(list, area, &mut app.patterns_state);
+}
+
+pub fn level_color(level: &LogLevel) -> Color {
+    match level {
+        LogLevel::Error => Color::Red,
+        LogLevel::Warning => Color::Yellow,
+        LogLevel::Info => Color::Blue,
+        LogLevel::Debug => Color::Gray,
+    }
+}
+
 fn draw_log_controls(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    if app.log_search_editing {
+        draw_search_prompt(f, area, &app.log_search);
+        return;
+    }
+
     let filter_info = match &app.log_filter {
         Some(filter) => format!("Filter: {} ", filter),
         None => "Filter: all ".to_string(),
     };
 
-    let controls = format!(
-        "{}| ↑↓: Scroll | f: Filter (e/w/i/d/a) | /: Search | ESC: Back | q: Quit",
-        filter_info
-    );
+    // Merged workload logs show every container; their pods can be hidden instead
+    let merged = app
+        .current_logs
+        .as_ref()
+        .is_some_and(|logs| !logs.pods.is_empty());
+    let sources = if merged {
+        "1-9: Hide/Show Pod | h: Hide Selected Pod | 0: All Pods"
+    } else {
+        "c: Container"
+    };
+
+    let controls = if app.log_view == LogView::Patterns {
+        format!(
+            "{}| ↑↓: Select | Enter: Show entries | f: Filter (e/w/i/d/a) | p/ESC: Entries | q: Quit",
+            filter_info
+        )
+    } else {
+        format!(
+            "{}| ↑↓←→: Scroll | Enter: Details | f: Filter (e/w/i/d/a) | p: Patterns | {} | o: Order | /: Search (n/N, C/R: Case/Regex) | x/X: Expand | ESC: Back | q: Quit",
+            filter_info, sources
+        )
+    };
 
     let instructions = Paragraph::new(controls)
         .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
         .block(Block::default().borders(Borders::ALL));
     f.render_widget(instructions, area);
 }
 
+fn draw_search_prompt(f: &mut Frame, area: ratatui::layout::Rect, search: &LogSearch) {
+    let mut spans = vec![
+        Span::styled("/", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            search.query.clone(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("▏", Style::default().fg(Color::Yellow)),
+    ];
+    if let Some(ref error) = search.error {
+        // Regex errors span several lines; the last one names the problem
+        spans.push(Span::styled(
+            format!("  ❌ {}", error.lines().last().unwrap_or("invalid regex")),
+            Style::default().fg(Color::Red),
+        ));
+    }
+    spans.push(Span::styled(
+        format!(
+            "  {}| Enter: Confirm | ESC: Cancel | Alt+C: Case | Alt+R: Regex",
+            search_flags(search)
+        ),
+        Style::default().fg(Color::Gray),
+    ));
+
+    let prompt = Paragraph::new(Line::from(spans))
+        .block(Block::default().borders(Borders::ALL).title("Search"));
+    f.render_widget(prompt, area);
+}
+
+/// Current state of the search options.
+pub fn search_flags(search: &LogSearch) -> String {
+    format!(
+        "Case: {} | Regex: {} ",
+        if search.case_sensitive { "on" } else { "off" },
+        if search.regex { "on" } else { "off" }
+    )
+}
+
+fn draw_log_detail(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let Some(entry) = app.selected_log_entry() else {
+        let empty = Paragraph::new("No entry selected")
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Entry Details"),
+            )
+            .alignment(Alignment::Center);
+        f.render_widget(empty, area);
+        return;
+    };
+
+    let label = Style::default().fg(Color::Cyan);
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Level: ", label),
+            Span::raw(format!(
+                "{} {}",
+                entry.level.color_code(),
+                entry.level.to_string()
+            )),
+            Span::styled(" | Source: ", label),
+            Span::raw(entry.source.clone()),
+            Span::styled(" | Line: ", label),
+            Span::raw(entry.line_number.to_string()),
+        ]),
+        Line::from(vec![
+            Span::styled("Timestamp: ", label),
+            Span::raw(entry.timestamp.clone()),
+            Span::styled(
+                entry
+                    .time
+                    .map(|time| format!(" ({})", time.display()))
+                    .unwrap_or_default(),
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("Message:", label)),
+    ];
+    lines.extend(
+        pretty_text(&entry.message)
+            .into_iter()
+            .map(|line| Line::from(highlight_matches(&line, &app.log_search))),
+    );
+
+    if !entry.fields.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("Fields:", label)));
+        for (key, value) in &entry.fields {
+            let mut value_lines = pretty_text(value).into_iter();
+            let mut spans = vec![Span::styled(
+                format!("  {}: ", key),
+                Style::default().fg(Color::Yellow),
+            )];
+            spans.extend(highlight_matches(
+                &value_lines.next().unwrap_or_default(),
+                &app.log_search,
+            ));
+            lines.push(Line::from(spans));
+            lines.extend(value_lines.map(|line| Line::from(format!("    {}", line))));
+        }
+    }
+
+    if !entry.continuation.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("Stack trace:", label)));
+        lines.extend(entry.continuation.iter().map(|line| {
+            Line::from(highlight_matches(
+                &line.replace('\t', "    "),
+                &app.log_search,
+            ))
+        }));
+    }
+
+    let detail = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Entry Details (Enter/ESC: Close | PgUp/PgDn: Scroll)"),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((app.log_detail_scroll, 0));
+    f.render_widget(detail, area);
+}
+
+/// Lines of a message, with the JSON it embeds pretty-printed.
+fn pretty_text(text: &str) -> Vec<String> {
+    let Some((before, json, after)) = split_embedded_json(text) else {
+        return text
+            .lines()
+            .map(|line| line.replace('\t', "    "))
+            .collect();
+    };
+
+    let mut lines = Vec::new();
+    if !before.trim().is_empty() {
+        lines.push(before.trim_end().to_string());
+    }
+    lines.extend(
+        serde_json::to_string_pretty(&json)
+            .unwrap_or_default()
+            .lines()
+            .map(|line| line.to_string()),
+    );
+    if !after.trim().is_empty() {
+        lines.push(after.trim().to_string());
+    }
+    lines
+}
+
+/// The first JSON object or array in `text`, with the text before and after it.
+fn split_embedded_json(text: &str) -> Option<(&str, Value, &str)> {
+    text.char_indices()
+        .filter(|(_, c)| matches!(c, '{' | '['))
+        .find_map(|(start, _)| {
+            let mut values =
+                serde_json::Deserializer::from_str(&text[start..]).into_iter::<Value>();
+            let value = values.next()?.ok()?;
+            let is_structured = match value {
+                Value::Object(ref map) => !map.is_empty(),
+                Value::Array(ref items) => !items.is_empty(),
+                _ => false,
+            };
+            let end = start + values.byte_offset();
+            is_structured.then(|| (&text[..start], value, &text[end..]))
+        })
+}
+
 fn truncate_timestamp(timestamp: &str) -> String {
-    if timestamp.len() > 19 {
+    if timestamp.chars().count() > 19 {
         // Extract just the date and time part for ISO timestamps
-        if let Some(t_pos) = timestamp.find('T') {
-            let date_part = &timestamp[..std::cmp::min(10, timestamp.len())];
-            let time_part = &timestamp[t_pos + 1..std::cmp::min(t_pos + 9, timestamp.len())];
-            format!("{} {}", date_part, time_part)
-        } else {
-            timestamp[..std::cmp::min(19, timestamp.len())].to_string()
+        match (timestamp.find('T'), timestamp.get(..10)) {
+            (Some(t_pos), Some(date_part)) => {
+                let time_part: String = timestamp[t_pos + 1..].chars().take(8).collect();
+                format!("{} {}", date_part, time_part)
+            }
+            _ => timestamp.chars().take(19).collect(),
         }
     } else {
         timestamp.to_string()
     }
 }
 
-fn truncate_message(message: &str, max_len: usize) -> String {
-    if message.len() > max_len {
-        format!("{}...", &message[..max_len - 3])
-    } else {
-        message.to_string()
+/// The message on a single line, without its first `offset` characters.
+fn scrolled_message(message: &str, offset: usize) -> String {
+    let single_line = message.replace(['\n', '\t'], " ");
+    if offset == 0 {
+        return single_line;
     }
+    format!("…{}", single_line.chars().skip(offset).collect::<String>())
 }
@@ -1,27 +1,43 @@
 use crate::app::{App, Screen};
 use ratatui::Frame;
 
+mod capacity;
 mod capybara;
 mod cluster_analysis;
 mod component_details;
 mod deployments_list;
+mod diff;
+mod events_list;
+mod global_search;
+mod issues;
 mod logs_list;
 mod logs_viewer;
 mod main_menu;
 mod namespace_details;
 mod namespace_list;
+mod nodes_list;
 mod pods_list;
+mod services_list;
+mod workloads_list;
 
+pub use capacity::draw_capacity;
 pub use capybara::draw_capybara;
 pub use cluster_analysis::draw_cluster_analysis;
 pub use component_details::draw_component_details;
 pub use deployments_list::draw_deployments_list;
+pub use diff::draw_diff;
+pub use events_list::draw_events_list;
+pub use global_search::draw_global_search;
+pub use issues::draw_issues;
 pub use logs_list::draw_logs_list;
 pub use logs_viewer::draw_logs_viewer;
 pub use main_menu::draw_main_menu;
 pub use namespace_details::draw_namespace_details;
 pub use namespace_list::draw_namespace_list;
+pub use nodes_list::draw_nodes_list;
 pub use pods_list::draw_pods_list;
+pub use services_list::draw_services_list;
+pub use workloads_list::draw_workloads_list;
 
 pub fn draw(f: &mut Frame, app: &mut App) {
     match app.current_screen {
@@ -29,11 +45,19 @@ pub fn draw(f: &mut Frame, app: &mut App) {
         Screen::NamespaceList => draw_namespace_list(f, app),
         Screen::NamespaceDetails => draw_namespace_details(f, app),
         Screen::ClusterAnalysis => draw_cluster_analysis(f, app),
+        Screen::Issues => draw_issues(f, app),
         Screen::ComponentDetails => draw_component_details(f, app),
         Screen::LogsList => draw_logs_list(f, app),
         Screen::LogsViewer => draw_logs_viewer(f, app),
         Screen::Capybara => draw_capybara(f, app),
         Screen::PodsList => draw_pods_list(f, app),
         Screen::DeploymentsList => draw_deployments_list(f, app),
+        Screen::WorkloadsList => draw_workloads_list(f, app),
+        Screen::ServicesList => draw_services_list(f, app),
+        Screen::EventsList => draw_events_list(f, app),
+        Screen::GlobalSearch => draw_global_search(f, app),
+        Screen::NodesList => draw_nodes_list(f, app),
+        Screen::Capacity => draw_capacity(f, app),
+        Screen::Diff => draw_diff(f, app),
     }
 }
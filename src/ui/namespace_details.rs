@@ -8,14 +8,16 @@ use ratatui::{
 use crate::app::App;
 
 pub fn draw_namespace_details(f: &mut Frame, app: &mut App) {
+    let menu_items = app.get_namespace_details_items();
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(2)
         .constraints([
             Constraint::Length(3),
-            Constraint::Length(8),      // Menu options
-            Constraint::Percentage(45), // Pods
-            Constraint::Percentage(45), // Deployments
+            Constraint::Length(menu_items.len() as u16 + 2), // Menu options
+            Constraint::Percentage(45),                      // Pods
+            Constraint::Percentage(45),                      // Deployments
         ])
         .split(f.size());
 
@@ -32,7 +34,6 @@ pub fn draw_namespace_details(f: &mut Frame, app: &mut App) {
     f.render_widget(title_widget, chunks[0]);
 
     // Menu options
-    let menu_items = app.get_namespace_details_items();
     let menu_list_items: Vec<ListItem> = menu_items
         .iter()
         .enumerate()
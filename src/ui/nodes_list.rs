@@ -0,0 +1,258 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::App;
+use crate::kubernetes::PodInfo;
+use crate::metrics::ResourceUsage;
+use crate::nodes::{NodeCondition, NodeInfo};
+use crate::resources::NodeAllocation;
+
+/// Resources shown in the capacity section, in display order.
+const NODE_RESOURCES: [&str; 4] = ["cpu", "memory", "pods", "ephemeral-storage"];
+
+pub fn draw_nodes_list(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .split(f.size());
+
+    let not_ready = app.nodes.iter().filter(|node| !node.ready).count();
+    let cordoned = app.nodes.iter().filter(|node| node.unschedulable).count();
+    let title = Paragraph::new(format!(
+        "🖥️ Nodes ({} total, {} NotReady, {} cordoned)",
+        app.nodes.len(),
+        not_ready,
+        cordoned
+    ))
+    .alignment(Alignment::Center)
+    .block(Block::default().borders(Borders::ALL))
+    .style(
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    );
+    f.render_widget(title, chunks[0]);
+
+    if app.nodes.is_empty() {
+        let empty_message = Paragraph::new("No nodes found in this dump (nodes.json is missing)")
+            .block(Block::default().borders(Borders::ALL).title("Nodes"))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::Yellow));
+        f.render_widget(empty_message, chunks[1]);
+    } else {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(chunks[1]);
+        draw_node_list(f, columns[0], app);
+        draw_node_details(f, columns[1], app);
+    }
+
+    let instructions = Paragraph::new("↑↓ Navigate | ESC: Back | q: Quit")
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL))
+        .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[2]);
+}
+
+fn draw_node_list(f: &mut Frame, area: Rect, app: &mut App) {
+    let items: Vec<ListItem> = app
+        .nodes
+        .iter()
+        .map(|node| {
+            let color = if !node.ready {
+                Color::Red
+            } else if node.unschedulable || !node.pressure_conditions().is_empty() {
+                Color::Yellow
+            } else {
+                Color::Green
+            };
+            ListItem::new(format!(
+                "{} {} | {} | {}",
+                node.icon(),
+                node.name,
+                node.status_label(),
+                node.roles_label()
+            ))
+            .style(Style::default().fg(color))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title("Nodes"))
+        .highlight_style(
+            Style::default()
+                .bg(Color::Blue)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        );
+    f.render_stateful_widget(list, area, &mut app.list_state);
+}
+
+fn draw_node_details(f: &mut Frame, area: Rect, app: &App) {
+    let lines = match app.selected_node() {
+        Some((node, pods)) => {
+            let usage = app
+                .dump
+                .metrics()
+                .node(&node.name)
+                .map(|metrics| metrics.usage);
+            node_detail_lines(node, &pods, usage)
+        }
+        None => Vec::new(),
+    };
+    let details = Paragraph::new(lines.join("\n"))
+        .block(Block::default().borders(Borders::ALL).title("Node Details"))
+        .wrap(Wrap { trim: false });
+    f.render_widget(details, area);
+}
+
+fn node_detail_lines(
+    node: &NodeInfo,
+    pods: &[&(String, PodInfo)],
+    usage: Option<ResourceUsage>,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    lines.push(format!("Name: {}", node.name));
+    lines.push(format!("Status: {} {}", node.icon(), node.status_label()));
+    lines.push(format!("Roles: {}", node.roles_label()));
+    for (label, value) in [
+        ("Internal IP", &node.internal_ip),
+        ("Kubelet", &node.kubelet_version),
+        ("OS Image", &node.os_image),
+        ("Runtime", &node.container_runtime),
+    ] {
+        lines.push(format!("{}: {}", label, value.as_deref().unwrap_or("-")));
+    }
+
+    lines.push(String::new());
+    lines.push("📊 CAPACITY (allocatable)".to_string());
+    for resource in NODE_RESOURCES {
+        if let Some(capacity) = node.capacity.get(resource) {
+            let allocatable = node
+                .allocatable
+                .get(resource)
+                .map(String::as_str)
+                .unwrap_or("-");
+            lines.push(format!("  {}: {} ({})", resource, capacity, allocatable));
+        }
+    }
+
+    let allocation = NodeAllocation::new(node, pods.iter().map(|(_, pod)| pod));
+    lines.push(String::new());
+    lines.push(format!(
+        "📈 ALLOCATED ({} pods, % of allocatable)",
+        allocation.pods
+    ));
+    lines.push(format!(
+        "  cpu: {} requests ({}), {} limits ({})",
+        allocation.totals.cpu_request.cpu_label(),
+        percent(allocation.cpu_request_percent()),
+        allocation.totals.cpu_limit.cpu_label(),
+        percent(allocation.cpu_limit_percent())
+    ));
+    lines.push(format!(
+        "  memory: {} requests ({}), {} limits ({})",
+        allocation.totals.memory_request.memory_label(),
+        percent(allocation.memory_request_percent()),
+        allocation.totals.memory_limit.memory_label(),
+        percent(allocation.memory_limit_percent())
+    ));
+    if allocation.limits_overcommitted() {
+        lines.push("  ⚠️ Limits are overcommitted".to_string());
+    }
+    if let Some(usage) = usage {
+        lines.push(format!(
+            "  usage: cpu {} ({}), memory {} ({})",
+            usage.cpu.cpu_label(),
+            percent(usage.cpu.percent_of(allocation.allocatable_cpu)),
+            usage.memory.memory_label(),
+            percent(usage.memory.percent_of(allocation.allocatable_memory))
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push("🩺 CONDITIONS".to_string());
+    if node.conditions.is_empty() {
+        lines.push("  None reported".to_string());
+    }
+    for condition in &node.conditions {
+        lines.push(format!(
+            "  {} {}: {}{}",
+            condition_icon(condition),
+            condition.condition_type,
+            condition.status,
+            condition
+                .reason
+                .as_ref()
+                .map(|reason| format!(" ({})", reason))
+                .unwrap_or_default()
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push("🚫 TAINTS".to_string());
+    if node.taints.is_empty() {
+        lines.push("  None".to_string());
+    }
+    for taint in &node.taints {
+        lines.push(format!("  {}", taint.label()));
+    }
+
+    lines.push(String::new());
+    lines.push(format!("📦 PODS ({})", pods.len()));
+    for (namespace, pod) in pods {
+        let icon = if pod.ready && pod.status == "Running" {
+            "🟢"
+        } else {
+            "🔴"
+        };
+        let usage = match pod.usage {
+            Some(usage) => format!(" {}", usage.label()),
+            None => String::new(),
+        };
+        lines.push(format!(
+            "  {} {}/{} ({}){}",
+            icon, namespace, pod.name, pod.status, usage
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push("🏷️ LABELS".to_string());
+    for (key, value) in &node.labels {
+        lines.push(format!("  {}={}", key, value));
+    }
+
+    lines
+}
+
+fn percent(percent: Option<f64>) -> String {
+    match percent {
+        Some(percent) => format!("{:.0}%", percent),
+        None => "-".to_string(),
+    }
+}
+
+/// ✅ for a healthy condition: `Ready` is `True`, every other condition is `False`.
+fn condition_icon(condition: &NodeCondition) -> &'static str {
+    let healthy = if condition.condition_type == "Ready" {
+        condition.status == "True"
+    } else {
+        condition.status == "False"
+    };
+    if healthy {
+        "✅"
+    } else {
+        "❌"
+    }
+}
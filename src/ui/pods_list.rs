@@ -83,13 +83,19 @@ pub fn draw_pods_list(f: &mut Frame, app: &mut App) {
                     Style::default().fg(color)
                 };
 
+                let usage_info = match pod.usage {
+                    Some(usage) => format!(" | 📈 {}", usage.label()),
+                    None => String::new(),
+                };
+
                 let pod_info = format!(
-                    "{} {} | Status: {} | Ready: {}{}",
+                    "{} {} | Status: {} | Ready: {}{}{}",
                     status_icon,
                     pod.name,
                     pod.status,
                     if pod.ready { "✅" } else { "❌" },
-                    restart_info
+                    restart_info,
+                    usage_info
                 );
 
                 ListItem::new(pod_info).style(style)
@@ -0,0 +1,116 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+
+pub fn draw_services_list(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .split(f.size());
+
+    // Title with namespace info
+    let title = if let Some(ref ns) = app.selected_namespace {
+        format!("🌐 Services in Namespace: {}", ns)
+    } else {
+        "🌐 Services List".to_string()
+    };
+
+    let title_widget = Paragraph::new(title)
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL))
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+    f.render_widget(title_widget, chunks[0]);
+
+    // Services list
+    if app.networking.services.is_empty() {
+        let empty_message = Paragraph::new("No services found in this namespace")
+            .block(Block::default().borders(Borders::ALL).title("Services"))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::Yellow));
+        f.render_widget(empty_message, chunks[1]);
+    } else {
+        let items: Vec<ListItem> = app
+            .networking
+            .services
+            .iter()
+            .enumerate()
+            .map(|(i, service)| {
+                let matching = service.matching_pods(&app.pods);
+                let ready = matching
+                    .iter()
+                    .filter(|pod| pod.ready && pod.status == "Running")
+                    .count();
+
+                // No selector: endpoints are managed by hand, nothing to check
+                let (status_icon, color) = if !service.has_selector() {
+                    ("⚪", Color::Gray)
+                } else if matching.is_empty() {
+                    ("🟡", Color::Yellow)
+                } else if ready == 0 {
+                    ("🔴", Color::Red)
+                } else {
+                    ("🟢", Color::Green)
+                };
+
+                let pods_info = if service.has_selector() {
+                    format!(" | Pods: {}/{} ready", ready, matching.len())
+                } else {
+                    String::new()
+                };
+
+                let style = if Some(i) == app.list_state.selected() {
+                    Style::default()
+                        .bg(Color::Blue)
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(color)
+                };
+
+                ListItem::new(format!(
+                    "{} {} | Type: {} | Ports: {}{}",
+                    status_icon,
+                    service.name,
+                    service.service_type,
+                    service.ports_label(),
+                    pods_info
+                ))
+                .style(style)
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Select Service to view details"),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            );
+        f.render_stateful_widget(list, chunks[1], &mut app.list_state);
+    }
+
+    // Instructions
+    let instructions = Paragraph::new("↑↓ Navigate | Enter: View Details | ESC: Back | q: Quit")
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL))
+        .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[2]);
+}
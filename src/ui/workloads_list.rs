@@ -0,0 +1,102 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::workloads::WorkloadHealth;
+
+pub fn draw_workloads_list(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .split(f.size());
+
+    let kind = app.workload_kind;
+
+    // Title with namespace info
+    let title = if let Some(ref ns) = app.selected_namespace {
+        format!("{} {} in Namespace: {}", kind.icon(), kind.plural(), ns)
+    } else {
+        format!("{} {} List", kind.icon(), kind.plural())
+    };
+
+    let title_widget = Paragraph::new(title)
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL))
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+    f.render_widget(title_widget, chunks[0]);
+
+    // Workloads list
+    let summaries = app.workloads.summaries(kind);
+    if summaries.is_empty() {
+        let empty_message = Paragraph::new(format!(
+            "No {} found in this namespace",
+            kind.plural().to_lowercase()
+        ))
+        .block(Block::default().borders(Borders::ALL).title(kind.plural()))
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(Color::Yellow));
+        f.render_widget(empty_message, chunks[1]);
+    } else {
+        let items: Vec<ListItem> = summaries
+            .iter()
+            .enumerate()
+            .map(|(i, summary)| {
+                let style = if Some(i) == app.list_state.selected() {
+                    Style::default()
+                        .bg(Color::Blue)
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    let color = match summary.health {
+                        WorkloadHealth::Healthy => Color::Green,
+                        WorkloadHealth::Degraded => Color::Yellow,
+                        WorkloadHealth::Down => Color::Red,
+                        WorkloadHealth::Idle => Color::Gray,
+                    };
+                    Style::default().fg(color)
+                };
+
+                ListItem::new(format!(
+                    "{} {} | {}",
+                    summary.health.icon(),
+                    summary.name,
+                    summary.status
+                ))
+                .style(style)
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("Select {} to view details", kind.label())),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            );
+        f.render_stateful_widget(list, chunks[1], &mut app.list_state);
+    }
+
+    // Instructions
+    let instructions = Paragraph::new("↑↓ Navigate | Enter: View Details | ESC: Back | q: Quit")
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL))
+        .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[2]);
+}
@@ -0,0 +1,438 @@
+use flate2::read::{GzDecoder, MultiGzDecoder};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Cursor, Read, Seek, SeekFrom, Write};
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::manifest::is_manifest_file_name;
+use crate::metrics::{NODE_METRICS_FILE, POD_METRICS_FILE};
+
+/// Archived files up to this size are kept in memory when the archive is opened, so
+/// reading them later does not decompress the archive again.
+const CACHED_FILE_BYTES: u64 = 4 * 1024 * 1024;
+
+/// Total size of the archived files kept in memory.
+const CACHED_TOTAL_BYTES: u64 = 256 * 1024 * 1024;
+
+/// A file or directory inside a dump directory.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DirEntry {
+    pub name: String,
+    pub is_dir: bool,
+}
+
+/// Read-only access to the files of a dump, wherever they are stored. Paths are
+/// relative to the root of the dump.
+pub trait DumpFs: fmt::Debug + Send + Sync {
+    fn exists(&self) -> bool;
+
+    fn is_file(&self, path: &Path) -> bool;
+
+    /// Entries of a directory; empty if it does not exist.
+    fn read_dir(&self, path: &Path) -> Vec<DirEntry>;
+
+    fn open(&self, path: &Path) -> Result<Box<dyn Read + Send>>;
+
+    /// The file on disk, for readers that need to seek in it.
+    fn local_path(&self, _path: &Path) -> Option<PathBuf> {
+        None
+    }
+}
+
+/// Open the dump at `path`: a directory, or a tar, tar.gz, tar.zst or zip archive.
+pub fn open_dump_fs(path: &Path) -> Result<Arc<dyn DumpFs>> {
+    match ArchiveKind::from_path(path) {
+        Some(kind) if path.is_file() => Ok(Arc::new(ArchiveFs::open(path, kind)?)),
+        _ => Ok(Arc::new(DirectoryFs {
+            root: path.to_path_buf(),
+        })),
+    }
+}
+
+/// True for the names of files compressed on their own, like rotated `logs.txt.gz`.
+pub fn is_gzip_file(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension == "gz")
+}
+
+/// Decompress gzip files on the fly; other files are returned as they are.
+pub fn decompress(path: &Path, reader: Box<dyn Read + Send>) -> Box<dyn Read + Send> {
+    if is_gzip_file(path) {
+        // Rotated logs are often several gzip members concatenated
+        Box::new(MultiGzDecoder::new(BufReader::new(reader)))
+    } else {
+        reader
+    }
+}
+
+/// A dump extracted in a directory.
+#[derive(Debug)]
+pub struct DirectoryFs {
+    root: PathBuf,
+}
+
+impl DumpFs for DirectoryFs {
+    fn exists(&self) -> bool {
+        self.root.is_dir()
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.root.join(path).is_file()
+    }
+
+    fn read_dir(&self, path: &Path) -> Vec<DirEntry> {
+        fs::read_dir(self.root.join(path))
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|entry| DirEntry {
+                        name: entry.file_name().to_string_lossy().to_string(),
+                        is_dir: entry.file_type().map(|t| t.is_dir()).unwrap_or(false),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn open(&self, path: &Path) -> Result<Box<dyn Read + Send>> {
+        Ok(Box::new(File::open(self.root.join(path))?))
+    }
+
+    fn local_path(&self, path: &Path) -> Option<PathBuf> {
+        Some(self.root.join(path))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ArchiveKind {
+    Tar,
+    TarGz,
+    TarZst,
+    Zip,
+}
+
+impl ArchiveKind {
+    fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+        if name.ends_with(".tar") {
+            Some(ArchiveKind::Tar)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(ArchiveKind::TarGz)
+        } else if name.ends_with(".tar.zst") || name.ends_with(".tzst") {
+            Some(ArchiveKind::TarZst)
+        } else if name.ends_with(".zip") {
+            Some(ArchiveKind::Zip)
+        } else {
+            None
+        }
+    }
+}
+
+/// Where the content of an archived file comes from.
+#[derive(Clone)]
+enum ArchivedData {
+    Cached(Arc<[u8]>),
+    /// Byte range in an uncompressed tar
+    Range {
+        offset: u64,
+        size: u64,
+    },
+    /// Byte range in the spill file of the archive
+    Spilled {
+        offset: u64,
+        size: u64,
+    },
+    /// Name in a zip archive, to look the file up again
+    Member(String),
+}
+
+/// Temp file holding the members of a compressed tar too large to keep in memory, so
+/// opening one reads it back instead of decompressing the archive up to it. Removed
+/// with the `ArchiveFs`.
+struct SpillFile {
+    path: PathBuf,
+    writer: Option<BufWriter<File>>,
+    size: u64,
+}
+
+impl SpillFile {
+    fn create() -> io::Result<Self> {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let path = std::env::temp_dir().join(format!(
+            "k8s-analyzer-{}-{}.spill",
+            std::process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+        let writer = BufWriter::new(File::create(&path)?);
+        Ok(SpillFile {
+            path,
+            writer: Some(writer),
+            size: 0,
+        })
+    }
+
+    /// Append a member, returning where it went.
+    fn append(&mut self, read: &mut dyn Read) -> io::Result<ArchivedData> {
+        let writer = self
+            .writer
+            .as_mut()
+            .expect("spill file is still being written");
+        let size = io::copy(read, writer)?;
+        let offset = self.size;
+        self.size += size;
+        Ok(ArchivedData::Spilled { offset, size })
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        match self.writer.take() {
+            Some(mut writer) => writer.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        self.writer = None;
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// A dump read straight from an archive, without extracting it. The archive is scanned
+/// once when opened; a single top-level directory (`output/`, `cluster-dump/`) is
+/// taken as the root of the dump. Large members of compressed tars go to a temp file.
+pub struct ArchiveFs {
+    path: PathBuf,
+    kind: ArchiveKind,
+    files: HashMap<PathBuf, ArchivedData>,
+    dirs: BTreeMap<PathBuf, BTreeSet<DirEntry>>,
+    spill: Option<SpillFile>,
+}
+
+impl fmt::Debug for ArchiveFs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArchiveFs")
+            .field("path", &self.path)
+            .field("kind", &self.kind)
+            .field("files", &self.files.len())
+            .finish()
+    }
+}
+
+impl ArchiveFs {
+    fn open(path: &Path, kind: ArchiveKind) -> Result<Self> {
+        let mut members = Vec::new();
+        let mut spill: Option<SpillFile> = None;
+        let mut cached_bytes = 0;
+        let mut cache = |size: u64, read: &mut dyn Read| -> io::Result<Option<Arc<[u8]>>> {
+            if size > CACHED_FILE_BYTES || cached_bytes + size > CACHED_TOTAL_BYTES {
+                return Ok(None);
+            }
+            cached_bytes += size;
+            let mut data = Vec::with_capacity(size as usize);
+            read.read_to_end(&mut data)?;
+            Ok(Some(data.into()))
+        };
+
+        match kind {
+            ArchiveKind::Zip => {
+                let mut archive = zip::ZipArchive::new(File::open(path)?)
+                    .map_err(|e| format!("Invalid zip archive {}: {}", path.display(), e))?;
+                for index in 0..archive.len() {
+                    let mut file = archive
+                        .by_index(index)
+                        .map_err(|e| format!("Invalid zip archive {}: {}", path.display(), e))?;
+                    if file.is_dir() {
+                        continue;
+                    }
+                    let name = file.name().to_string();
+                    let data = match cache(file.size(), &mut file)? {
+                        Some(data) => ArchivedData::Cached(data),
+                        None => ArchivedData::Member(name.clone()),
+                    };
+                    members.push((PathBuf::from(name), data));
+                }
+            }
+            ArchiveKind::Tar => {
+                let mut archive = tar::Archive::new(File::open(path)?);
+                for entry in archive.entries_with_seek()? {
+                    let entry = entry?;
+                    if !entry.header().entry_type().is_file() {
+                        continue;
+                    }
+                    let data = ArchivedData::Range {
+                        offset: entry.raw_file_position(),
+                        size: entry.size(),
+                    };
+                    members.push((entry.path()?.into_owned(), data));
+                }
+            }
+            ArchiveKind::TarGz | ArchiveKind::TarZst => {
+                let mut archive = tar::Archive::new(open_compressed_tar(path, kind)?);
+                for entry in archive.entries()? {
+                    let mut entry = entry?;
+                    if !entry.header().entry_type().is_file() {
+                        continue;
+                    }
+                    let name = entry.path()?.into_owned();
+                    let data = match cache(entry.size(), &mut entry)? {
+                        Some(data) => ArchivedData::Cached(data),
+                        None => {
+                            let spill = match spill {
+                                Some(ref mut spill) => spill,
+                                None => spill.insert(SpillFile::create()?),
+                            };
+                            spill.append(&mut entry)?
+                        }
+                    };
+                    members.push((name, data));
+                }
+            }
+        }
+
+        if let Some(ref mut spill) = spill {
+            spill.finish()?;
+        }
+        let mut archive = ArchiveFs {
+            path: path.to_path_buf(),
+            kind,
+            files: HashMap::new(),
+            dirs: BTreeMap::new(),
+            spill,
+        };
+        let prefix = common_root(members.iter().map(|(name, _)| name.as_path()));
+        for (name, data) in members {
+            let name = normalize(&name);
+            if let Ok(relative) = name.strip_prefix(&prefix) {
+                archive.add_file(relative.to_path_buf(), data);
+            }
+        }
+        Ok(archive)
+    }
+
+    fn add_file(&mut self, path: PathBuf, data: ArchivedData) {
+        // Register the file and every directory above it
+        let mut child = path.clone();
+        let mut is_dir = false;
+        while let Some(parent) = child.parent() {
+            let name = match child.file_name() {
+                Some(name) => name.to_string_lossy().to_string(),
+                None => break,
+            };
+            self.dirs
+                .entry(parent.to_path_buf())
+                .or_default()
+                .insert(DirEntry { name, is_dir });
+            child = parent.to_path_buf();
+            is_dir = true;
+        }
+        self.files.insert(path, data);
+    }
+}
+
+impl DumpFs for ArchiveFs {
+    fn exists(&self) -> bool {
+        !self.files.is_empty()
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.files.contains_key(&normalize(path))
+    }
+
+    fn read_dir(&self, path: &Path) -> Vec<DirEntry> {
+        self.dirs
+            .get(&normalize(path))
+            .map(|entries| entries.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn open(&self, path: &Path) -> Result<Box<dyn Read + Send>> {
+        let data = self
+            .files
+            .get(&normalize(path))
+            .ok_or_else(|| format!("{} not found in {}", path.display(), self.path.display()))?;
+        match data {
+            ArchivedData::Cached(data) => Ok(Box::new(Cursor::new(Arc::clone(data)))),
+            ArchivedData::Range { offset, size } => {
+                let mut file = File::open(&self.path)?;
+                file.seek(SeekFrom::Start(*offset))?;
+                Ok(Box::new(file.take(*size)))
+            }
+            ArchivedData::Spilled { offset, size } => {
+                let spill = self.spill.as_ref().ok_or("spill file missing")?;
+                let mut file = File::open(&spill.path)?;
+                file.seek(SeekFrom::Start(*offset))?;
+                Ok(Box::new(file.take(*size)))
+            }
+            ArchivedData::Member(name) => {
+                let mut archive = zip::ZipArchive::new(File::open(&self.path)?)
+                    .map_err(|e| format!("Invalid zip archive {}: {}", self.path.display(), e))?;
+                let mut file = archive
+                    .by_name(name)
+                    .map_err(|e| format!("{}: {}", name, e))?;
+                let mut data = Vec::with_capacity(file.size() as usize);
+                file.read_to_end(&mut data)?;
+                Ok(Box::new(Cursor::new(data)))
+            }
+        }
+    }
+}
+
+fn open_compressed_tar(path: &Path, kind: ArchiveKind) -> Result<Box<dyn Read + Send>> {
+    let file = BufReader::new(File::open(path)?);
+    Ok(match kind {
+        ArchiveKind::TarZst => Box::new(zstd::Decoder::with_buffer(file)?),
+        _ => Box::new(GzDecoder::new(file)),
+    })
+}
+
+/// `./a/b` and `a/b/` as `a/b`.
+fn normalize(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| matches!(component, Component::Normal(_)))
+        .collect()
+}
+
+/// The single top-level directory holding everything, when it is the root of the dump
+/// rather than a namespace. It is the root when it holds namespace directories (with
+/// manifests), or holds manifests and no directories (kubectl List files). A directory
+/// holding manifests and pod directories is a namespace. Metrics and other files
+/// (`top-pods.txt`, a README) do not count.
+fn common_root<'a>(names: impl Iterator<Item = &'a Path>) -> PathBuf {
+    let mut root: Option<PathBuf> = None;
+    let mut has_manifests = false;
+    let mut has_dirs = false;
+    let mut has_namespace_dirs = false;
+    for name in names {
+        let name = normalize(name);
+        let components: Vec<_> = name.components().map(|c| c.as_os_str()).collect();
+        let Some(first) = components.first() else {
+            continue;
+        };
+        let is_manifest = components.last().is_some_and(|file| {
+            let file = file.to_string_lossy();
+            is_manifest_file_name(&file) && file != POD_METRICS_FILE && file != NODE_METRICS_FILE
+        });
+        match components.len() {
+            // A file in the first level: no common root to strip
+            1 => return PathBuf::new(),
+            2 => has_manifests |= is_manifest,
+            length => {
+                has_dirs = true;
+                has_namespace_dirs |= length == 3 && is_manifest;
+            }
+        }
+        let first = PathBuf::from(first);
+        match root {
+            Some(ref root) if *root != first => return PathBuf::new(),
+            _ => root = Some(first),
+        }
+    }
+    if has_manifests && has_dirs && !has_namespace_dirs {
+        return PathBuf::new();
+    }
+    root.unwrap_or_default()
+}
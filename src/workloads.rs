@@ -0,0 +1,420 @@
+use serde_json::Value;
+
+use crate::kubernetes::NamespaceObjects;
+
+/// Workload kinds besides Deployments that the dump may contain.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WorkloadKind {
+    StatefulSet,
+    DaemonSet,
+    ReplicaSet,
+    Job,
+    CronJob,
+}
+
+impl WorkloadKind {
+    pub const ALL: [WorkloadKind; 5] = [
+        WorkloadKind::StatefulSet,
+        WorkloadKind::DaemonSet,
+        WorkloadKind::ReplicaSet,
+        WorkloadKind::Job,
+        WorkloadKind::CronJob,
+    ];
+
+    /// Kubernetes kind name, also used as the component type.
+    pub fn label(&self) -> &'static str {
+        match self {
+            WorkloadKind::StatefulSet => "StatefulSet",
+            WorkloadKind::DaemonSet => "DaemonSet",
+            WorkloadKind::ReplicaSet => "ReplicaSet",
+            WorkloadKind::Job => "Job",
+            WorkloadKind::CronJob => "CronJob",
+        }
+    }
+
+    pub fn plural(&self) -> &'static str {
+        match self {
+            WorkloadKind::StatefulSet => "StatefulSets",
+            WorkloadKind::DaemonSet => "DaemonSets",
+            WorkloadKind::ReplicaSet => "ReplicaSets",
+            WorkloadKind::Job => "Jobs",
+            WorkloadKind::CronJob => "CronJobs",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            WorkloadKind::StatefulSet => "🗄️",
+            WorkloadKind::DaemonSet => "👹",
+            WorkloadKind::ReplicaSet => "🧬",
+            WorkloadKind::Job => "⚙️",
+            WorkloadKind::CronJob => "⏰",
+        }
+    }
+
+    /// Dump file holding the `List` of this kind.
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            WorkloadKind::StatefulSet => "statefulsets.json",
+            WorkloadKind::DaemonSet => "daemonsets.json",
+            WorkloadKind::ReplicaSet => "replicasets.json",
+            WorkloadKind::Job => "jobs.json",
+            WorkloadKind::CronJob => "cronjobs.json",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Option<WorkloadKind> {
+        WorkloadKind::ALL
+            .into_iter()
+            .find(|kind| kind.label() == label)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WorkloadHealth {
+    Healthy,
+    Degraded,
+    Down,
+    Idle,
+}
+
+impl WorkloadHealth {
+    pub fn icon(&self) -> &'static str {
+        match self {
+            WorkloadHealth::Healthy => "🟢",
+            WorkloadHealth::Degraded => "🟡",
+            WorkloadHealth::Down => "🔴",
+            WorkloadHealth::Idle => "⚪",
+        }
+    }
+}
+
+/// One-line view of a workload, shared by the list screens.
+#[derive(Debug, Clone)]
+pub struct WorkloadSummary {
+    pub name: String,
+    pub health: WorkloadHealth,
+    pub status: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct StatefulSetInfo {
+    pub name: String,
+    pub ready_replicas: u32,
+    pub desired_replicas: u32,
+    pub service_name: Option<String>,
+    pub update_strategy: Option<String>,
+    pub image: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DaemonSetInfo {
+    pub name: String,
+    pub desired_scheduled: u32,
+    pub current_scheduled: u32,
+    pub ready: u32,
+    pub misscheduled: u32,
+    pub image: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReplicaSetInfo {
+    pub name: String,
+    pub ready_replicas: u32,
+    pub desired_replicas: u32,
+    pub owner: Option<String>,
+    pub image: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct JobInfo {
+    pub name: String,
+    pub completions: u32,
+    pub succeeded: u32,
+    pub failed: u32,
+    pub active: u32,
+    pub complete: bool,
+    pub failure_reason: Option<String>,
+    pub owner: Option<String>,
+    pub image: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CronJobInfo {
+    pub name: String,
+    pub schedule: String,
+    pub suspended: bool,
+    pub active_jobs: usize,
+    pub last_schedule_time: Option<String>,
+    pub last_successful_time: Option<String>,
+    pub image: Option<String>,
+}
+
+/// All non-Deployment workloads of a namespace.
+#[derive(Debug, Clone, Default)]
+pub struct NamespaceWorkloads {
+    pub statefulsets: Vec<StatefulSetInfo>,
+    pub daemonsets: Vec<DaemonSetInfo>,
+    pub replicasets: Vec<ReplicaSetInfo>,
+    pub jobs: Vec<JobInfo>,
+    pub cronjobs: Vec<CronJobInfo>,
+}
+
+impl NamespaceWorkloads {
+    pub fn from_objects(objects: &NamespaceObjects) -> Self {
+        NamespaceWorkloads {
+            statefulsets: objects
+                .statefulsets
+                .iter()
+                .filter_map(parse_statefulset)
+                .collect(),
+            daemonsets: objects
+                .daemonsets
+                .iter()
+                .filter_map(parse_daemonset)
+                .collect(),
+            replicasets: objects
+                .replicasets
+                .iter()
+                .filter_map(parse_replicaset)
+                .collect(),
+            jobs: objects.jobs.iter().filter_map(parse_job).collect(),
+            cronjobs: objects.cronjobs.iter().filter_map(parse_cronjob).collect(),
+        }
+    }
+
+    pub fn count(&self, kind: WorkloadKind) -> usize {
+        match kind {
+            WorkloadKind::StatefulSet => self.statefulsets.len(),
+            WorkloadKind::DaemonSet => self.daemonsets.len(),
+            WorkloadKind::ReplicaSet => self.replicasets.len(),
+            WorkloadKind::Job => self.jobs.len(),
+            WorkloadKind::CronJob => self.cronjobs.len(),
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        WorkloadKind::ALL.iter().map(|kind| self.count(*kind)).sum()
+    }
+
+    pub fn summaries(&self, kind: WorkloadKind) -> Vec<WorkloadSummary> {
+        match kind {
+            WorkloadKind::StatefulSet => self.statefulsets.iter().map(|s| s.summary()).collect(),
+            WorkloadKind::DaemonSet => self.daemonsets.iter().map(|d| d.summary()).collect(),
+            WorkloadKind::ReplicaSet => self.replicasets.iter().map(|r| r.summary()).collect(),
+            WorkloadKind::Job => self.jobs.iter().map(|j| j.summary()).collect(),
+            WorkloadKind::CronJob => self.cronjobs.iter().map(|c| c.summary()).collect(),
+        }
+    }
+}
+
+impl StatefulSetInfo {
+    pub fn summary(&self) -> WorkloadSummary {
+        WorkloadSummary {
+            name: self.name.clone(),
+            health: replica_health(self.ready_replicas, self.desired_replicas),
+            status: format!(
+                "Replicas: {}/{}",
+                self.ready_replicas, self.desired_replicas
+            ),
+        }
+    }
+}
+
+impl DaemonSetInfo {
+    pub fn summary(&self) -> WorkloadSummary {
+        WorkloadSummary {
+            name: self.name.clone(),
+            health: replica_health(self.ready, self.desired_scheduled),
+            status: format!(
+                "Ready: {}/{} nodes{}",
+                self.ready,
+                self.desired_scheduled,
+                if self.misscheduled > 0 {
+                    format!(" | Misscheduled: {}", self.misscheduled)
+                } else {
+                    String::new()
+                }
+            ),
+        }
+    }
+}
+
+impl ReplicaSetInfo {
+    pub fn summary(&self) -> WorkloadSummary {
+        WorkloadSummary {
+            name: self.name.clone(),
+            health: replica_health(self.ready_replicas, self.desired_replicas),
+            status: format!(
+                "Replicas: {}/{}{}",
+                self.ready_replicas,
+                self.desired_replicas,
+                self.owner
+                    .as_ref()
+                    .map(|owner| format!(" | Owner: {}", owner))
+                    .unwrap_or_default()
+            ),
+        }
+    }
+}
+
+impl JobInfo {
+    pub fn summary(&self) -> WorkloadSummary {
+        let health = if self.failure_reason.is_some() {
+            WorkloadHealth::Down
+        } else if self.complete {
+            WorkloadHealth::Healthy
+        } else if self.failed > 0 {
+            WorkloadHealth::Degraded
+        } else {
+            WorkloadHealth::Idle
+        };
+
+        WorkloadSummary {
+            name: self.name.clone(),
+            health,
+            status: format!(
+                "Succeeded: {}/{} | Active: {} | Failed: {}",
+                self.succeeded, self.completions, self.active, self.failed
+            ),
+        }
+    }
+}
+
+impl CronJobInfo {
+    pub fn summary(&self) -> WorkloadSummary {
+        WorkloadSummary {
+            name: self.name.clone(),
+            health: if self.suspended {
+                WorkloadHealth::Idle
+            } else {
+                WorkloadHealth::Healthy
+            },
+            status: format!(
+                "Schedule: {}{} | Active: {} | Last run: {}",
+                self.schedule,
+                if self.suspended { " (suspended)" } else { "" },
+                self.active_jobs,
+                self.last_schedule_time.as_deref().unwrap_or("never")
+            ),
+        }
+    }
+}
+
+fn replica_health(ready: u32, desired: u32) -> WorkloadHealth {
+    if desired == 0 {
+        WorkloadHealth::Idle
+    } else if ready >= desired {
+        WorkloadHealth::Healthy
+    } else if ready == 0 {
+        WorkloadHealth::Down
+    } else {
+        WorkloadHealth::Degraded
+    }
+}
+
+pub fn parse_statefulset(statefulset: &Value) -> Option<StatefulSetInfo> {
+    Some(StatefulSetInfo {
+        name: statefulset["metadata"]["name"].as_str()?.to_string(),
+        ready_replicas: statefulset["status"]["readyReplicas"].as_u64().unwrap_or(0) as u32,
+        desired_replicas: statefulset["spec"]["replicas"].as_u64().unwrap_or(1) as u32,
+        service_name: string_field(&statefulset["spec"]["serviceName"]),
+        update_strategy: string_field(&statefulset["spec"]["updateStrategy"]["type"]),
+        image: template_image(&statefulset["spec"]["template"]),
+    })
+}
+
+pub fn parse_daemonset(daemonset: &Value) -> Option<DaemonSetInfo> {
+    let status = &daemonset["status"];
+    Some(DaemonSetInfo {
+        name: daemonset["metadata"]["name"].as_str()?.to_string(),
+        desired_scheduled: status["desiredNumberScheduled"].as_u64().unwrap_or(0) as u32,
+        current_scheduled: status["currentNumberScheduled"].as_u64().unwrap_or(0) as u32,
+        ready: status["numberReady"].as_u64().unwrap_or(0) as u32,
+        misscheduled: status["numberMisscheduled"].as_u64().unwrap_or(0) as u32,
+        image: template_image(&daemonset["spec"]["template"]),
+    })
+}
+
+pub fn parse_replicaset(replicaset: &Value) -> Option<ReplicaSetInfo> {
+    Some(ReplicaSetInfo {
+        name: replicaset["metadata"]["name"].as_str()?.to_string(),
+        ready_replicas: replicaset["status"]["readyReplicas"].as_u64().unwrap_or(0) as u32,
+        desired_replicas: replicaset["spec"]["replicas"].as_u64().unwrap_or(1) as u32,
+        owner: owner_name(replicaset),
+        image: template_image(&replicaset["spec"]["template"]),
+    })
+}
+
+pub fn parse_job(job: &Value) -> Option<JobInfo> {
+    let status = &job["status"];
+    let condition_true = |condition_type: &str| {
+        status["conditions"]
+            .as_array()
+            .and_then(|conditions| conditions.iter().find(|c| c["type"] == condition_type))
+            .filter(|condition| condition["status"] == "True")
+    };
+
+    Some(JobInfo {
+        name: job["metadata"]["name"].as_str()?.to_string(),
+        completions: job["spec"]["completions"].as_u64().unwrap_or(1) as u32,
+        succeeded: status["succeeded"].as_u64().unwrap_or(0) as u32,
+        failed: status["failed"].as_u64().unwrap_or(0) as u32,
+        active: status["active"].as_u64().unwrap_or(0) as u32,
+        complete: condition_true("Complete").is_some(),
+        failure_reason: condition_true("Failed").map(|condition| {
+            condition["reason"]
+                .as_str()
+                .or_else(|| condition["message"].as_str())
+                .unwrap_or("Failed")
+                .to_string()
+        }),
+        owner: owner_name(job),
+        image: template_image(&job["spec"]["template"]),
+    })
+}
+
+pub fn parse_cronjob(cronjob: &Value) -> Option<CronJobInfo> {
+    Some(CronJobInfo {
+        name: cronjob["metadata"]["name"].as_str()?.to_string(),
+        schedule: cronjob["spec"]["schedule"]
+            .as_str()
+            .unwrap_or("?")
+            .to_string(),
+        suspended: cronjob["spec"]["suspend"].as_bool().unwrap_or(false),
+        active_jobs: cronjob["status"]["active"]
+            .as_array()
+            .map(|active| active.len())
+            .unwrap_or(0),
+        last_schedule_time: string_field(&cronjob["status"]["lastScheduleTime"]),
+        last_successful_time: string_field(&cronjob["status"]["lastSuccessfulTime"]),
+        image: template_image(&cronjob["spec"]["jobTemplate"]["spec"]["template"]),
+    })
+}
+
+fn string_field(value: &Value) -> Option<String> {
+    value.as_str().map(|s| s.to_string())
+}
+
+fn template_image(template: &Value) -> Option<String> {
+    template["spec"]["containers"]
+        .as_array()
+        .and_then(|containers| containers.first())
+        .and_then(|container| container["image"].as_str())
+        .map(|s| s.to_string())
+}
+
+/// "Kind/name" of the controller owning an object, if any.
+fn owner_name(object: &Value) -> Option<String> {
+    object["metadata"]["ownerReferences"]
+        .as_array()?
+        .iter()
+        .find(|owner| owner["controller"].as_bool().unwrap_or(true))
+        .map(|owner| {
+            format!(
+                "{}/{}",
+                owner["kind"].as_str().unwrap_or("?"),
+                owner["name"].as_str().unwrap_or("?")
+            )
+        })
+}